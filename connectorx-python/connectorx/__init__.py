@@ -268,6 +268,8 @@ def read_sql(
     ==========
     conn
       the connection string, or dict of connection string mapping for federated query.
+      Arrow IPC (Feather) files are read with "arrow://" (or "arrow://?split_batches=true" to
+      read each record batch of a file in parallel), the queries being the paths of the files.
     query
       a SQL query or a list of SQL queries.
     return_type
@@ -315,6 +315,28 @@ def test_trino_types_binary(trino_url: str) -> None:
     assert_frame_equal(df, expected, check_names=True)
 
 
+@pytest.mark.skipif(
+    not os.environ.get("TRINO_URL"), reason="Test Trino only when `TRINO_URL` is set"
+)
+def test_trino_uuid(trino_url: str) -> None:
+    query = "select test_uuid from test.test_types order by test_int"
+    df = read_sql(trino_url, query)
+    expected = pd.DataFrame(
+        index=range(3),
+        data={
+            "test_uuid": pd.Series(
+                [
+                    "f4967dbb-33e9-4242-a13a-45b56ce60dba",
+                    "1c8b79d0-4508-4974-b728-7651bce4a5a5",
+                    None,
+                ],
+                dtype="object",
+            ),
+        },
+    )
+    assert_frame_equal(df, expected, check_names=True)
+
+
 @pytest.mark.skipif(
     not os.environ.get("TRINO_URL"), reason="Test Trino only when `TRINO_URL` is set"
 )
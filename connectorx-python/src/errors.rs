@@ -45,6 +45,9 @@ pub enum ConnectorXPythonError {
     #[error(transparent)]
     TrinoSourceError(#[from] connectorx::sources::trino::TrinoSourceError),
 
+    #[error(transparent)]
+    ArrowIPCSourceError(#[from] connectorx::sources::arrow_ipc::ArrowIPCSourceError),
+
     #[error(transparent)]
     ArrowDestinationError(#[from] connectorx::destinations::arrow::ArrowDestinationError),
 
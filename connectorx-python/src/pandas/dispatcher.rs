@@ -94,7 +94,7 @@ where
             .allocate_py(py, total_rows, &names, &dst_schema, dorder)?;
 
         debug!("Create destination partition");
-        let dst_partitions = self.dst.partition(self.queries.len())?;
+        let dst_partitions = self.dst.partition(src_partitions.len())?;
 
         #[cfg(all(not(feature = "branch"), not(feature = "fptr")))]
         compile_error!("branch or fptr, pick one");
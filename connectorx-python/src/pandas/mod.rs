@@ -9,8 +9,8 @@ mod typesystem;
 pub use self::destination::{PandasBlockInfo, PandasDestination, PandasPartitionDestination};
 use self::dispatcher::PandasDispatcher;
 pub use self::transports::{
-    BigQueryPandasTransport, MsSQLPandasTransport, MysqlPandasTransport, OraclePandasTransport,
-    PostgresPandasTransport, SqlitePandasTransport, TrinoPandasTransport,
+    ArrowIPCPandasTransport, BigQueryPandasTransport, MsSQLPandasTransport, MysqlPandasTransport,
+    OraclePandasTransport, PostgresPandasTransport, SqlitePandasTransport, TrinoPandasTransport,
 };
 pub use self::typesystem::{PandasDType, PandasTypeSystem};
 use crate::errors::ConnectorXPythonError;
@@ -233,6 +233,16 @@ pub fn write_pandas<'a, 'py: 'a>(
             );
             dispatcher.run(py)?
         }
+        SourceType::ArrowIPC => {
+            let source = ArrowIPCSource::from_url(&source_conn.conn);
+            let dispatcher = PandasDispatcher::<_, ArrowIPCPandasTransport>::new(
+                source,
+                destination,
+                queries,
+                origin_query,
+            );
+            dispatcher.run(py)?
+        }
         _ => unimplemented!("{:?} not implemented!", source_conn.ty),
     }
 }
@@ -0,0 +1,72 @@
+use crate::errors::ConnectorXPythonError;
+use crate::pandas::destination::PandasDestination;
+use crate::pandas::typesystem::PandasTypeSystem;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use connectorx::{
+    impl_transport,
+    sources::arrow_ipc::{ArrowIPCSource, ArrowIPCTypeSystem},
+    typesystem::TypeConversion,
+};
+use rust_decimal::prelude::*;
+
+#[allow(dead_code)]
+pub struct ArrowIPCPandasTransport<'py>(&'py ());
+
+impl_transport!(
+    name = ArrowIPCPandasTransport<'tp>,
+    error = ConnectorXPythonError,
+    systems = ArrowIPCTypeSystem => PandasTypeSystem,
+    route = ArrowIPCSource => PandasDestination<'tp>,
+    mappings = {
+        { Boolean[bool]                  => Bool[bool]              | conversion auto }
+        { Int8[i8]                       => I64[i64]                | conversion auto }
+        { Int16[i16]                     => I64[i64]                | conversion auto }
+        { Int32[i32]                     => I64[i64]                | conversion auto }
+        { Int64[i64]                     => I64[i64]                | conversion auto }
+        { UInt8[u8]                      => I64[i64]                | conversion auto }
+        { UInt16[u16]                    => I64[i64]                | conversion auto }
+        { UInt32[u32]                    => I64[i64]                | conversion auto }
+        { UInt64[u64]                    => F64[f64]                | conversion auto }
+        { Float32[f32]                   => F64[f64]                | conversion auto }
+        { Float64[f64]                   => F64[f64]                | conversion auto }
+        { Decimal[Decimal]               => F64[f64]                | conversion option }
+        { Utf8[String]                   => String[String]          | conversion auto }
+        { Binary[Vec<u8>]                => Bytes[Vec<u8>]          | conversion auto }
+        { Date32[NaiveDate]              => DateTime[DateTime<Utc>] | conversion option }
+        { Date64[NaiveDateTime]          => DateTime[DateTime<Utc>] | conversion option }
+        { Time64[NaiveTime]              => String[String]          | conversion option }
+        { Timestamp[NaiveDateTime]       => DateTime[DateTime<Utc>] | conversion none }
+        { TimestampTz[DateTime<Utc>]     => DateTime[DateTime<Utc>] | conversion auto }
+        { TimestampNano[NaiveDateTime]   => DateTime[DateTime<Utc>] | conversion none }
+        { TimestampTzNano[DateTime<Utc>] => DateTime[DateTime<Utc>] | conversion none }
+    }
+);
+
+impl<'py> TypeConversion<Decimal, f64> for ArrowIPCPandasTransport<'py> {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
+
+impl<'py> TypeConversion<NaiveDate, DateTime<Utc>> for ArrowIPCPandasTransport<'py> {
+    fn convert(val: NaiveDate) -> DateTime<Utc> {
+        DateTime::from_naive_utc_and_offset(
+            val.and_hms_opt(0, 0, 0)
+                .unwrap_or_else(|| panic!("and_hms_opt got None from {:?}", val)),
+            Utc,
+        )
+    }
+}
+
+impl<'py> TypeConversion<NaiveDateTime, DateTime<Utc>> for ArrowIPCPandasTransport<'py> {
+    fn convert(val: NaiveDateTime) -> DateTime<Utc> {
+        DateTime::from_naive_utc_and_offset(val, Utc)
+    }
+}
+
+impl<'py> TypeConversion<NaiveTime, String> for ArrowIPCPandasTransport<'py> {
+    fn convert(val: NaiveTime) -> String {
+        val.to_string()
+    }
+}
@@ -1,3 +1,4 @@
+mod arrow_ipc;
 mod bigquery;
 mod mssql;
 mod mysql;
@@ -7,6 +8,7 @@ mod sqlite;
 mod trino;
 
 pub use self::postgres::PostgresPandasTransport;
+pub use arrow_ipc::ArrowIPCPandasTransport;
 pub use bigquery::BigQueryPandasTransport;
 pub use mssql::MsSQLPandasTransport;
 pub use mysql::MysqlPandasTransport;
@@ -7,6 +7,7 @@ use connectorx::{
     sources::oracle::{OracleSource, OracleTypeSystem},
     typesystem::TypeConversion,
 };
+use rust_decimal::prelude::*;
 
 #[allow(dead_code)]
 pub struct OraclePandasTransport<'py>(&'py ());
@@ -18,6 +19,7 @@ impl_transport!(
     route = OracleSource => PandasDestination<'tp>,
     mappings = {
         { NumFloat[f64]              => F64[f64]                | conversion auto }
+        { NumDecimal[Decimal]        => F64[f64]                | conversion option }
         { Float[f64]                 => F64[f64]                | conversion none }
         { BinaryFloat[f64]           => F64[f64]                | conversion none }
         { BinaryDouble[f64]          => F64[f64]                | conversion none }
@@ -39,3 +41,10 @@ impl<'py> TypeConversion<NaiveDateTime, DateTime<Utc>> for OraclePandasTransport
         DateTime::from_naive_utc_and_offset(val, Utc)
     }
 }
+
+impl<'py> TypeConversion<Decimal, f64> for OraclePandasTransport<'py> {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
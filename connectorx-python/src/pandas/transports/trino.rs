@@ -7,6 +7,8 @@ use connectorx::{
     sources::trino::{TrinoSource, TrinoTypeSystem},
     typesystem::TypeConversion,
 };
+use rust_decimal::prelude::*;
+use uuid::Uuid;
 
 #[allow(dead_code)]
 pub struct TrinoPandasTransport<'py>(&'py ());
@@ -27,8 +29,10 @@ impl_transport!(
         { Tinyint[i8]                => I64[i64]                | conversion auto }
         { Double[f64]                => F64[f64]                | conversion auto }
         { Real[f32]                  => F64[f64]                | conversion auto }
+        { Decimal[Decimal]           => F64[f64]                | conversion option }
         { Varchar[String]            => String[String]          | conversion auto }
         { Char[String]               => String[String]          | conversion none }
+        { Uuid[Uuid]                 => String[String]          | conversion option }
     }
 );
 
@@ -53,3 +57,16 @@ impl<'py> TypeConversion<NaiveDateTime, DateTime<Utc>> for TrinoPandasTransport<
         DateTime::from_naive_utc_and_offset(val, Utc)
     }
 }
+
+impl<'py> TypeConversion<Decimal, f64> for TrinoPandasTransport<'py> {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
+
+impl<'py> TypeConversion<Uuid, String> for TrinoPandasTransport<'py> {
+    fn convert(val: Uuid) -> String {
+        val.to_string()
+    }
+}
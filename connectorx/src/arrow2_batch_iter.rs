@@ -0,0 +1,191 @@
+use crate::destinations::arrow2::{polars_from_chunks, Arrow2DestinationError};
+use crate::errors::OutResult;
+use crate::prelude::*;
+use anyhow::anyhow;
+use arrow2::{array::Array, chunk::Chunk, datatypes::Schema};
+use itertools::Itertools;
+use log::debug;
+use polars::prelude::DataFrame;
+use rayon::prelude::*;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// The iterator that returns arrow2 `Chunk`s, or polars `DataFrame`s, while the partitions
+/// are being written.
+pub struct Arrow2BatchIter<S, TP>
+where
+    S: Source,
+    TP: Transport<TSS = S::TypeSystem, TSD = Arrow2TypeSystem, S = S, D = Arrow2Destination>,
+    <S as Source>::Partition: 'static,
+    <S as Source>::TypeSystem: 'static,
+    <TP as Transport>::Error: 'static,
+{
+    dst: Arrow2Destination,
+    dst_parts: Option<Vec<Arrow2PartitionWriter>>,
+    src_parts: Option<Vec<S::Partition>>,
+    dorder: DataOrder,
+    src_schema: Vec<S::TypeSystem>,
+    dst_schema: Vec<Arrow2TypeSystem>,
+    handle: Option<JoinHandle<Result<(), TP::Error>>>,
+    _phantom: PhantomData<TP>,
+}
+
+impl<S, TP> Arrow2BatchIter<S, TP>
+where
+    S: Source,
+    TP: Transport<TSS = S::TypeSystem, TSD = Arrow2TypeSystem, S = S, D = Arrow2Destination>,
+{
+    pub fn new(
+        src: S,
+        mut dst: Arrow2Destination,
+        origin_query: Option<String>,
+        queries: &[CXQuery<String>],
+    ) -> Result<Self, TP::Error> {
+        let dispatcher = Dispatcher::<_, _, TP>::new(src, &mut dst, queries, origin_query);
+        let (dorder, src_parts, dst_parts, src_schema, dst_schema) = dispatcher.prepare()?;
+
+        Ok(Self {
+            dst,
+            dst_parts: Some(dst_parts),
+            src_parts: Some(src_parts),
+            dorder,
+            src_schema,
+            dst_schema,
+            handle: None,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Start writing the partitions in the background, if not started yet.
+    fn run(&mut self) {
+        let (src_partitions, dst_partitions) = match (self.src_parts.take(), self.dst_parts.take())
+        {
+            (Some(src_partitions), Some(dst_partitions)) => (src_partitions, dst_partitions),
+            _ => return,
+        };
+        let src_schema = self.src_schema.clone();
+        let dst_schema = self.dst_schema.clone();
+        let dorder = self.dorder;
+
+        self.handle = Some(std::thread::spawn(move || -> Result<(), TP::Error> {
+            let schemas: Vec<_> = src_schema
+                .iter()
+                .zip_eq(&dst_schema)
+                .map(|(&src_ty, &dst_ty)| (src_ty, dst_ty))
+                .collect();
+
+            debug!("Start writing");
+            // parse and write
+            dst_partitions
+                .into_par_iter()
+                .zip_eq(src_partitions)
+                .enumerate()
+                .try_for_each(|(i, (mut dst, mut src))| -> Result<(), TP::Error> {
+                    let mut parser = src.parser()?;
+
+                    match dorder {
+                        DataOrder::RowMajor => loop {
+                            let (n, is_last) = parser.fetch_next()?;
+                            dst.aquire_row(n)?;
+                            for _ in 0..n {
+                                #[allow(clippy::needless_range_loop)]
+                                for col in 0..dst.ncols() {
+                                    let (s1, s2) = schemas[col];
+                                    TP::process(s1, s2, &mut parser, &mut dst)?;
+                                }
+                            }
+                            if is_last {
+                                break;
+                            }
+                        },
+                        DataOrder::ColumnMajor => loop {
+                            let (n, is_last) = parser.fetch_next()?;
+                            dst.aquire_row(n)?;
+                            #[allow(clippy::needless_range_loop)]
+                            for col in 0..dst.ncols() {
+                                for _ in 0..n {
+                                    let (s1, s2) = schemas[col];
+                                    TP::process(s1, s2, &mut parser, &mut dst)?;
+                                }
+                            }
+                            if is_last {
+                                break;
+                            }
+                        },
+                    }
+
+                    debug!("Finalize partition {}", i);
+                    dst.finalize()?;
+                    debug!("Partition {} finished", i);
+                    Ok(())
+                })?;
+
+            debug!("Writing finished");
+
+            Ok(())
+        }));
+    }
+}
+
+pub trait Arrow2BatchIterator {
+    fn get_schema(&self) -> (Arc<Schema>, &[String]);
+    fn prepare(&mut self);
+    /// The next chunk, `None` once all the partitions are written. The error of a
+    /// partition is returned after the chunks written before it.
+    fn next_batch(&mut self) -> OutResult<Option<Chunk<Box<dyn Array>>>>;
+
+    /// The next chunk as a polars `DataFrame`.
+    fn next_polars(&mut self) -> OutResult<Option<DataFrame>> {
+        match self.next_batch()? {
+            Some(chunk) => {
+                let (schema, _) = self.get_schema();
+                let df = polars_from_chunks(vec![chunk], &schema.fields)
+                    .map_err(Arrow2DestinationError::from)?;
+                Ok(Some(df))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<S, TP> Arrow2BatchIterator for Arrow2BatchIter<S, TP>
+where
+    S: Source,
+    TP: Transport<TSS = S::TypeSystem, TSD = Arrow2TypeSystem, S = S, D = Arrow2Destination>,
+    ConnectorXOutError: From<TP::Error>,
+{
+    fn get_schema(&self) -> (Arc<Schema>, &[String]) {
+        (self.dst.arrow_schema(), self.dst.names())
+    }
+
+    fn prepare(&mut self) {
+        self.run();
+    }
+
+    fn next_batch(&mut self) -> OutResult<Option<Chunk<Box<dyn Array>>>> {
+        self.run();
+        if let Some(chunk) = self.dst.chunk() {
+            return Ok(Some(chunk));
+        }
+        if let Some(handle) = self.handle.take() {
+            handle
+                .join()
+                .map_err(|_| anyhow!("the writing of the partitions panicked"))??;
+        }
+        Ok(None)
+    }
+}
+
+impl<S, TP> Iterator for Arrow2BatchIter<S, TP>
+where
+    S: Source,
+    TP: Transport<TSS = S::TypeSystem, TSD = Arrow2TypeSystem, S = S, D = Arrow2Destination>,
+    ConnectorXOutError: From<TP::Error>,
+{
+    type Item = OutResult<Chunk<Box<dyn Array>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
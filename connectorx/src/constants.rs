@@ -7,11 +7,47 @@ const KILO: usize = 1 << 10;
 #[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
 pub const RECORD_BATCH_SIZE: usize = 64 * KILO;
 
+/// Number of rows sampled to infer the type of JSON columns.
+pub const JSON_SAMPLE_ROWS: usize = KILO;
+
+/// Precision of `Decimal128` columns whose source does not fix it.
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+pub const DEFAULT_ARROW_DECIMAL_PRECISION: u8 = 38;
+
+/// Field metadata key naming the extension type of a column.
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+pub const ARROW_EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// Extension name of `FixedSizeBinary(16)` columns holding UUIDs.
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// Field metadata key of the source type name of a column, see `ColumnDescriptor`.
+pub const COLUMN_TYPE_NAME_KEY: &str = "connectorx.type_name";
+
+/// Field metadata key of the source precision or length of a column.
+pub const COLUMN_PRECISION_KEY: &str = "connectorx.precision";
+
+/// Field metadata key of the source scale of a column.
+pub const COLUMN_SCALE_KEY: &str = "connectorx.scale";
+
+/// Field metadata key of whether the source column accepts nulls, `true` or `false`.
+pub const COLUMN_NULLABLE_KEY: &str = "connectorx.nullable";
+
+/// Field metadata key of the source table of a column.
+pub const COLUMN_TABLE_KEY: &str = "connectorx.table";
+
+/// Scale of `Decimal128` columns whose source does not fix it.
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+pub const DEFAULT_ARROW_DECIMAL_SCALE: i8 = 10;
+
 #[cfg(any(
     feature = "src_postgres",
     feature = "src_mysql",
     feature = "src_oracle",
-    feature = "src_mssql"
+    feature = "src_mssql",
+    feature = "src_parquet",
+    feature = "src_clickhouse"
 ))]
 pub const DB_BUFFER_SIZE: usize = 32;
 
@@ -1,25 +1,52 @@
 use super::{
     errors::{ArrowDestinationError, Result},
-    typesystem::{DateTimeWrapperMicro, NaiveDateTimeWrapperMicro, NaiveTimeWrapperMicro},
+    typesystem::{
+        DateTimeWrapperMicro, IntervalMonthDayNano, NaiveDateTimeWrapperMicro,
+        NaiveTimeWrapperMicro,
+    },
 };
-use crate::constants::SECONDS_IN_DAY;
+use crate::constants::{
+    ARROW_EXTENSION_NAME_KEY, DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE,
+    SECONDS_IN_DAY, UUID_EXTENSION_NAME,
+};
+use crate::utils::decimal_to_i128;
+use anyhow::anyhow;
 use arrow::array::{
-    ArrayBuilder, BooleanBuilder, Date32Builder, Float32Builder, Float64Builder, Int32Builder,
-    Int64Builder, LargeBinaryBuilder, StringBuilder, Time64MicrosecondBuilder,
-    Time64NanosecondBuilder, TimestampMicrosecondBuilder, TimestampNanosecondBuilder,
-    UInt32Builder, UInt64Builder,
+    make_array, new_empty_array, Array, ArrayBuilder, ArrayRef, BooleanBuilder, Date32Builder,
+    Decimal128Builder, FixedSizeBinaryBuilder, Float32Builder, Float64Builder, Int16Builder,
+    Int32Builder, Int64Builder, Int8Builder, IntervalMonthDayNanoBuilder, LargeBinaryBuilder,
+    LargeListBuilder, MapBuilder, StringArray, StringBuilder, StringDictionaryBuilder,
+    Time64MicrosecondBuilder, Time64NanosecondBuilder, UInt16Builder, UInt32Builder, UInt64Builder,
+    UInt8Builder,
 };
 use arrow::datatypes::Field;
-use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use arrow::datatypes::{
+    DataType as ArrowDataType, Fields, Int32Type, IntervalMonthDayNanoType, IntervalUnit, Schema,
+    TimeUnit,
+};
+use arrow::json::ReaderBuilder;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
 use fehler::throws;
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+use std::{any::Any, collections::HashMap, sync::Arc};
+use uuid::Uuid;
 
 /// Associate arrow builder with native type
 pub trait ArrowAssoc {
     type Builder: ArrayBuilder + Send;
 
     fn builder(nrows: usize) -> Self::Builder;
+    /// Create a builder for the column `field`, whose type may be more specific than `field()`.
+    fn builder_for(nrows: usize, _field: &Field) -> Self::Builder {
+        Self::builder(nrows)
+    }
     fn append(builder: &mut Self::Builder, value: Self) -> Result<()>;
+    /// Finish the column, which fails for builders that only check their values at the end.
+    fn finish(builder: &mut Self::Builder) -> Result<ArrayRef> {
+        Ok(ArrayBuilder::finish(builder))
+    }
     fn field(header: &str) -> Field;
 }
 
@@ -61,14 +88,70 @@ macro_rules! impl_arrow_assoc {
     };
 }
 
+impl_arrow_assoc!(u8, ArrowDataType::UInt8, UInt8Builder);
+impl_arrow_assoc!(u16, ArrowDataType::UInt16, UInt16Builder);
 impl_arrow_assoc!(u32, ArrowDataType::UInt32, UInt32Builder);
 impl_arrow_assoc!(u64, ArrowDataType::UInt64, UInt64Builder);
+impl_arrow_assoc!(i8, ArrowDataType::Int8, Int8Builder);
+impl_arrow_assoc!(i16, ArrowDataType::Int16, Int16Builder);
 impl_arrow_assoc!(i32, ArrowDataType::Int32, Int32Builder);
 impl_arrow_assoc!(i64, ArrowDataType::Int64, Int64Builder);
 impl_arrow_assoc!(f32, ArrowDataType::Float32, Float32Builder);
 impl_arrow_assoc!(f64, ArrowDataType::Float64, Float64Builder);
 impl_arrow_assoc!(bool, ArrowDataType::Boolean, BooleanBuilder);
 
+macro_rules! impl_arrow_assoc_vec {
+    ($T:ty, $AT:expr, $B:ty) => {
+        impl ArrowAssoc for Vec<$T> {
+            type Builder = LargeListBuilder<$B>;
+
+            fn builder(nrows: usize) -> Self::Builder {
+                LargeListBuilder::with_capacity(<$B>::new(), nrows)
+            }
+
+            #[throws(ArrowDestinationError)]
+            fn append(builder: &mut Self::Builder, value: Self) {
+                builder.append_value(value.into_iter().map(Some));
+            }
+
+            fn field(header: &str) -> Field {
+                Field::new(header, list_type($AT), false)
+            }
+        }
+
+        impl ArrowAssoc for Option<Vec<$T>> {
+            type Builder = LargeListBuilder<$B>;
+
+            fn builder(nrows: usize) -> Self::Builder {
+                LargeListBuilder::with_capacity(<$B>::new(), nrows)
+            }
+
+            #[throws(ArrowDestinationError)]
+            fn append(builder: &mut Self::Builder, value: Self) {
+                builder.append_option(value.map(|values| values.into_iter().map(Some)));
+            }
+
+            fn field(header: &str) -> Field {
+                Field::new(header, list_type($AT), true)
+            }
+        }
+    };
+}
+
+/// The type of a list column, items are nullable to match what `LargeListBuilder` produces.
+fn list_type(item: ArrowDataType) -> ArrowDataType {
+    ArrowDataType::LargeList(Arc::new(Field::new("item", item, true)))
+}
+
+impl_arrow_assoc_vec!(bool, ArrowDataType::Boolean, BooleanBuilder);
+impl_arrow_assoc_vec!(i32, ArrowDataType::Int32, Int32Builder);
+impl_arrow_assoc_vec!(i64, ArrowDataType::Int64, Int64Builder);
+impl_arrow_assoc_vec!(u32, ArrowDataType::UInt32, UInt32Builder);
+impl_arrow_assoc_vec!(u64, ArrowDataType::UInt64, UInt64Builder);
+impl_arrow_assoc_vec!(f32, ArrowDataType::Float32, Float32Builder);
+impl_arrow_assoc_vec!(f64, ArrowDataType::Float64, Float64Builder);
+impl_arrow_assoc_vec!(String, ArrowDataType::Utf8, StringBuilder);
+
 impl ArrowAssoc for &str {
     type Builder = StringBuilder;
 
@@ -106,131 +189,108 @@ impl ArrowAssoc for Option<&str> {
     }
 }
 
-impl ArrowAssoc for String {
-    type Builder = StringBuilder;
-
-    fn builder(nrows: usize) -> Self::Builder {
-        StringBuilder::with_capacity(1024, nrows)
-    }
+/// Builds a string column, dictionary-encoded with `Int32` keys if its field is a `Dictionary`.
+pub enum Utf8Builder {
+    Plain(StringBuilder),
+    Dictionary(StringDictionaryBuilder<Int32Type>),
+}
 
-    #[throws(ArrowDestinationError)]
-    fn append(builder: &mut Self::Builder, value: String) {
-        builder.append_value(value.as_str());
+impl Utf8Builder {
+    fn new(nrows: usize, field: &Field) -> Self {
+        match field.data_type() {
+            ArrowDataType::Dictionary(..) => {
+                Utf8Builder::Dictionary(StringDictionaryBuilder::with_capacity(nrows, 64, 1024))
+            }
+            _ => Utf8Builder::Plain(StringBuilder::with_capacity(1024, nrows)),
+        }
     }
 
-    fn field(header: &str) -> Field {
-        Field::new(header, ArrowDataType::Utf8, false)
+    fn append_option(&mut self, value: Option<&str>) {
+        match self {
+            Utf8Builder::Plain(builder) => builder.append_option(value),
+            Utf8Builder::Dictionary(builder) => builder.append_option(value),
+        }
     }
 }
 
-impl ArrowAssoc for Option<String> {
-    type Builder = StringBuilder;
+impl ArrayBuilder for Utf8Builder {
+    fn len(&self) -> usize {
+        match self {
+            Utf8Builder::Plain(builder) => builder.len(),
+            Utf8Builder::Dictionary(builder) => builder.len(),
+        }
+    }
 
-    fn builder(nrows: usize) -> Self::Builder {
-        StringBuilder::with_capacity(1024, nrows)
+    fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
-    #[throws(ArrowDestinationError)]
-    fn append(builder: &mut Self::Builder, value: Self) {
-        match value {
-            Some(s) => builder.append_value(s.as_str()),
-            None => builder.append_null(),
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Utf8Builder::Plain(builder) => Arc::new(builder.finish()),
+            Utf8Builder::Dictionary(builder) => Arc::new(builder.finish()),
         }
     }
 
-    fn field(header: &str) -> Field {
-        Field::new(header, ArrowDataType::Utf8, true)
+    fn finish_cloned(&self) -> ArrayRef {
+        match self {
+            Utf8Builder::Plain(builder) => Arc::new(builder.finish_cloned()),
+            Utf8Builder::Dictionary(builder) => Arc::new(builder.finish_cloned()),
+        }
     }
-}
-
-impl ArrowAssoc for DateTime<Utc> {
-    type Builder = TimestampNanosecondBuilder;
 
-    fn builder(nrows: usize) -> Self::Builder {
-        TimestampNanosecondBuilder::with_capacity(nrows)
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 
-    #[throws(ArrowDestinationError)]
-    fn append(builder: &mut Self::Builder, value: DateTime<Utc>) {
-        builder.append_value(
-            value
-                .timestamp_nanos_opt()
-                .unwrap_or_else(|| panic!("out of range DateTime!")),
-        )
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
     }
 
-    fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
-            false,
-        )
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
     }
 }
 
-impl ArrowAssoc for Option<DateTime<Utc>> {
-    type Builder = TimestampNanosecondBuilder;
+impl ArrowAssoc for String {
+    type Builder = Utf8Builder;
 
     fn builder(nrows: usize) -> Self::Builder {
-        TimestampNanosecondBuilder::with_capacity(nrows)
+        Utf8Builder::Plain(StringBuilder::with_capacity(1024, nrows))
     }
 
-    #[throws(ArrowDestinationError)]
-    fn append(builder: &mut Self::Builder, value: Option<DateTime<Utc>>) {
-        builder.append_option(value.map(|x| {
-            x.timestamp_nanos_opt()
-                .unwrap_or_else(|| panic!("out of range DateTime!"))
-        }))
-    }
-
-    fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
-            true,
-        )
-    }
-}
-
-impl ArrowAssoc for DateTimeWrapperMicro {
-    type Builder = TimestampMicrosecondBuilder;
-
-    fn builder(nrows: usize) -> Self::Builder {
-        TimestampMicrosecondBuilder::with_capacity(nrows).with_timezone("UTC")
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        Utf8Builder::new(nrows, field)
     }
 
     #[throws(ArrowDestinationError)]
-    fn append(builder: &mut Self::Builder, value: DateTimeWrapperMicro) {
-        builder.append_value(value.0.timestamp_micros());
+    fn append(builder: &mut Self::Builder, value: String) {
+        builder.append_option(Some(value.as_str()));
     }
 
     fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
-            false,
-        )
+        Field::new(header, ArrowDataType::Utf8, false)
     }
 }
 
-impl ArrowAssoc for Option<DateTimeWrapperMicro> {
-    type Builder = TimestampMicrosecondBuilder;
+impl ArrowAssoc for Option<String> {
+    type Builder = Utf8Builder;
 
     fn builder(nrows: usize) -> Self::Builder {
-        TimestampMicrosecondBuilder::with_capacity(nrows).with_timezone("UTC")
+        Utf8Builder::Plain(StringBuilder::with_capacity(1024, nrows))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        Utf8Builder::new(nrows, field)
     }
 
     #[throws(ArrowDestinationError)]
-    fn append(builder: &mut Self::Builder, value: Option<DateTimeWrapperMicro>) {
-        builder.append_option(value.map(|x| x.0.timestamp_micros()));
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_option(value.as_deref());
     }
 
     fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
-            true,
-        )
+        Field::new(header, ArrowDataType::Utf8, true)
     }
 }
 
@@ -241,12 +301,6 @@ fn naive_date_to_arrow(nd: NaiveDate) -> i32 {
     }
 }
 
-fn naive_datetime_to_arrow(nd: NaiveDateTime) -> i64 {
-    nd.and_utc()
-        .timestamp_nanos_opt()
-        .unwrap_or_else(|| panic!("out of range DateTime"))
-}
-
 impl ArrowAssoc for Option<NaiveDate> {
     type Builder = Date32Builder;
 
@@ -281,93 +335,204 @@ impl ArrowAssoc for NaiveDate {
     }
 }
 
-impl ArrowAssoc for Option<NaiveDateTime> {
-    type Builder = TimestampNanosecondBuilder;
+/// A time zone given by name, e.g. `Europe/Paris`, or by offset, e.g. `+02:00`.
+#[derive(Debug, Clone, Copy)]
+pub enum ArrowTimeZone {
+    Named(Tz),
+    Offset(FixedOffset),
+}
 
-    fn builder(nrows: usize) -> Self::Builder {
-        TimestampNanosecondBuilder::with_capacity(nrows)
+impl ArrowTimeZone {
+    pub fn parse(tz: &str) -> Option<Self> {
+        match tz.parse() {
+            Ok(offset) => Some(ArrowTimeZone::Offset(offset)),
+            Err(_) => tz.parse().ok().map(ArrowTimeZone::Named),
+        }
     }
 
-    fn append(builder: &mut Self::Builder, value: Option<NaiveDateTime>) -> Result<()> {
-        builder.append_option(value.map(naive_datetime_to_arrow));
-        Ok(())
+    /// The instant of a local time, the earlier one if it is ambiguous.
+    fn localize(&self, value: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            ArrowTimeZone::Named(tz) => tz
+                .from_local_datetime(&value)
+                .earliest()
+                .map(|v| v.with_timezone(&Utc)),
+            ArrowTimeZone::Offset(offset) => offset
+                .from_local_datetime(&value)
+                .earliest()
+                .map(|v| v.with_timezone(&Utc)),
+        }
     }
+}
 
-    fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
-            true,
-        )
-    }
+/// Builds a timestamp column in the unit and time zone of its field. Naive values are read as
+/// local times of that zone, or as UTC if the field has none.
+pub struct TimestampBuilder {
+    values: Int64Builder,
+    data_type: ArrowDataType,
+    unit: TimeUnit,
+    local: Option<ArrowTimeZone>,
 }
 
-impl ArrowAssoc for NaiveDateTime {
-    type Builder = TimestampNanosecondBuilder;
+impl TimestampBuilder {
+    fn new(nrows: usize, field: &Field) -> Self {
+        let (unit, local) = match field.data_type() {
+            ArrowDataType::Timestamp(unit, tz) => {
+                (unit.clone(), tz.as_deref().and_then(ArrowTimeZone::parse))
+            }
+            _ => (TimeUnit::Nanosecond, None),
+        };
+        TimestampBuilder {
+            values: Int64Builder::with_capacity(nrows),
+            data_type: field.data_type().clone(),
+            unit,
+            local,
+        }
+    }
 
-    fn builder(nrows: usize) -> Self::Builder {
-        TimestampNanosecondBuilder::with_capacity(nrows)
+    #[throws(ArrowDestinationError)]
+    fn append_instant(&mut self, value: Option<DateTime<Utc>>) {
+        let value = match value {
+            Some(v) => Some(match self.unit {
+                TimeUnit::Second => v.timestamp(),
+                TimeUnit::Millisecond => v.timestamp_millis(),
+                TimeUnit::Microsecond => v.timestamp_micros(),
+                TimeUnit::Nanosecond => v
+                    .timestamp_nanos_opt()
+                    .ok_or_else(|| anyhow!("out of range DateTime: {}", v))?,
+            }),
+            None => None,
+        };
+        self.values.append_option(value);
     }
 
-    fn append(builder: &mut Self::Builder, value: NaiveDateTime) -> Result<()> {
-        builder.append_value(naive_datetime_to_arrow(value));
-        Ok(())
+    #[throws(ArrowDestinationError)]
+    fn append_naive(&mut self, value: Option<NaiveDateTime>) {
+        let value = match (value, &self.local) {
+            (Some(v), Some(tz)) => Some(
+                tz.localize(v)
+                    .ok_or_else(|| anyhow!("{} does not exist in time zone {:?}", v, tz))?,
+            ),
+            (Some(v), None) => Some(v.and_utc()),
+            (None, _) => None,
+        };
+        self.append_instant(value)?;
     }
 
-    fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
-            false,
-        )
+    fn with_data_type(&self, values: ArrayRef) -> ArrayRef {
+        let data = values
+            .into_data()
+            .into_builder()
+            .data_type(self.data_type.clone());
+        // every timestamp unit is stored as i64
+        make_array(data.build().expect("timestamps stored as i64"))
     }
 }
 
-impl ArrowAssoc for Option<NaiveDateTimeWrapperMicro> {
-    type Builder = TimestampMicrosecondBuilder;
-
-    fn builder(nrows: usize) -> Self::Builder {
-        TimestampMicrosecondBuilder::with_capacity(nrows)
+impl ArrayBuilder for TimestampBuilder {
+    fn len(&self) -> usize {
+        self.values.len()
     }
 
-    fn append(builder: &mut Self::Builder, value: Option<NaiveDateTimeWrapperMicro>) -> Result<()> {
-        builder.append_option(match value {
-            Some(v) => Some(v.0.and_utc().timestamp_micros()),
-            None => None,
-        });
-        Ok(())
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
     }
 
-    fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
-            true,
-        )
+    fn finish(&mut self) -> ArrayRef {
+        let values = Arc::new(self.values.finish());
+        self.with_data_type(values)
     }
-}
 
-impl ArrowAssoc for NaiveDateTimeWrapperMicro {
-    type Builder = TimestampMicrosecondBuilder;
+    fn finish_cloned(&self) -> ArrayRef {
+        self.with_data_type(Arc::new(self.values.finish_cloned()))
+    }
 
-    fn builder(nrows: usize) -> Self::Builder {
-        TimestampMicrosecondBuilder::with_capacity(nrows)
+    fn as_any(&self) -> &dyn Any {
+        self
     }
 
-    fn append(builder: &mut Self::Builder, value: NaiveDateTimeWrapperMicro) -> Result<()> {
-        builder.append_value(value.0.and_utc().timestamp_micros());
-        Ok(())
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
     }
 
-    fn field(header: &str) -> Field {
-        Field::new(
-            header,
-            ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
-            false,
-        )
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
     }
 }
 
+macro_rules! impl_arrow_assoc_timestamp {
+    ($T:ty, $unit:expr, $tz:expr, $append:ident, $inner:expr) => {
+        impl ArrowAssoc for $T {
+            type Builder = TimestampBuilder;
+
+            fn builder(nrows: usize) -> Self::Builder {
+                TimestampBuilder::new(nrows, &Self::field(""))
+            }
+
+            fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+                TimestampBuilder::new(nrows, field)
+            }
+
+            fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+                builder.$append(Some(($inner)(value)))
+            }
+
+            fn field(header: &str) -> Field {
+                Field::new(header, ArrowDataType::Timestamp($unit, $tz), false)
+            }
+        }
+
+        impl ArrowAssoc for Option<$T> {
+            type Builder = TimestampBuilder;
+
+            fn builder(nrows: usize) -> Self::Builder {
+                TimestampBuilder::new(nrows, &Self::field(""))
+            }
+
+            fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+                TimestampBuilder::new(nrows, field)
+            }
+
+            fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+                builder.$append(value.map($inner))
+            }
+
+            fn field(header: &str) -> Field {
+                Field::new(header, ArrowDataType::Timestamp($unit, $tz), true)
+            }
+        }
+    };
+}
+
+impl_arrow_assoc_timestamp!(
+    DateTime<Utc>,
+    TimeUnit::Nanosecond,
+    None,
+    append_instant,
+    |v: DateTime<Utc>| v
+);
+impl_arrow_assoc_timestamp!(
+    DateTimeWrapperMicro,
+    TimeUnit::Microsecond,
+    Some("UTC".into()),
+    append_instant,
+    |v: DateTimeWrapperMicro| v.0
+);
+impl_arrow_assoc_timestamp!(
+    NaiveDateTime,
+    TimeUnit::Nanosecond,
+    None,
+    append_naive,
+    |v: NaiveDateTime| v
+);
+impl_arrow_assoc_timestamp!(
+    NaiveDateTimeWrapperMicro,
+    TimeUnit::Microsecond,
+    None,
+    append_naive,
+    |v: NaiveDateTimeWrapperMicro| v.0
+);
+
 impl ArrowAssoc for Option<NaiveTime> {
     type Builder = Time64NanosecondBuilder;
 
@@ -483,3 +648,402 @@ impl ArrowAssoc for Vec<u8> {
         Field::new(header, ArrowDataType::LargeBinary, false)
     }
 }
+
+fn interval_to_i128(value: IntervalMonthDayNano) -> i128 {
+    IntervalMonthDayNanoType::make_value(value.months, value.days, value.nanoseconds)
+}
+
+impl ArrowAssoc for Option<IntervalMonthDayNano> {
+    type Builder = IntervalMonthDayNanoBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        IntervalMonthDayNanoBuilder::with_capacity(nrows)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+        builder.append_option(value.map(interval_to_i128));
+        Ok(())
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(
+            header,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+            true,
+        )
+    }
+}
+
+impl ArrowAssoc for IntervalMonthDayNano {
+    type Builder = IntervalMonthDayNanoBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        IntervalMonthDayNanoBuilder::with_capacity(nrows)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+        builder.append_value(interval_to_i128(value));
+        Ok(())
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(
+            header,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+            false,
+        )
+    }
+}
+
+/// A `Decimal128Builder` that rescales the values it appends to its scale.
+#[derive(Debug)]
+pub struct DecimalBuilder {
+    builder: Decimal128Builder,
+    precision: u8,
+    scale: i8,
+}
+
+impl DecimalBuilder {
+    fn new(nrows: usize, field: &Field) -> Self {
+        let (precision, scale) = match field.data_type() {
+            ArrowDataType::Decimal128(precision, scale) => (*precision, *scale),
+            _ => (DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE),
+        };
+        DecimalBuilder {
+            builder: Decimal128Builder::with_capacity(nrows)
+                .with_data_type(ArrowDataType::Decimal128(precision, scale)),
+            precision,
+            scale,
+        }
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn append_option(&mut self, value: Option<Decimal>) {
+        match value {
+            Some(v) => {
+                let v = decimal_to_i128(v, self.precision, self.scale).ok_or_else(|| {
+                    anyhow!(
+                        "{} does not fit in Decimal128({}, {})",
+                        v,
+                        self.precision,
+                        self.scale
+                    )
+                })?;
+                self.builder.append_value(v)
+            }
+            None => self.builder.append_null(),
+        }
+    }
+}
+
+impl ArrayBuilder for DecimalBuilder {
+    fn len(&self) -> usize {
+        self.builder.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.builder.is_empty()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(self.builder.finish())
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        Arc::new(self.builder.finish_cloned())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+fn decimal_field(header: &str, nullable: bool) -> Field {
+    Field::new(
+        header,
+        ArrowDataType::Decimal128(DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE),
+        nullable,
+    )
+}
+
+impl ArrowAssoc for Option<Decimal> {
+    type Builder = DecimalBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        DecimalBuilder::new(nrows, &decimal_field("", true))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        DecimalBuilder::new(nrows, field)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+        builder.append_option(value)
+    }
+
+    fn field(header: &str) -> Field {
+        decimal_field(header, true)
+    }
+}
+
+impl ArrowAssoc for Decimal {
+    type Builder = DecimalBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        DecimalBuilder::new(nrows, &decimal_field("", false))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        DecimalBuilder::new(nrows, field)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+        builder.append_option(Some(value))
+    }
+
+    fn field(header: &str) -> Field {
+        decimal_field(header, false)
+    }
+}
+
+type StringMapBuilder = MapBuilder<StringBuilder, StringBuilder>;
+
+#[throws(ArrowDestinationError)]
+fn append_map(builder: &mut StringMapBuilder, value: Option<HashMap<String, Option<String>>>) {
+    let valid = value.is_some();
+    for (key, value) in value.into_iter().flatten() {
+        builder.keys().append_value(key);
+        builder.values().append_option(value);
+    }
+    builder.append(valid)?;
+}
+
+/// The type of `hstore` columns, as produced by `MapBuilder`.
+fn map_type() -> ArrowDataType {
+    let entries = Fields::from(vec![
+        Field::new("keys", ArrowDataType::Utf8, false),
+        Field::new("values", ArrowDataType::Utf8, true),
+    ]);
+    ArrowDataType::Map(
+        Arc::new(Field::new("entries", ArrowDataType::Struct(entries), false)),
+        false,
+    )
+}
+
+impl ArrowAssoc for Option<HashMap<String, Option<String>>> {
+    type Builder = StringMapBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MapBuilder::with_capacity(None, StringBuilder::new(), StringBuilder::new(), nrows)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+        append_map(builder, value)
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(header, map_type(), true)
+    }
+}
+
+impl ArrowAssoc for HashMap<String, Option<String>> {
+    type Builder = StringMapBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MapBuilder::with_capacity(None, StringBuilder::new(), StringBuilder::new(), nrows)
+    }
+
+    fn append(builder: &mut Self::Builder, value: Self) -> Result<()> {
+        append_map(builder, Some(value))
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(header, map_type(), false)
+    }
+}
+
+/// Buffers JSON values and decodes them into an array of the type of the column when finished.
+/// A `Utf8` column keeps the JSON text instead.
+#[derive(Debug)]
+pub struct JsonBuilder {
+    values: Vec<Value>,
+    data_type: ArrowDataType,
+}
+
+impl JsonBuilder {
+    fn new(nrows: usize, field: &Field) -> Self {
+        JsonBuilder {
+            values: Vec::with_capacity(nrows),
+            data_type: field.data_type().clone(),
+        }
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn try_finish(&mut self) -> ArrayRef {
+        let values = std::mem::take(&mut self.values);
+        if self.data_type == ArrowDataType::Utf8 {
+            let texts: StringArray = values
+                .into_iter()
+                .map(|value| match value {
+                    Value::Null => None,
+                    Value::String(s) => Some(s),
+                    value => Some(value.to_string()),
+                })
+                .collect();
+            return Arc::new(texts) as ArrayRef;
+        }
+
+        // the decoder reads objects, so wrap each value in one
+        let schema = Schema::new(vec![Field::new("v", self.data_type.clone(), true)]);
+        let rows: Vec<_> = values.into_iter().map(|v| json!({ "v": v })).collect();
+        let mut decoder = ReaderBuilder::new(Arc::new(schema))
+            .with_batch_size(rows.len().max(1))
+            .build_decoder()?;
+        decoder.serialize(&rows)?;
+        match decoder.flush()? {
+            Some(batch) => Arc::clone(batch.column(0)),
+            None => new_empty_array(&self.data_type),
+        }
+    }
+}
+
+impl ArrayBuilder for JsonBuilder {
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        self.try_finish()
+            .unwrap_or_else(|e| panic!("cannot decode JSON column: {}", e))
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        JsonBuilder {
+            values: self.values.clone(),
+            data_type: self.data_type.clone(),
+        }
+        .finish()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+// a JSON null is stored as null, so the column is nullable either way
+impl ArrowAssoc for Option<Value> {
+    type Builder = JsonBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        JsonBuilder::new(nrows, &Self::field(""))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        JsonBuilder::new(nrows, field)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.values.push(value.unwrap_or(Value::Null));
+    }
+
+    fn finish(builder: &mut Self::Builder) -> Result<ArrayRef> {
+        builder.try_finish()
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(header, ArrowDataType::Utf8, true)
+    }
+}
+
+impl ArrowAssoc for Value {
+    type Builder = JsonBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        JsonBuilder::new(nrows, &Self::field(""))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        JsonBuilder::new(nrows, field)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.values.push(value);
+    }
+
+    fn finish(builder: &mut Self::Builder) -> Result<ArrayRef> {
+        builder.try_finish()
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(header, ArrowDataType::Utf8, true)
+    }
+}
+
+fn uuid_field(header: &str, nullable: bool) -> Field {
+    Field::new(header, ArrowDataType::FixedSizeBinary(16), nullable).with_metadata(
+        std::iter::once((
+            ARROW_EXTENSION_NAME_KEY.to_string(),
+            UUID_EXTENSION_NAME.to_string(),
+        ))
+        .collect(),
+    )
+}
+
+impl ArrowAssoc for Option<Uuid> {
+    type Builder = FixedSizeBinaryBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        FixedSizeBinaryBuilder::with_capacity(nrows, 16)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn append(builder: &mut Self::Builder, value: Self) {
+        match value {
+            Some(v) => builder.append_value(v.as_bytes())?,
+            None => builder.append_null(),
+        }
+    }
+
+    fn field(header: &str) -> Field {
+        uuid_field(header, true)
+    }
+}
+
+impl ArrowAssoc for Uuid {
+    type Builder = FixedSizeBinaryBuilder;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        FixedSizeBinaryBuilder::with_capacity(nrows, 16)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn append(builder: &mut Self::Builder, value: Self) {
+        builder.append_value(value.as_bytes())?;
+    }
+
+    fn field(header: &str) -> Field {
+        uuid_field(header, false)
+    }
+}
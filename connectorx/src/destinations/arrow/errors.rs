@@ -10,6 +10,17 @@ pub enum ArrowDestinationError {
     #[error(transparent)]
     ConnectorXError(#[from] crate::errors::ConnectorXError),
 
+    #[cfg(feature = "dst_csv")]
+    #[error(transparent)]
+    CSVError(#[from] csv::Error),
+
+    #[cfg(feature = "dst_parquet")]
+    #[error(transparent)]
+    ParquetError(#[from] parquet::errors::ParquetError),
+
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+
     /// Any other errors that are too trivial to be put here explicitly.
     #[error(transparent)]
     Other(#[from] anyhow::Error),
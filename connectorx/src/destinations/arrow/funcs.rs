@@ -1,15 +1,14 @@
 use super::arrow_assoc::ArrowAssoc;
-use super::Builder;
-use crate::errors::Result;
+use super::{Builder, Result as ArrowResult};
 use crate::typesystem::{ParameterizedFunc, ParameterizedOn};
 use anyhow::anyhow;
-use arrow::array::{ArrayBuilder, ArrayRef};
+use arrow::array::ArrayRef;
 use arrow::datatypes::Field;
 
 pub struct FNewBuilder;
 
 impl ParameterizedFunc for FNewBuilder {
-    type Function = fn(nrows: usize) -> Builder;
+    type Function = fn(nrows: usize, field: &Field) -> Builder;
 }
 
 impl<T> ParameterizedOn<T> for FNewBuilder
@@ -17,11 +16,11 @@ where
     T: ArrowAssoc,
 {
     fn parameterize() -> Self::Function {
-        fn imp<T>(nrows: usize) -> Builder
+        fn imp<T>(nrows: usize, field: &Field) -> Builder
         where
             T: ArrowAssoc,
         {
-            Box::new(T::builder(nrows)) as Builder
+            Box::new(T::builder_for(nrows, field)) as Builder
         }
         imp::<T>
     }
@@ -30,7 +29,7 @@ where
 pub struct FFinishBuilder;
 
 impl ParameterizedFunc for FFinishBuilder {
-    type Function = fn(Builder) -> Result<ArrayRef>;
+    type Function = fn(Builder) -> ArrowResult<ArrayRef>;
 }
 
 impl<T> ParameterizedOn<T> for FFinishBuilder
@@ -38,15 +37,14 @@ where
     T: ArrowAssoc,
 {
     fn parameterize() -> Self::Function {
-        fn imp<T>(mut builder: Builder) -> Result<ArrayRef>
+        fn imp<T>(mut builder: Builder) -> ArrowResult<ArrayRef>
         where
             T: ArrowAssoc,
         {
             let t = builder
                 .downcast_mut::<T::Builder>()
                 .ok_or_else(|| anyhow!("cannot cast arrow builder for finish"))?;
-            let a = ArrayBuilder::finish(t);
-            Ok(a)
+            T::finish(t)
         }
         imp::<T>
     }
@@ -7,18 +7,29 @@ pub mod typesystem;
 
 pub use self::errors::{ArrowDestinationError, Result};
 pub use self::typesystem::ArrowTypeSystem;
-use super::{Consume, Destination, DestinationPartition};
-use crate::constants::RECORD_BATCH_SIZE;
+use super::{ArrowOptions, Consume, Destination, DestinationPartition};
+use crate::constants::{
+    DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE, RECORD_BATCH_SIZE,
+};
 use crate::data_order::DataOrder;
+use crate::errors::Result as CXResult;
+use crate::sources::ColumnDescriptor;
 use crate::typesystem::{Realize, TypeAssoc, TypeSystem};
 use anyhow::anyhow;
-use arrow::{datatypes::Schema, record_batch::RecordBatch};
-use arrow_assoc::ArrowAssoc;
+use arrow::{
+    datatypes::{DataType, Schema, TimeUnit},
+    json::reader::infer_json_schema_from_iterator,
+    record_batch::RecordBatch,
+};
+use arrow_assoc::{ArrowAssoc, ArrowTimeZone};
 use fehler::{throw, throws};
 use funcs::{FFinishBuilder, FNewBuilder, FNewField};
 use itertools::Itertools;
+use log::debug;
+use serde_json::{json, Value};
 use std::{
     any::Any,
+    collections::HashMap,
     sync::{Arc, Mutex},
 };
 
@@ -31,6 +42,12 @@ pub struct ArrowDestination {
     data: Arc<Mutex<Vec<RecordBatch>>>,
     arrow_schema: Arc<Schema>,
     batch_size: usize,
+    options: ArrowOptions,
+    decimal_precision_scale: Vec<Option<(u8, i8)>>,
+    json_samples: Vec<Option<Vec<Value>>>,
+    timestamp_precisions: Vec<Option<u8>>,
+    timezones: Vec<Option<String>>,
+    column_descriptors: Vec<ColumnDescriptor>,
 }
 
 impl Default for ArrowDestination {
@@ -41,6 +58,12 @@ impl Default for ArrowDestination {
             data: Arc::new(Mutex::new(vec![])),
             arrow_schema: Arc::new(Schema::empty()),
             batch_size: RECORD_BATCH_SIZE,
+            options: ArrowOptions::default(),
+            decimal_precision_scale: vec![],
+            json_samples: vec![],
+            timestamp_precisions: vec![],
+            timezones: vec![],
+            column_descriptors: vec![],
         }
     }
 }
@@ -52,11 +75,15 @@ impl ArrowDestination {
 
     pub fn new_with_batch_size(batch_size: usize) -> Self {
         ArrowDestination {
-            schema: vec![],
-            names: vec![],
-            data: Arc::new(Mutex::new(vec![])),
-            arrow_schema: Arc::new(Schema::empty()),
             batch_size,
+            ..Self::default()
+        }
+    }
+
+    pub fn new_with_options(options: ArrowOptions) -> Self {
+        ArrowDestination {
+            options,
+            ..Self::default()
         }
     }
 }
@@ -71,6 +98,90 @@ impl Destination for ArrowDestination {
         false
     }
 
+    fn needs_decimal_precision_scale(&self) -> bool {
+        self.options.decimal128
+    }
+
+    fn set_decimal_precision_scale(&mut self, params: Vec<Option<(u8, i8)>>) {
+        self.decimal_precision_scale = params;
+    }
+
+    fn needs_json_samples(&self) -> bool {
+        self.options.json_nested
+    }
+
+    fn set_json_samples(&mut self, samples: Vec<Option<Vec<Value>>>) {
+        self.json_samples = samples;
+    }
+
+    fn needs_timestamp_precisions(&self) -> bool {
+        self.options.timestamp_unit
+    }
+
+    fn set_timestamp_precisions(&mut self, precisions: Vec<Option<u8>>) {
+        self.timestamp_precisions = precisions;
+    }
+
+    fn needs_timezones(&self) -> bool {
+        self.options.timestamp_tz
+    }
+
+    fn set_timezones(&mut self, timezones: Vec<Option<String>>) {
+        self.timezones = timezones;
+    }
+
+    fn needs_column_descriptors(&self) -> bool {
+        self.options.column_metadata
+    }
+
+    fn set_column_descriptors(&mut self, descriptors: Vec<ColumnDescriptor>) {
+        self.column_descriptors = descriptors;
+    }
+
+    fn type_overrides(&self) -> CXResult<HashMap<String, ArrowTypeSystem>> {
+        self.options
+            .type_overrides_as("arrow", ArrowTypeSystem::from_name)
+    }
+
+    fn preferred_types(&self, ty: ArrowTypeSystem) -> Vec<ArrowTypeSystem> {
+        use ArrowTypeSystem::*;
+
+        let mut preferred = vec![];
+        match ty {
+            Float64(nullable) => {
+                if self.options.decimal128 {
+                    preferred.push(Decimal(nullable));
+                }
+                if self.options.keep_width {
+                    preferred.push(Float32(nullable));
+                }
+            }
+            // a source type has at most one rule per width, so the order only matters for readability.
+            // Booleans are left out, e.g. MySQL TINYINT(1) stays a Boolean.
+            Int64(nullable) | Int32(nullable) if self.options.keep_width => {
+                preferred.extend([Int8(nullable), Int16(nullable), Int32(nullable)]);
+                preferred.extend([UInt8(nullable), UInt16(nullable), UInt32(nullable)]);
+            }
+            UInt64(nullable) | UInt32(nullable) if self.options.keep_width => {
+                preferred.extend([UInt8(nullable), UInt16(nullable), UInt32(nullable)]);
+            }
+            LargeUtf8(nullable) => {
+                if self.options.hstore_map {
+                    preferred.push(Map(nullable));
+                }
+                if self.options.json_nested {
+                    preferred.push(Json(nullable));
+                }
+                if self.options.uuid_binary {
+                    preferred.push(Uuid(nullable));
+                }
+            }
+            _ => {}
+        }
+        preferred.retain(|t| *t != ty);
+        preferred
+    }
+
     #[throws(ArrowDestinationError)]
     fn allocate<S: AsRef<str>>(
         &mut self,
@@ -93,7 +204,39 @@ impl Destination for ArrowDestination {
             .schema
             .iter()
             .zip_eq(&self.names)
-            .map(|(&dt, h)| Ok(Realize::<FNewField>::realize(dt)?(h.as_str())))
+            .enumerate()
+            .map(|(i, (&dt, h))| {
+                let mut field = Realize::<FNewField>::realize(dt)?(h.as_str());
+                if let Some(descriptor) = self.column_descriptors.get(i) {
+                    let mut metadata = field.metadata().clone();
+                    metadata.extend(descriptor.metadata());
+                    field = field.with_metadata(metadata);
+                }
+                Ok(match field.data_type() {
+                    DataType::Decimal128(..) => {
+                        let (precision, scale) = self.decimal_type(i);
+                        field.with_data_type(DataType::Decimal128(precision, scale))
+                    }
+                    _ if matches!(dt, ArrowTypeSystem::Json(_)) => {
+                        field.with_data_type(self.json_type(i)?)
+                    }
+                    DataType::Timestamp(unit, tz) => {
+                        let unit = self.timestamp_unit(i, unit);
+                        let tz = self.timestamp_tz(i, dt, tz)?;
+                        field.with_data_type(DataType::Timestamp(unit, tz))
+                    }
+                    DataType::Utf8
+                        if matches!(dt, ArrowTypeSystem::Dictionary(_))
+                            || self.options.dictionary_columns.contains(h) =>
+                    {
+                        field.with_data_type(DataType::Dictionary(
+                            Box::new(DataType::Int32),
+                            Box::new(DataType::Utf8),
+                        ))
+                    }
+                    _ => field,
+                })
+            })
             .collect::<Result<Vec<_>>>()?;
         self.arrow_schema = Arc::new(Schema::new(fields));
     }
@@ -104,7 +247,7 @@ impl Destination for ArrowDestination {
         for _ in 0..counts {
             partitions.push(ArrowPartitionWriter::new(
                 self.schema.clone(),
-                Arc::clone(&self.data),
+                Box::new(Arc::clone(&self.data)),
                 Arc::clone(&self.arrow_schema),
                 self.batch_size,
             )?);
@@ -145,6 +288,109 @@ impl ArrowDestination {
     pub fn names(&self) -> &[String] {
         self.names.as_slice()
     }
+
+    /// Precision and scale of the decimal column `i`, the defaults if the source does not
+    /// fix them or they do not fit in a `Decimal128`.
+    fn decimal_type(&self, i: usize) -> (u8, i8) {
+        match self.decimal_precision_scale.get(i) {
+            Some(&Some((precision, scale)))
+                if (1..=38).contains(&precision) && scale.unsigned_abs() <= precision =>
+            {
+                (precision, scale)
+            }
+            _ => (DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE),
+        }
+    }
+
+    /// Unit of the timestamp column `i`, the one of `default` if the source does not give its
+    /// precision.
+    fn timestamp_unit(&self, i: usize, default: &TimeUnit) -> TimeUnit {
+        if !self.options.timestamp_unit {
+            return default.clone();
+        }
+        match self.timestamp_precisions.get(i) {
+            Some(Some(0)) => TimeUnit::Second,
+            Some(Some(1..=3)) => TimeUnit::Millisecond,
+            Some(Some(4..=6)) => TimeUnit::Microsecond,
+            Some(Some(_)) => TimeUnit::Nanosecond,
+            _ => default.clone(),
+        }
+    }
+
+    /// Time zone of the timestamp column `i` of type `dt`. Naive timestamps are tagged with
+    /// `naive_timezone` and time zone aware ones with the time zone given by the source,
+    /// which is skipped if arrow cannot read it.
+    #[throws(ArrowDestinationError)]
+    fn timestamp_tz(
+        &self,
+        i: usize,
+        dt: ArrowTypeSystem,
+        default: &Option<Arc<str>>,
+    ) -> Option<Arc<str>> {
+        use ArrowTypeSystem::*;
+
+        match dt {
+            Date64(_) | Date64Micro(_) => match &self.options.naive_timezone {
+                Some(tz) if ArrowTimeZone::parse(tz).is_some() => Some(tz.as_str().into()),
+                Some(tz) => throw!(anyhow!("unknown time zone: {}", tz)),
+                None => default.clone(),
+            },
+            DateTimeTz(_) | DateTimeTzMicro(_) if self.options.timestamp_tz => {
+                match self.timezones.get(i) {
+                    Some(Some(tz)) if ArrowTimeZone::parse(tz).is_some() => {
+                        Some(tz.as_str().into())
+                    }
+                    Some(Some(tz)) => {
+                        debug!(
+                            "Keep the default time zone of column {}, cannot read {}",
+                            i, tz
+                        );
+                        default.clone()
+                    }
+                    _ => default.clone(),
+                }
+            }
+            _ => default.clone(),
+        }
+    }
+
+    /// Type of the JSON column `i`, as given in the options or inferred from the samples of
+    /// the column. Columns without samples keep their text.
+    #[throws(ArrowDestinationError)]
+    fn json_type(&self, i: usize) -> DataType {
+        if let Some(data_type) = self.options.json_types.get(&self.names[i]) {
+            return data_type.clone();
+        }
+        match self.json_samples.get(i) {
+            Some(Some(samples)) if !samples.is_empty() => {
+                // the inference reads objects, so wrap each value in one
+                let schema =
+                    infer_json_schema_from_iterator(samples.iter().map(|v| Ok(json!({ "v": v }))))?;
+                schema.field(0).data_type().clone()
+            }
+            _ => DataType::Utf8,
+        }
+    }
+}
+
+/// Where an [`ArrowPartitionWriter`] puts the record batches it fills, e.g. the memory of the
+/// [`ArrowDestination`] or a file.
+pub trait RecordBatchSink: Send {
+    fn write(&mut self, rb: RecordBatch) -> Result<()>;
+
+    /// Called once the partition wrote its last batch.
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl RecordBatchSink for Arc<Mutex<Vec<RecordBatch>>> {
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: RecordBatch) {
+        let mut guard = self.lock().map_err(|e| anyhow!("mutex poisoned {}", e))?;
+        let inner_data = &mut *guard;
+        inner_data.push(rb);
+    }
 }
 
 pub struct ArrowPartitionWriter {
@@ -152,7 +398,7 @@ pub struct ArrowPartitionWriter {
     builders: Option<Builders>,
     current_row: usize,
     current_col: usize,
-    data: Arc<Mutex<Vec<RecordBatch>>>,
+    sink: Box<dyn RecordBatchSink>,
     arrow_schema: Arc<Schema>,
     batch_size: usize,
 }
@@ -161,9 +407,9 @@ pub struct ArrowPartitionWriter {
 
 impl ArrowPartitionWriter {
     #[throws(ArrowDestinationError)]
-    fn new(
+    pub(crate) fn new(
         schema: Vec<ArrowTypeSystem>,
-        data: Arc<Mutex<Vec<RecordBatch>>>,
+        sink: Box<dyn RecordBatchSink>,
         arrow_schema: Arc<Schema>,
         batch_size: usize,
     ) -> Self {
@@ -172,7 +418,7 @@ impl ArrowPartitionWriter {
             builders: None,
             current_row: 0,
             current_col: 0,
-            data,
+            sink,
             arrow_schema,
             batch_size,
         };
@@ -185,7 +431,13 @@ impl ArrowPartitionWriter {
         let builders = self
             .schema
             .iter()
-            .map(|dt| Ok(Realize::<FNewBuilder>::realize(*dt)?(self.batch_size)))
+            .zip(self.arrow_schema.fields())
+            .map(|(dt, field)| {
+                Ok(Realize::<FNewBuilder>::realize(*dt)?(
+                    self.batch_size,
+                    field,
+                ))
+            })
             .collect::<Result<Vec<_>>>()?;
         self.builders.replace(builders);
     }
@@ -200,16 +452,9 @@ impl ArrowPartitionWriter {
             .into_iter()
             .zip(self.schema.iter())
             .map(|(builder, &dt)| Realize::<FFinishBuilder>::realize(dt)?(builder))
-            .collect::<std::result::Result<Vec<_>, crate::errors::ConnectorXError>>()?;
+            .collect::<Result<Vec<_>>>()?;
         let rb = RecordBatch::try_new(Arc::clone(&self.arrow_schema), columns)?;
-        {
-            let mut guard = self
-                .data
-                .lock()
-                .map_err(|e| anyhow!("mutex poisoned {}", e))?;
-            let inner_data = &mut *guard;
-            inner_data.push(rb);
-        }
+        self.sink.write(rb)?;
 
         self.current_row = 0;
         self.current_col = 0;
@@ -225,6 +470,7 @@ impl<'a> DestinationPartition<'a> for ArrowPartitionWriter {
         if self.builders.is_some() {
             self.flush()?;
         }
+        self.sink.finish()?;
     }
 
     #[throws(ArrowDestinationError)]
@@ -1,5 +1,9 @@
 use crate::impl_typesystem;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy)]
 pub struct DateTimeWrapperMicro(pub DateTime<Utc>);
@@ -10,16 +14,30 @@ pub struct NaiveTimeWrapperMicro(pub NaiveTime);
 #[derive(Debug, Clone, Copy)]
 pub struct NaiveDateTimeWrapperMicro(pub NaiveDateTime);
 
+/// An interval of months, days and nanoseconds, stored as `Interval(MonthDayNano)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntervalMonthDayNano {
+    pub months: i32,
+    pub days: i32,
+    pub nanoseconds: i64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ArrowTypeSystem {
+    Int8(bool),
+    Int16(bool),
     Int32(bool),
     Int64(bool),
+    UInt8(bool),
+    UInt16(bool),
     UInt32(bool),
     UInt64(bool),
     Float32(bool),
     Float64(bool),
+    Decimal(bool),
     Boolean(bool),
     LargeUtf8(bool),
+    Dictionary(bool),
     LargeBinary(bool),
     Date32(bool),
     Date64(bool),
@@ -28,19 +46,36 @@ pub enum ArrowTypeSystem {
     Time64Micro(bool),
     DateTimeTz(bool),
     DateTimeTzMicro(bool),
+    Interval(bool),
+    Map(bool),
+    Json(bool),
+    Uuid(bool),
+    BoolArray(bool),
+    Int32Array(bool),
+    Int64Array(bool),
+    UInt32Array(bool),
+    UInt64Array(bool),
+    Float32Array(bool),
+    Float64Array(bool),
+    Utf8Array(bool),
 }
 
 impl_typesystem! {
     system = ArrowTypeSystem,
     mappings = {
+        { Int8       => i8            }
+        { Int16      => i16           }
         { Int32      => i32           }
         { Int64      => i64           }
+        { UInt8      => u8            }
+        { UInt16     => u16           }
         { UInt32     => u32           }
         { UInt64     => u64           }
         { Float64    => f64           }
         { Float32    => f32           }
+        { Decimal    => Decimal       }
         { Boolean    => bool          }
-        { LargeUtf8  => String        }
+        { LargeUtf8 | Dictionary => String }
         { LargeBinary => Vec<u8>      }
         { Date32     => NaiveDate     }
         { Date64     => NaiveDateTime }
@@ -49,5 +84,17 @@ impl_typesystem! {
         { Time64Micro     => NaiveTimeWrapperMicro }
         { DateTimeTz => DateTime<Utc> }
         { DateTimeTzMicro => DateTimeWrapperMicro }
+        { Interval   => IntervalMonthDayNano }
+        { Map        => HashMap<String, Option<String>> }
+        { Json       => Value         }
+        { Uuid       => Uuid          }
+        { BoolArray    => Vec<bool>     }
+        { Int32Array   => Vec<i32>      }
+        { Int64Array   => Vec<i64>      }
+        { UInt32Array  => Vec<u32>      }
+        { UInt64Array  => Vec<u64>      }
+        { Float32Array => Vec<f32>      }
+        { Float64Array => Vec<f64>      }
+        { Utf8Array    => Vec<String>   }
     }
 }
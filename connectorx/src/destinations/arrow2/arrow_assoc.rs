@@ -1,17 +1,30 @@
 use super::typesystem::{DateTimeWrapperMicro, NaiveDateTimeWrapperMicro, NaiveTimeWrapperMicro};
 use arrow2::{
     array::*,
-    datatypes::{DataType as ArrowDataType, Field, TimeUnit},
+    bitmap::MutableBitmap,
+    datatypes::{DataType as ArrowDataType, Field, IntervalUnit, TimeUnit},
+    types::months_days_ns,
 };
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 
-use crate::constants::SECONDS_IN_DAY;
+use crate::constants::{
+    ARROW_EXTENSION_NAME_KEY, DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE,
+    SECONDS_IN_DAY, UUID_EXTENSION_NAME,
+};
+use crate::utils::decimal_to_i128;
+use rust_decimal::Decimal;
+use std::any::Any;
+use uuid::Uuid;
 
 /// Associate arrow builder with native type
 pub trait ArrowAssoc {
     type Builder: MutableArray + 'static + Send;
 
     fn builder(nrows: usize) -> Self::Builder;
+    /// Create a builder for the column `field`, whose type may be more specific than `field()`.
+    fn builder_for(nrows: usize, _field: &Field) -> Self::Builder {
+        Self::builder(nrows)
+    }
     fn push(builder: &mut Self::Builder, value: Self);
     fn field(header: &str) -> Field;
 }
@@ -163,16 +176,101 @@ impl ArrowAssoc for Option<&str> {
     }
 }
 
+/// Builds a string column, dictionary-encoded with `i32` keys if its field is a `Dictionary`.
+#[derive(Debug)]
+pub enum Utf8Builder {
+    Plain(MutableUtf8Array<i64>),
+    Dictionary(Box<MutableDictionaryArray<i32, MutableUtf8Array<i32>>>),
+}
+
+impl Utf8Builder {
+    fn new(nrows: usize, field: &Field) -> Self {
+        match field.data_type() {
+            ArrowDataType::Dictionary(..) => {
+                let mut builder = Box::new(MutableDictionaryArray::new());
+                builder.reserve(nrows);
+                Utf8Builder::Dictionary(builder)
+            }
+            _ => Utf8Builder::Plain(MutableUtf8Array::with_capacity(nrows)),
+        }
+    }
+
+    fn push(&mut self, value: Option<&str>) {
+        match self {
+            Utf8Builder::Plain(builder) => builder.push(value),
+            Utf8Builder::Dictionary(builder) => builder
+                .try_push(value)
+                .expect("a batch has fewer distinct values than i32::MAX"),
+        }
+    }
+
+    fn inner(&self) -> &dyn MutableArray {
+        match self {
+            Utf8Builder::Plain(builder) => builder,
+            Utf8Builder::Dictionary(builder) => builder.as_ref(),
+        }
+    }
+
+    fn inner_mut(&mut self) -> &mut dyn MutableArray {
+        match self {
+            Utf8Builder::Plain(builder) => builder,
+            Utf8Builder::Dictionary(builder) => builder.as_mut(),
+        }
+    }
+}
+
+impl MutableArray for Utf8Builder {
+    fn data_type(&self) -> &ArrowDataType {
+        self.inner().data_type()
+    }
+
+    fn len(&self) -> usize {
+        self.inner().len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.inner().validity()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.inner_mut().as_box()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.inner_mut().push_null()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner_mut().reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.inner_mut().shrink_to_fit()
+    }
+}
+
 impl ArrowAssoc for String {
-    type Builder = MutableUtf8Array<i64>;
+    type Builder = Utf8Builder;
 
     fn builder(nrows: usize) -> Self::Builder {
-        MutableUtf8Array::<i64>::with_capacity(nrows)
+        Utf8Builder::Plain(MutableUtf8Array::with_capacity(nrows))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        Utf8Builder::new(nrows, field)
     }
 
     #[inline]
     fn push(builder: &mut Self::Builder, value: String) {
-        builder.push(Some(value));
+        builder.push(Some(value.as_str()));
     }
 
     fn field(header: &str) -> Field {
@@ -181,15 +279,19 @@ impl ArrowAssoc for String {
 }
 
 impl ArrowAssoc for Option<String> {
-    type Builder = MutableUtf8Array<i64>;
+    type Builder = Utf8Builder;
 
     fn builder(nrows: usize) -> Self::Builder {
-        MutableUtf8Array::with_capacity(nrows)
+        Utf8Builder::Plain(MutableUtf8Array::with_capacity(nrows))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        Utf8Builder::new(nrows, field)
     }
 
     #[inline]
     fn push(builder: &mut Self::Builder, value: Self) {
-        builder.push(value);
+        builder.push(value.as_deref());
     }
 
     fn field(header: &str) -> Field {
@@ -598,3 +700,163 @@ impl ArrowAssoc for Vec<String> {
         Field::new(header, ArrowDataType::LargeUtf8, false)
     }
 }
+
+impl ArrowAssoc for Option<months_days_ns> {
+    type Builder = MutablePrimitiveArray<months_days_ns>;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MutablePrimitiveArray::with_capacity(nrows)
+            .to(ArrowDataType::Interval(IntervalUnit::MonthDayNano))
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, value: Self) {
+        builder.push(value);
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(
+            header,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+            true,
+        )
+    }
+}
+
+impl ArrowAssoc for months_days_ns {
+    type Builder = MutablePrimitiveArray<months_days_ns>;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MutablePrimitiveArray::with_capacity(nrows)
+            .to(ArrowDataType::Interval(IntervalUnit::MonthDayNano))
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, value: Self) {
+        builder.push(Some(value));
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(
+            header,
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+            false,
+        )
+    }
+}
+
+fn decimal_type() -> ArrowDataType {
+    ArrowDataType::Decimal(
+        DEFAULT_ARROW_DECIMAL_PRECISION as usize,
+        DEFAULT_ARROW_DECIMAL_SCALE as usize,
+    )
+}
+
+fn decimal_builder(nrows: usize, field: &Field) -> MutablePrimitiveArray<i128> {
+    let data_type = match field.data_type() {
+        ArrowDataType::Decimal(..) => field.data_type().clone(),
+        _ => decimal_type(),
+    };
+    MutablePrimitiveArray::with_capacity_from(nrows, data_type)
+}
+
+fn decimal_to_value(builder: &MutablePrimitiveArray<i128>, value: Decimal) -> i128 {
+    let (precision, scale) = match builder.data_type() {
+        ArrowDataType::Decimal(precision, scale) => (*precision, *scale),
+        _ => unreachable!("decimal builder of {:?}", builder.data_type()),
+    };
+    decimal_to_i128(value, precision as u8, scale as i8).unwrap_or_else(|| {
+        panic!(
+            "{} does not fit in Decimal({}, {})",
+            value, precision, scale
+        )
+    })
+}
+
+impl ArrowAssoc for Option<Decimal> {
+    type Builder = MutablePrimitiveArray<i128>;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MutablePrimitiveArray::with_capacity_from(nrows, decimal_type())
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        decimal_builder(nrows, field)
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, value: Self) {
+        let value = value.map(|v| decimal_to_value(builder, v));
+        builder.push(value);
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(header, decimal_type(), true)
+    }
+}
+
+impl ArrowAssoc for Decimal {
+    type Builder = MutablePrimitiveArray<i128>;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MutablePrimitiveArray::with_capacity_from(nrows, decimal_type())
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        decimal_builder(nrows, field)
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, value: Self) {
+        let value = decimal_to_value(builder, value);
+        builder.push(Some(value));
+    }
+
+    fn field(header: &str) -> Field {
+        Field::new(header, decimal_type(), false)
+    }
+}
+
+fn uuid_field(header: &str, nullable: bool) -> Field {
+    Field::new(header, ArrowDataType::FixedSizeBinary(16), nullable).with_metadata(
+        std::iter::once((
+            ARROW_EXTENSION_NAME_KEY.to_string(),
+            UUID_EXTENSION_NAME.to_string(),
+        ))
+        .collect(),
+    )
+}
+
+impl ArrowAssoc for Option<Uuid> {
+    type Builder = MutableFixedSizeBinaryArray;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MutableFixedSizeBinaryArray::with_capacity(16, nrows)
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, value: Self) {
+        builder.push(value.as_ref().map(Uuid::as_bytes));
+    }
+
+    fn field(header: &str) -> Field {
+        uuid_field(header, true)
+    }
+}
+
+impl ArrowAssoc for Uuid {
+    type Builder = MutableFixedSizeBinaryArray;
+
+    fn builder(nrows: usize) -> Self::Builder {
+        MutableFixedSizeBinaryArray::with_capacity(16, nrows)
+    }
+
+    #[inline]
+    fn push(builder: &mut Self::Builder, value: Self) {
+        builder.push(Some(value.as_bytes()));
+    }
+
+    fn field(header: &str) -> Field {
+        uuid_field(header, false)
+    }
+}
@@ -5,33 +5,53 @@ mod errors;
 mod funcs;
 pub mod typesystem;
 
-use super::{Consume, Destination, DestinationPartition};
-use crate::constants::RECORD_BATCH_SIZE;
+use super::{ArrowOptions, Consume, Destination, DestinationPartition};
+use crate::constants::{
+    DEFAULT_ARROW_DECIMAL_PRECISION, DEFAULT_ARROW_DECIMAL_SCALE, RECORD_BATCH_SIZE,
+};
 use crate::data_order::DataOrder;
+use crate::errors::Result as CXResult;
+use crate::sources::ColumnDescriptor;
 use crate::typesystem::{Realize, TypeAssoc, TypeSystem};
 use anyhow::anyhow;
 use arrow2::array::{Array, MutableArray};
 use arrow2::chunk::Chunk;
-use arrow2::datatypes::{Field, Schema};
+use arrow2::datatypes::{DataType, Field, IntegerType, Schema};
 use arrow_assoc::ArrowAssoc;
 pub use errors::{Arrow2DestinationError, Result};
 use fehler::throw;
 use fehler::throws;
 use funcs::{FFinishBuilder, FNewBuilder, FNewField};
 use polars::prelude::{DataFrame, PolarsError, Series};
+use std::collections::HashMap;
 use std::convert::TryFrom;
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    mpsc::{sync_channel, Receiver, SyncSender},
+    Arc, Mutex,
+};
 pub use typesystem::Arrow2TypeSystem;
 
 type Builder = Box<dyn MutableArray + 'static + Send>;
 type Builders = Vec<Builder>;
 type ChunkBuffer = Arc<Mutex<Vec<Chunk<Box<dyn Array>>>>>;
 
+/// Where the partitions put the chunks they finish.
+enum ChunkSink {
+    Buffer(ChunkBuffer),
+    Stream(SyncSender<Chunk<Box<dyn Array>>>),
+}
+
 pub struct Arrow2Destination {
     schema: Vec<Arrow2TypeSystem>,
     names: Vec<String>,
     data: ChunkBuffer,
     arrow_schema: Arc<Schema>,
+    options: ArrowOptions,
+    decimal_precision_scale: Vec<Option<(u8, i8)>>,
+    column_descriptors: Vec<ColumnDescriptor>,
+    batch_size: usize,
+    streaming: bool,
+    receiver: Option<Receiver<Chunk<Box<dyn Array>>>>,
 }
 
 impl Default for Arrow2Destination {
@@ -41,6 +61,12 @@ impl Default for Arrow2Destination {
             names: vec![],
             data: Arc::new(Mutex::new(vec![])),
             arrow_schema: Arc::new(Schema::default()),
+            options: ArrowOptions::default(),
+            decimal_precision_scale: vec![],
+            column_descriptors: vec![],
+            batch_size: RECORD_BATCH_SIZE,
+            streaming: false,
+            receiver: None,
         }
     }
 }
@@ -49,6 +75,25 @@ impl Arrow2Destination {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub fn new_with_options(options: ArrowOptions) -> Self {
+        Arrow2Destination {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// A destination handing out its chunks of at most `batch_size` rows through
+    /// [`chunk`](Self::chunk) while they are written, instead of keeping them all.
+    /// The partitions wait for the chunks to be taken once each one has a chunk pending.
+    pub fn new_streaming(batch_size: usize, options: ArrowOptions) -> Self {
+        Arrow2Destination {
+            options,
+            batch_size,
+            streaming: true,
+            ..Self::default()
+        }
+    }
 }
 
 impl Destination for Arrow2Destination {
@@ -61,6 +106,39 @@ impl Destination for Arrow2Destination {
         false
     }
 
+    fn needs_decimal_precision_scale(&self) -> bool {
+        self.options.decimal128
+    }
+
+    fn set_decimal_precision_scale(&mut self, params: Vec<Option<(u8, i8)>>) {
+        self.decimal_precision_scale = params;
+    }
+
+    fn needs_column_descriptors(&self) -> bool {
+        self.options.column_metadata
+    }
+
+    fn set_column_descriptors(&mut self, descriptors: Vec<ColumnDescriptor>) {
+        self.column_descriptors = descriptors;
+    }
+
+    fn type_overrides(&self) -> CXResult<HashMap<String, Arrow2TypeSystem>> {
+        self.options
+            .type_overrides_as("arrow2", Arrow2TypeSystem::from_name)
+    }
+
+    fn preferred_types(&self, ty: Arrow2TypeSystem) -> Vec<Arrow2TypeSystem> {
+        match ty {
+            Arrow2TypeSystem::Float64(nullable) if self.options.decimal128 => {
+                vec![Arrow2TypeSystem::Decimal(nullable)]
+            }
+            Arrow2TypeSystem::LargeUtf8(nullable) if self.options.uuid_binary => {
+                vec![Arrow2TypeSystem::Uuid(nullable)]
+            }
+            _ => vec![],
+        }
+    }
+
     #[throws(Arrow2DestinationError)]
     fn allocate<S: AsRef<str>>(
         &mut self,
@@ -75,6 +153,15 @@ impl Destination for Arrow2Destination {
                 data_order
             ))
         }
+        self.options.check_supported(
+            "arrow2",
+            &[
+                "decimal128",
+                "uuid_binary",
+                "column_metadata",
+                "dictionary_columns",
+            ],
+        )?;
 
         // parse the metadata
         self.schema = schema.to_vec();
@@ -83,21 +170,54 @@ impl Destination for Arrow2Destination {
             .schema
             .iter()
             .zip(&self.names)
-            .map(|(&dt, h)| Ok(Realize::<FNewField>::realize(dt)?(h.as_str())))
+            .enumerate()
+            .map(|(i, (&dt, h))| {
+                let mut field = Realize::<FNewField>::realize(dt)?(h.as_str());
+                if let DataType::Decimal(..) = field.data_type {
+                    let (precision, scale) = self.decimal_type(i);
+                    field.data_type = DataType::Decimal(precision, scale);
+                }
+                if matches!(dt, Arrow2TypeSystem::Dictionary(_))
+                    || (matches!(dt, Arrow2TypeSystem::LargeUtf8(_))
+                        && self.options.dictionary_columns.contains(h))
+                {
+                    field.data_type =
+                        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+                }
+                if let Some(descriptor) = self.column_descriptors.get(i) {
+                    field.metadata.extend(descriptor.metadata());
+                }
+                Ok(field)
+            })
             .collect::<Result<Vec<_>>>()?;
         self.arrow_schema = Arc::new(Schema::from(fields));
     }
 
     #[throws(Arrow2DestinationError)]
     fn partition(&mut self, counts: usize) -> Vec<Self::Partition<'_>> {
+        let sender = match self.streaming {
+            true => {
+                let (tx, rx) = sync_channel(counts.max(1));
+                self.receiver = Some(rx);
+                Some(tx)
+            }
+            false => None,
+        };
         let mut partitions = vec![];
         for _ in 0..counts {
+            let sink = match &sender {
+                Some(tx) => ChunkSink::Stream(tx.clone()),
+                None => ChunkSink::Buffer(Arc::clone(&self.data)),
+            };
             partitions.push(ArrowPartitionWriter::new(
                 self.schema.clone(),
-                Arc::clone(&self.data),
+                sink,
+                Arc::clone(&self.arrow_schema),
+                self.batch_size,
             )?);
         }
         partitions
+        // the sender is dropped so that the stream ends with the partitions
     }
 
     fn schema(&self) -> &[Arrow2TypeSystem] {
@@ -106,6 +226,22 @@ impl Destination for Arrow2Destination {
 }
 
 impl Arrow2Destination {
+    /// Precision and scale of the decimal column `i`, the defaults if the source does not
+    /// fix them or they do not fit in a 128-bit decimal.
+    fn decimal_type(&self, i: usize) -> (usize, usize) {
+        match self.decimal_precision_scale.get(i) {
+            Some(&Some((precision, scale)))
+                if (1..=38).contains(&precision) && scale >= 0 && scale as u8 <= precision =>
+            {
+                (precision as usize, scale as usize)
+            }
+            _ => (
+                DEFAULT_ARROW_DECIMAL_PRECISION as usize,
+                DEFAULT_ARROW_DECIMAL_SCALE as usize,
+            ),
+        }
+    }
+
     #[throws(Arrow2DestinationError)]
     pub fn arrow(self) -> (Vec<Chunk<Box<dyn Array>>>, Arc<Schema>) {
         let lock = Arc::try_unwrap(self.data).map_err(|_| anyhow!("Partitions are not freed"))?;
@@ -122,48 +258,51 @@ impl Arrow2Destination {
         //let fields = schema.fields.as_slice();
         let fields: &[Field] = schema.fields.as_slice();
 
-        // This should be in polars but their version needs updating.
-        // Whave placed this here contained in an inner function until the fix is merged upstream
-        fn try_from(
-            chunks: (Vec<Chunk<Box<dyn Array>>>, &[Field]),
-        ) -> std::result::Result<DataFrame, PolarsError> {
-            use polars::prelude::NamedFrom;
-
-            let mut series: Vec<Series> = vec![];
-
-            for chunk in chunks.0.into_iter() {
-                let columns_results: std::result::Result<Vec<Series>, PolarsError> = chunk
-                    .into_arrays()
-                    .into_iter()
-                    .zip(chunks.1)
-                    .map(|(arr, field)| {
-                        let a = Series::try_from((field.name.as_str(), arr)).map_err(|_| {
-                            PolarsError::ComputeError("Couldn't build Series from box".into())
-                        });
-                        a
-                    })
-                    .collect();
-
-                let columns = columns_results?;
-
-                if series.is_empty() {
-                    for col in columns.iter() {
-                        let name = col.name().to_string();
-                        series.push(Series::new(&name, col));
-                    }
-                    continue;
-                }
+        polars_from_chunks(rbs, fields).unwrap()
+    }
 
-                for (i, col) in columns.into_iter().enumerate() {
-                    series[i].append(&col)?;
-                }
-            }
+    /// The next chunk written by the partitions of a streaming destination, `None` once
+    /// they are all finalized.
+    pub fn chunk(&mut self) -> Option<Chunk<Box<dyn Array>>> {
+        self.receiver.as_ref().and_then(|rx| rx.recv().ok())
+    }
 
-            DataFrame::new(series)
-        }
+    pub fn arrow_schema(&self) -> Arc<Schema> {
+        self.arrow_schema.clone()
+    }
+
+    pub fn names(&self) -> &[String] {
+        self.names.as_slice()
+    }
+}
 
-        try_from((rbs, fields)).unwrap()
+/// The data frame of `chunks` with the columns of `fields`.
+// This should be in polars but their version needs updating.
+// Whave placed this here until the fix is merged upstream
+pub(crate) fn polars_from_chunks(
+    chunks: Vec<Chunk<Box<dyn Array>>>,
+    fields: &[Field],
+) -> std::result::Result<DataFrame, PolarsError> {
+    // build each column from all its chunks at once, since categorical chunks with
+    // different dictionaries cannot be appended to each other
+    let mut columns: Vec<Vec<Box<dyn Array>>> = fields.iter().map(|_| vec![]).collect();
+    for chunk in chunks.into_iter() {
+        for (column, arr) in columns.iter_mut().zip(chunk.into_arrays()) {
+            column.push(arr);
+        }
     }
+
+    let series = columns
+        .into_iter()
+        .zip(fields)
+        .filter(|(arrs, _)| !arrs.is_empty())
+        .map(|(arrs, field)| {
+            Series::try_from((field.name.as_str(), arrs))
+                .map_err(|_| PolarsError::ComputeError("Couldn't build Series from box".into()))
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    DataFrame::new(series)
 }
 
 pub struct ArrowPartitionWriter {
@@ -171,18 +310,27 @@ pub struct ArrowPartitionWriter {
     builders: Option<Builders>,
     current_row: usize,
     current_col: usize,
-    data: ChunkBuffer,
+    sink: Option<ChunkSink>,
+    arrow_schema: Arc<Schema>,
+    batch_size: usize,
 }
 
 impl ArrowPartitionWriter {
     #[throws(Arrow2DestinationError)]
-    fn new(schema: Vec<Arrow2TypeSystem>, data: ChunkBuffer) -> Self {
+    fn new(
+        schema: Vec<Arrow2TypeSystem>,
+        sink: ChunkSink,
+        arrow_schema: Arc<Schema>,
+        batch_size: usize,
+    ) -> Self {
         let mut pw = ArrowPartitionWriter {
             schema,
             builders: None,
             current_row: 0,
             current_col: 0,
-            data,
+            sink: Some(sink),
+            arrow_schema,
+            batch_size,
         };
         pw.allocate()?;
         pw
@@ -193,7 +341,8 @@ impl ArrowPartitionWriter {
         let builders = self
             .schema
             .iter()
-            .map(|&dt| Ok(Realize::<FNewBuilder>::realize(dt)?(RECORD_BATCH_SIZE)))
+            .zip(&self.arrow_schema.fields)
+            .map(|(&dt, field)| Ok(Realize::<FNewBuilder>::realize(dt)?(self.batch_size, field)))
             .collect::<Result<Vec<_>>>()?;
         self.builders.replace(builders);
     }
@@ -213,13 +362,16 @@ impl ArrowPartitionWriter {
             )?;
 
         let rb = Chunk::try_new(columns)?;
-        {
-            let mut guard = self
-                .data
-                .lock()
-                .map_err(|e| anyhow!("mutex poisoned {}", e))?;
-            let inner_data = &mut *guard;
-            inner_data.push(rb);
+        match &self.sink {
+            Some(ChunkSink::Buffer(data)) => {
+                let mut guard = data.lock().map_err(|e| anyhow!("mutex poisoned {}", e))?;
+                let inner_data = &mut *guard;
+                inner_data.push(rb);
+            }
+            Some(ChunkSink::Stream(sender)) => sender
+                .send(rb)
+                .map_err(|_| anyhow!("the chunk stream is closed"))?,
+            None => throw!(anyhow!("partition is already finalized")),
         }
         self.current_row = 0;
         self.current_col = 0;
@@ -239,6 +391,8 @@ impl<'a> DestinationPartition<'a> for ArrowPartitionWriter {
         if self.builders.is_some() {
             self.flush()?;
         }
+        // release the sender so that the stream ends once every partition is finalized
+        std::mem::drop(self.sink.take());
     }
 
     #[throws(Arrow2DestinationError)]
@@ -275,7 +429,7 @@ where
         // flush if exceed batch_size
         if self.current_col == 0 {
             self.current_row += 1;
-            if self.current_row >= RECORD_BATCH_SIZE {
+            if self.current_row >= self.batch_size {
                 self.flush()?;
                 self.allocate()?;
             }
@@ -1,5 +1,8 @@
 use crate::impl_typesystem;
+use arrow2::types::months_days_ns;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy)]
 pub struct DateTimeWrapperMicro(pub DateTime<Utc>);
@@ -18,8 +21,10 @@ pub enum Arrow2TypeSystem {
     UInt64(bool),
     Float32(bool),
     Float64(bool),
+    Decimal(bool),
     Boolean(bool),
     LargeUtf8(bool),
+    Dictionary(bool),
     LargeBinary(bool),
     Date32(bool),
     Date64(bool),
@@ -36,6 +41,8 @@ pub enum Arrow2TypeSystem {
     Float32Array(bool),
     Float64Array(bool),
     Utf8Array(bool),
+    Interval(bool),
+    Uuid(bool),
 }
 
 impl_typesystem! {
@@ -47,8 +54,9 @@ impl_typesystem! {
         { UInt64          => u64           }
         { Float64         => f64           }
         { Float32         => f32           }
+        { Decimal         => Decimal       }
         { Boolean         => bool          }
-        { LargeUtf8       => String        }
+        { LargeUtf8 | Dictionary => String }
         { LargeBinary     => Vec<u8>       }
         { Date32          => NaiveDate     }
         { Date64          => NaiveDateTime }
@@ -65,5 +73,7 @@ impl_typesystem! {
         { Float32Array    => Vec<f32>      }
         { Float64Array    => Vec<f64>      }
         { Utf8Array       => Vec<String>   }
+        { Interval        => months_days_ns }
+        { Uuid            => Uuid          }
     }
 }
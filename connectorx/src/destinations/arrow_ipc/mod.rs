@@ -0,0 +1,261 @@
+//! Destination implementation writing Arrow IPC files and streams.
+//!
+//! The columns are built the same way as in the [`arrow`](super::arrow) destination, and the
+//! partitions write their record batches to the output as they fill, e.g. a file, a pipe or
+//! a socket.
+
+use super::arrow::{
+    ArrowDestination, ArrowDestinationError, ArrowPartitionWriter, ArrowTypeSystem, RecordBatchSink,
+};
+use super::{ArrowOptions, Destination};
+use crate::constants::RECORD_BATCH_SIZE;
+use crate::data_order::DataOrder;
+use crate::errors::Result as CXResult;
+use crate::sources::ColumnDescriptor;
+use anyhow::anyhow;
+use arrow::{
+    compute::cast,
+    datatypes::{DataType, Schema, SchemaRef},
+    ipc::writer::{FileWriter, StreamWriter},
+    record_batch::RecordBatch,
+};
+use fehler::throws;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::Write,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+/// Format of the output of the [`ArrowIPCDestination`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IPCFormat {
+    /// The random access file format (`.arrow`, Feather v2). The file format allows a
+    /// single dictionary per column, so dictionary columns are written as their values.
+    File,
+    /// The streaming format, readable as it is written.
+    Stream,
+}
+
+enum IPCWriter<W: Write> {
+    /// The file writer and its schema, with the dictionary columns decoded.
+    File(FileWriter<W>, SchemaRef),
+    Stream(StreamWriter<W>),
+}
+
+impl<W: Write> IPCWriter<W> {
+    #[throws(ArrowDestinationError)]
+    fn new(output: W, schema: &Schema, format: IPCFormat) -> Self {
+        match format {
+            IPCFormat::File => {
+                let schema = Arc::new(decoded_schema(schema));
+                IPCWriter::File(FileWriter::try_new(output, &schema)?, schema)
+            }
+            IPCFormat::Stream => IPCWriter::Stream(StreamWriter::try_new(output, schema)?),
+        }
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: &RecordBatch) {
+        match self {
+            IPCWriter::File(writer, schema) => {
+                // each partition builds its own dictionaries, which the file format cannot
+                // hold, so write their values instead
+                let columns = rb
+                    .columns()
+                    .iter()
+                    .zip(schema.fields())
+                    .map(|(column, field)| match column.data_type() {
+                        DataType::Dictionary(..) => cast(column, field.data_type()),
+                        _ => Ok(Arc::clone(column)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                writer.write(&RecordBatch::try_new(Arc::clone(schema), columns)?)?
+            }
+            IPCWriter::Stream(writer) => writer.write(rb)?,
+        }
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn into_inner(self) -> W {
+        match self {
+            IPCWriter::File(writer, _) => writer.into_inner()?,
+            IPCWriter::Stream(writer) => writer.into_inner()?,
+        }
+    }
+}
+
+/// `schema` with the dictionary fields replaced by their value type.
+fn decoded_schema(schema: &Schema) -> Schema {
+    let fields: Vec<_> = schema
+        .fields()
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::Dictionary(_, value) => field.as_ref().clone().with_data_type(*value.clone()),
+            _ => field.as_ref().clone(),
+        })
+        .collect();
+    Schema::new_with_metadata(fields, schema.metadata().clone())
+}
+
+pub struct ArrowIPCDestination<W: Write + Send + 'static> {
+    arrow: ArrowDestination,
+    format: IPCFormat,
+    output: Option<W>,
+    writer: Option<Arc<Mutex<IPCWriter<W>>>>,
+}
+
+impl ArrowIPCDestination<File> {
+    /// Write to the IPC file `path`.
+    #[throws(ArrowDestinationError)]
+    pub fn create<P: AsRef<Path>>(path: P) -> Self {
+        Self::new(File::create(path)?, IPCFormat::File)
+    }
+}
+
+impl<W: Write + Send + 'static> ArrowIPCDestination<W> {
+    /// Write to `output`, e.g. a file, a socket or `std::io::stdout()`.
+    pub fn new(output: W, format: IPCFormat) -> Self {
+        Self::new_with_options(output, format, ArrowOptions::default())
+    }
+
+    pub fn new_with_options(output: W, format: IPCFormat, options: ArrowOptions) -> Self {
+        ArrowIPCDestination {
+            arrow: ArrowDestination::new_with_options(options),
+            format,
+            output: Some(output),
+            writer: None,
+        }
+    }
+
+    /// Write the end of the file or stream once the partitions are finalized, and return
+    /// the output.
+    #[throws(ArrowDestinationError)]
+    pub fn finish(self) -> W {
+        let writer = match self.writer {
+            Some(writer) => Arc::try_unwrap(writer)
+                .map_err(|_| anyhow!("Partitions are not freed"))?
+                .into_inner()
+                .map_err(|e| anyhow!("mutex poisoned {}", e))?,
+            // nothing was partitioned, still write a valid empty output
+            None => IPCWriter::new(
+                self.output
+                    .ok_or_else(|| anyhow!("the output is lost after a failed partition"))?,
+                &self.arrow.arrow_schema(),
+                self.format,
+            )?,
+        };
+        writer.into_inner()?
+    }
+}
+
+impl<W: Write + Send + 'static> Destination for ArrowIPCDestination<W> {
+    const DATA_ORDERS: &'static [DataOrder] = ArrowDestination::DATA_ORDERS;
+    type TypeSystem = ArrowTypeSystem;
+    type Partition<'a> = ArrowPartitionWriter;
+    type Error = ArrowDestinationError;
+
+    fn needs_count(&self) -> bool {
+        self.arrow.needs_count()
+    }
+
+    fn needs_decimal_precision_scale(&self) -> bool {
+        self.arrow.needs_decimal_precision_scale()
+    }
+
+    fn set_decimal_precision_scale(&mut self, params: Vec<Option<(u8, i8)>>) {
+        self.arrow.set_decimal_precision_scale(params)
+    }
+
+    fn needs_json_samples(&self) -> bool {
+        self.arrow.needs_json_samples()
+    }
+
+    fn set_json_samples(&mut self, samples: Vec<Option<Vec<Value>>>) {
+        self.arrow.set_json_samples(samples)
+    }
+
+    fn needs_timestamp_precisions(&self) -> bool {
+        self.arrow.needs_timestamp_precisions()
+    }
+
+    fn set_timestamp_precisions(&mut self, precisions: Vec<Option<u8>>) {
+        self.arrow.set_timestamp_precisions(precisions)
+    }
+
+    fn needs_timezones(&self) -> bool {
+        self.arrow.needs_timezones()
+    }
+
+    fn set_timezones(&mut self, timezones: Vec<Option<String>>) {
+        self.arrow.set_timezones(timezones)
+    }
+
+    fn needs_column_descriptors(&self) -> bool {
+        self.arrow.needs_column_descriptors()
+    }
+
+    fn set_column_descriptors(&mut self, descriptors: Vec<ColumnDescriptor>) {
+        self.arrow.set_column_descriptors(descriptors)
+    }
+
+    fn type_overrides(&self) -> CXResult<HashMap<String, ArrowTypeSystem>> {
+        self.arrow.type_overrides()
+    }
+
+    fn preferred_types(&self, ty: ArrowTypeSystem) -> Vec<ArrowTypeSystem> {
+        self.arrow.preferred_types(ty)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn allocate<S: AsRef<str>>(
+        &mut self,
+        nrow: usize,
+        names: &[S],
+        schema: &[ArrowTypeSystem],
+        data_order: DataOrder,
+    ) {
+        self.arrow.allocate(nrow, names, schema, data_order)?;
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn partition(&mut self, counts: usize) -> Vec<Self::Partition<'_>> {
+        let output = self
+            .output
+            .take()
+            .ok_or_else(|| anyhow!("the destination is already partitioned"))?;
+        let writer = Arc::new(Mutex::new(IPCWriter::new(
+            output,
+            &self.arrow.arrow_schema(),
+            self.format,
+        )?));
+        let mut partitions = vec![];
+        for _ in 0..counts {
+            partitions.push(ArrowPartitionWriter::new(
+                self.arrow.schema().to_vec(),
+                Box::new(IPCSink(Arc::clone(&writer))),
+                self.arrow.arrow_schema(),
+                RECORD_BATCH_SIZE,
+            )?);
+        }
+        self.writer = Some(writer);
+        partitions
+    }
+
+    fn schema(&self) -> &[ArrowTypeSystem] {
+        self.arrow.schema()
+    }
+}
+
+/// Writes the batches of a partition to the output shared by all of them.
+struct IPCSink<W: Write>(Arc<Mutex<IPCWriter<W>>>);
+
+impl<W: Write + Send> RecordBatchSink for IPCSink<W> {
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: RecordBatch) {
+        let mut writer = self.0.lock().map_err(|e| anyhow!("mutex poisoned {}", e))?;
+        writer.write(&rb)?;
+    }
+}
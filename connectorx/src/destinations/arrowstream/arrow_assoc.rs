@@ -1,20 +1,26 @@
 use super::errors::{ArrowDestinationError, Result};
 use crate::constants::SECONDS_IN_DAY;
 use arrow::array::{
-    ArrayBuilder, BooleanBuilder, Date32Builder, Date64Builder, Float32Builder, Float64Builder,
-    Int32Builder, Int64Builder, LargeBinaryBuilder, StringBuilder, Time64NanosecondBuilder,
-    TimestampNanosecondBuilder, UInt32Builder, UInt64Builder,
+    ArrayBuilder, ArrayRef, BooleanBuilder, Date32Builder, Date64Builder, Float32Builder,
+    Float64Builder, Int32Builder, Int64Builder, LargeBinaryBuilder, LargeListBuilder,
+    StringBuilder, StringDictionaryBuilder, Time64NanosecondBuilder, TimestampNanosecondBuilder,
+    UInt32Builder, UInt64Builder,
 };
 use arrow::datatypes::Field;
-use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
+use arrow::datatypes::{DataType as ArrowDataType, Int32Type, TimeUnit};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 use fehler::throws;
+use std::{any::Any, sync::Arc};
 
 /// Associate arrow builder with native type
 pub trait ArrowAssoc {
     type Builder: ArrayBuilder + Send;
 
     fn builder(nrows: usize) -> Self::Builder;
+    /// Create a builder for the column `field`, whose type may be more specific than `field()`.
+    fn builder_for(nrows: usize, _field: &Field) -> Self::Builder {
+        Self::builder(nrows)
+    }
     fn append(builder: &mut Self::Builder, value: Self) -> Result<()>;
     fn field(header: &str) -> Field;
 }
@@ -65,6 +71,58 @@ impl_arrow_assoc!(f32, ArrowDataType::Float32, Float32Builder);
 impl_arrow_assoc!(f64, ArrowDataType::Float64, Float64Builder);
 impl_arrow_assoc!(bool, ArrowDataType::Boolean, BooleanBuilder);
 
+macro_rules! impl_arrow_assoc_vec {
+    ($T:ty, $AT:expr, $B:ty) => {
+        impl ArrowAssoc for Vec<$T> {
+            type Builder = LargeListBuilder<$B>;
+
+            fn builder(nrows: usize) -> Self::Builder {
+                LargeListBuilder::with_capacity(<$B>::new(), nrows)
+            }
+
+            #[throws(ArrowDestinationError)]
+            fn append(builder: &mut Self::Builder, value: Self) {
+                builder.append_value(value.into_iter().map(Some));
+            }
+
+            fn field(header: &str) -> Field {
+                Field::new(header, list_type($AT), false)
+            }
+        }
+
+        impl ArrowAssoc for Option<Vec<$T>> {
+            type Builder = LargeListBuilder<$B>;
+
+            fn builder(nrows: usize) -> Self::Builder {
+                LargeListBuilder::with_capacity(<$B>::new(), nrows)
+            }
+
+            #[throws(ArrowDestinationError)]
+            fn append(builder: &mut Self::Builder, value: Self) {
+                builder.append_option(value.map(|values| values.into_iter().map(Some)));
+            }
+
+            fn field(header: &str) -> Field {
+                Field::new(header, list_type($AT), true)
+            }
+        }
+    };
+}
+
+/// The type of a list column, items are nullable to match what `LargeListBuilder` produces.
+fn list_type(item: ArrowDataType) -> ArrowDataType {
+    ArrowDataType::LargeList(Arc::new(Field::new("item", item, true)))
+}
+
+impl_arrow_assoc_vec!(bool, ArrowDataType::Boolean, BooleanBuilder);
+impl_arrow_assoc_vec!(i32, ArrowDataType::Int32, Int32Builder);
+impl_arrow_assoc_vec!(i64, ArrowDataType::Int64, Int64Builder);
+impl_arrow_assoc_vec!(u32, ArrowDataType::UInt32, UInt32Builder);
+impl_arrow_assoc_vec!(u64, ArrowDataType::UInt64, UInt64Builder);
+impl_arrow_assoc_vec!(f32, ArrowDataType::Float32, Float32Builder);
+impl_arrow_assoc_vec!(f64, ArrowDataType::Float64, Float64Builder);
+impl_arrow_assoc_vec!(String, ArrowDataType::Utf8, StringBuilder);
+
 impl ArrowAssoc for &str {
     type Builder = StringBuilder;
 
@@ -102,16 +160,83 @@ impl ArrowAssoc for Option<&str> {
     }
 }
 
+/// Builds a string column, dictionary-encoded with `Int32` keys if its field is a `Dictionary`.
+pub enum Utf8Builder {
+    Plain(StringBuilder),
+    Dictionary(StringDictionaryBuilder<Int32Type>),
+}
+
+impl Utf8Builder {
+    fn new(nrows: usize, field: &Field) -> Self {
+        match field.data_type() {
+            ArrowDataType::Dictionary(..) => {
+                Utf8Builder::Dictionary(StringDictionaryBuilder::with_capacity(nrows, 64, 1024))
+            }
+            _ => Utf8Builder::Plain(StringBuilder::with_capacity(1024, nrows)),
+        }
+    }
+
+    fn append_option(&mut self, value: Option<&str>) {
+        match self {
+            Utf8Builder::Plain(builder) => builder.append_option(value),
+            Utf8Builder::Dictionary(builder) => builder.append_option(value),
+        }
+    }
+}
+
+impl ArrayBuilder for Utf8Builder {
+    fn len(&self) -> usize {
+        match self {
+            Utf8Builder::Plain(builder) => builder.len(),
+            Utf8Builder::Dictionary(builder) => builder.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Utf8Builder::Plain(builder) => Arc::new(builder.finish()),
+            Utf8Builder::Dictionary(builder) => Arc::new(builder.finish()),
+        }
+    }
+
+    fn finish_cloned(&self) -> ArrayRef {
+        match self {
+            Utf8Builder::Plain(builder) => Arc::new(builder.finish_cloned()),
+            Utf8Builder::Dictionary(builder) => Arc::new(builder.finish_cloned()),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn into_box_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
 impl ArrowAssoc for String {
-    type Builder = StringBuilder;
+    type Builder = Utf8Builder;
 
     fn builder(nrows: usize) -> Self::Builder {
-        StringBuilder::with_capacity(1024, nrows)
+        Utf8Builder::Plain(StringBuilder::with_capacity(1024, nrows))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        Utf8Builder::new(nrows, field)
     }
 
     #[throws(ArrowDestinationError)]
     fn append(builder: &mut Self::Builder, value: String) {
-        builder.append_value(value.as_str());
+        builder.append_option(Some(value.as_str()));
     }
 
     fn field(header: &str) -> Field {
@@ -120,18 +245,19 @@ impl ArrowAssoc for String {
 }
 
 impl ArrowAssoc for Option<String> {
-    type Builder = StringBuilder;
+    type Builder = Utf8Builder;
 
     fn builder(nrows: usize) -> Self::Builder {
-        StringBuilder::with_capacity(1024, nrows)
+        Utf8Builder::Plain(StringBuilder::with_capacity(1024, nrows))
+    }
+
+    fn builder_for(nrows: usize, field: &Field) -> Self::Builder {
+        Utf8Builder::new(nrows, field)
     }
 
     #[throws(ArrowDestinationError)]
     fn append(builder: &mut Self::Builder, value: Self) {
-        match value {
-            Some(s) => builder.append_value(s.as_str()),
-            None => builder.append_null(),
-        }
+        builder.append_option(value.as_deref());
     }
 
     fn field(header: &str) -> Field {
@@ -7,18 +7,23 @@ pub mod typesystem;
 
 pub use self::errors::{ArrowDestinationError, Result};
 pub use self::typesystem::ArrowTypeSystem;
-use super::{Consume, Destination, DestinationPartition};
+use super::{ArrowOptions, Consume, Destination, DestinationPartition};
 use crate::constants::RECORD_BATCH_SIZE;
 use crate::data_order::DataOrder;
+use crate::errors::Result as CXResult;
 use crate::typesystem::{Realize, TypeAssoc, TypeSystem};
 use anyhow::anyhow;
-use arrow::{datatypes::Schema, record_batch::RecordBatch};
+use arrow::{
+    datatypes::{DataType, Schema},
+    record_batch::RecordBatch,
+};
 use arrow_assoc::ArrowAssoc;
 use fehler::{throw, throws};
 use funcs::{FFinishBuilder, FNewBuilder, FNewField};
 use itertools::Itertools;
 use std::{
     any::Any,
+    collections::HashMap,
     sync::{
         mpsc::{channel, Receiver, Sender},
         Arc,
@@ -35,6 +40,7 @@ pub struct ArrowDestination {
     batch_size: usize,
     sender: Option<Sender<RecordBatch>>,
     receiver: Receiver<RecordBatch>,
+    options: ArrowOptions,
 }
 
 impl Default for ArrowDestination {
@@ -47,6 +53,7 @@ impl Default for ArrowDestination {
             batch_size: RECORD_BATCH_SIZE,
             sender: Some(tx),
             receiver: rx,
+            options: ArrowOptions::default(),
         }
     }
 }
@@ -57,14 +64,17 @@ impl ArrowDestination {
     }
 
     pub fn new_with_batch_size(batch_size: usize) -> Self {
-        let (tx, rx) = channel();
         ArrowDestination {
-            schema: vec![],
-            names: vec![],
-            arrow_schema: Arc::new(Schema::empty()),
             batch_size,
-            sender: Some(tx),
-            receiver: rx,
+            ..Self::default()
+        }
+    }
+
+    pub fn new_with_options(batch_size: usize, options: ArrowOptions) -> Self {
+        ArrowDestination {
+            batch_size,
+            options,
+            ..Self::default()
         }
     }
 }
@@ -79,6 +89,11 @@ impl Destination for ArrowDestination {
         false
     }
 
+    fn type_overrides(&self) -> CXResult<HashMap<String, ArrowTypeSystem>> {
+        self.options
+            .type_overrides_as("record batch iterator", ArrowTypeSystem::from_name)
+    }
+
     #[throws(ArrowDestinationError)]
     fn allocate<S: AsRef<str>>(
         &mut self,
@@ -93,6 +108,8 @@ impl Destination for ArrowDestination {
                 data_order
             ))
         }
+        self.options
+            .check_supported("record batch iterator", &["dictionary_columns"])?;
 
         // parse the metadata
         self.schema = schema.to_vec();
@@ -101,7 +118,21 @@ impl Destination for ArrowDestination {
             .schema
             .iter()
             .zip_eq(&self.names)
-            .map(|(&dt, h)| Ok(Realize::<FNewField>::realize(dt)?(h.as_str())))
+            .map(|(&dt, h)| {
+                let field = Realize::<FNewField>::realize(dt)?(h.as_str());
+                Ok(match field.data_type() {
+                    DataType::Utf8
+                        if matches!(dt, ArrowTypeSystem::Dictionary(_))
+                            || self.options.dictionary_columns.contains(h) =>
+                    {
+                        field.with_data_type(DataType::Dictionary(
+                            Box::new(DataType::Int32),
+                            Box::new(DataType::Utf8),
+                        ))
+                    }
+                    _ => field,
+                })
+            })
             .collect::<Result<Vec<_>>>()?;
         self.arrow_schema = Arc::new(Schema::new(fields));
     }
@@ -204,7 +235,8 @@ impl ArrowPartitionWriter {
         let builders = self
             .schema
             .iter()
-            .map(|dt| Ok(Realize::<FNewBuilder>::realize(*dt)?(self.batch_size)))
+            .zip(self.arrow_schema.fields())
+            .map(|(&dt, field)| Ok(Realize::<FNewBuilder>::realize(dt)?(self.batch_size, field)))
             .collect::<Result<Vec<_>>>()?;
         self.builders.replace(builders);
     }
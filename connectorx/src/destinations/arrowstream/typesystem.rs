@@ -11,11 +11,20 @@ pub enum ArrowTypeSystem {
     Float64(bool),
     Boolean(bool),
     LargeUtf8(bool),
+    Dictionary(bool),
     LargeBinary(bool),
     Date32(bool),
     Date64(bool),
     Time64(bool),
     DateTimeTz(bool),
+    BoolArray(bool),
+    Int32Array(bool),
+    Int64Array(bool),
+    UInt32Array(bool),
+    UInt64Array(bool),
+    Float32Array(bool),
+    Float64Array(bool),
+    Utf8Array(bool),
 }
 
 impl_typesystem! {
@@ -28,11 +37,19 @@ impl_typesystem! {
         { Float64    => f64           }
         { Float32    => f32           }
         { Boolean    => bool          }
-        { LargeUtf8  => String        }
+        { LargeUtf8 | Dictionary => String }
         { LargeBinary => Vec<u8>      }
         { Date32     => NaiveDate     }
         { Date64     => NaiveDateTime }
         { Time64     => NaiveTime     }
         { DateTimeTz => DateTime<Utc> }
+        { BoolArray    => Vec<bool>     }
+        { Int32Array   => Vec<i32>      }
+        { Int64Array   => Vec<i64>      }
+        { UInt32Array  => Vec<u32>      }
+        { UInt64Array  => Vec<u64>      }
+        { Float32Array => Vec<f32>      }
+        { Float64Array => Vec<f64>      }
+        { Utf8Array    => Vec<String>   }
     }
 }
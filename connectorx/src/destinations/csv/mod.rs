@@ -0,0 +1,311 @@
+//! Destination implementation writing CSV files.
+//!
+//! The columns are built the same way as in the [`arrow`](super::arrow) destination, and each
+//! partition writes the rows of its record batches to the file as they fill.
+
+use super::arrow::{
+    ArrowDestination, ArrowDestinationError, ArrowPartitionWriter, ArrowTypeSystem, RecordBatchSink,
+};
+use super::{ArrowOptions, Destination};
+use crate::constants::RECORD_BATCH_SIZE;
+use crate::data_order::DataOrder;
+use crate::errors::Result as CXResult;
+use crate::sources::ColumnDescriptor;
+use anyhow::anyhow;
+use arrow::{
+    error::ArrowError,
+    record_batch::RecordBatch,
+    util::display::{ArrayFormatter, FormatOptions},
+};
+use csv::Writer;
+use fehler::throws;
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+pub use csv::QuoteStyle;
+
+/// Dialect of the CSV files written by the [`CSVDestination`].
+#[derive(Debug, Clone)]
+pub struct CSVWriteOptions {
+    /// Field delimiter, `,` by default.
+    pub delimiter: u8,
+    /// Quote character, `"` by default.
+    pub quote: u8,
+    /// Which fields are quoted, the ones containing a delimiter, a quote or a newline by default.
+    pub quote_style: QuoteStyle,
+    /// Whether to write the column names as the first record.
+    pub has_header: bool,
+    /// Value written for nulls, the empty field by default. It is quoted as any text with
+    /// `QuoteStyle::Always` and `QuoteStyle::NonNumeric`.
+    pub null_value: String,
+    /// `chrono` format of dates, RFC 3339 if `None`.
+    pub date_format: Option<String>,
+    /// `chrono` format of times, RFC 3339 if `None`.
+    pub time_format: Option<String>,
+    /// `chrono` format of timestamps without time zone, RFC 3339 if `None`.
+    pub datetime_format: Option<String>,
+    /// `chrono` format of timestamps with time zone, RFC 3339 if `None`.
+    pub datetime_tz_format: Option<String>,
+    /// Write each partition to its own file in the directory of the destination, named
+    /// `part-<i>.csv`, instead of writing all of them to a single file.
+    pub file_per_partition: bool,
+    /// Options of the Arrow columns the files are written from.
+    pub arrow: ArrowOptions,
+}
+
+impl Default for CSVWriteOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            quote_style: QuoteStyle::Necessary,
+            has_header: true,
+            null_value: String::new(),
+            date_format: None,
+            time_format: None,
+            datetime_format: None,
+            datetime_tz_format: None,
+            file_per_partition: false,
+            arrow: ArrowOptions::default(),
+        }
+    }
+}
+
+impl CSVWriteOptions {
+    fn format_options(&self) -> FormatOptions<'_> {
+        FormatOptions::new()
+            .with_display_error(false)
+            .with_null(&self.null_value)
+            .with_date_format(self.date_format.as_deref())
+            .with_time_format(self.time_format.as_deref())
+            .with_datetime_format(self.datetime_format.as_deref())
+            .with_timestamp_format(self.datetime_format.as_deref())
+            .with_timestamp_tz_format(self.datetime_tz_format.as_deref())
+    }
+
+    /// Open a writer of `path` with this dialect, writing the header `names` if needed.
+    #[throws(ArrowDestinationError)]
+    fn writer<S: AsRef<str>>(&self, path: &Path, names: &[S]) -> Writer<File> {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quote_style(self.quote_style)
+            .from_path(path)?;
+        if self.has_header {
+            writer.write_record(names.iter().map(|n| n.as_ref()))?;
+        }
+        writer
+    }
+
+    /// Write the rows of `rb` to `writer`.
+    #[throws(ArrowDestinationError)]
+    fn write_rows(&self, writer: &mut Writer<File>, rb: &RecordBatch) {
+        let format_options = self.format_options();
+        let formatters = rb
+            .columns()
+            .iter()
+            .map(|c| ArrayFormatter::try_new(c.as_ref(), &format_options))
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+        let mut record = vec![String::new(); formatters.len()];
+        for row in 0..rb.num_rows() {
+            for (field, formatter) in record.iter_mut().zip(&formatters) {
+                field.clear();
+                formatter.value(row).write(field)?;
+            }
+            writer.write_record(&record)?;
+        }
+    }
+}
+
+pub struct CSVDestination {
+    arrow: ArrowDestination,
+    path: PathBuf,
+    options: Arc<CSVWriteOptions>,
+    writer: Option<Arc<Mutex<Writer<File>>>>,
+    files: Vec<PathBuf>,
+}
+
+impl CSVDestination {
+    /// Write to the file `path`, or to files in the directory `path` if
+    /// `file_per_partition` is set.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self::new_with_options(path, CSVWriteOptions::default())
+    }
+
+    pub fn new_with_options<P: Into<PathBuf>>(path: P, options: CSVWriteOptions) -> Self {
+        CSVDestination {
+            arrow: ArrowDestination::new_with_options(options.arrow.clone()),
+            path: path.into(),
+            options: Arc::new(options),
+            writer: None,
+            files: vec![],
+        }
+    }
+
+    /// Flush the files and return their paths. The single file is only complete once this
+    /// is called, after the partitions are finalized.
+    #[throws(ArrowDestinationError)]
+    pub fn finish(self) -> Vec<PathBuf> {
+        if let Some(writer) = self.writer {
+            writer
+                .lock()
+                .map_err(|e| anyhow!("mutex poisoned {}", e))?
+                .flush()?;
+        }
+        self.files
+    }
+}
+
+impl Destination for CSVDestination {
+    const DATA_ORDERS: &'static [DataOrder] = ArrowDestination::DATA_ORDERS;
+    type TypeSystem = ArrowTypeSystem;
+    type Partition<'a> = ArrowPartitionWriter;
+    type Error = ArrowDestinationError;
+
+    fn needs_count(&self) -> bool {
+        self.arrow.needs_count()
+    }
+
+    fn needs_decimal_precision_scale(&self) -> bool {
+        self.arrow.needs_decimal_precision_scale()
+    }
+
+    fn set_decimal_precision_scale(&mut self, params: Vec<Option<(u8, i8)>>) {
+        self.arrow.set_decimal_precision_scale(params)
+    }
+
+    fn needs_json_samples(&self) -> bool {
+        self.arrow.needs_json_samples()
+    }
+
+    fn set_json_samples(&mut self, samples: Vec<Option<Vec<Value>>>) {
+        self.arrow.set_json_samples(samples)
+    }
+
+    fn needs_timestamp_precisions(&self) -> bool {
+        self.arrow.needs_timestamp_precisions()
+    }
+
+    fn set_timestamp_precisions(&mut self, precisions: Vec<Option<u8>>) {
+        self.arrow.set_timestamp_precisions(precisions)
+    }
+
+    fn needs_timezones(&self) -> bool {
+        self.arrow.needs_timezones()
+    }
+
+    fn set_timezones(&mut self, timezones: Vec<Option<String>>) {
+        self.arrow.set_timezones(timezones)
+    }
+
+    fn needs_column_descriptors(&self) -> bool {
+        self.arrow.needs_column_descriptors()
+    }
+
+    fn set_column_descriptors(&mut self, descriptors: Vec<ColumnDescriptor>) {
+        self.arrow.set_column_descriptors(descriptors)
+    }
+
+    fn type_overrides(&self) -> CXResult<HashMap<String, ArrowTypeSystem>> {
+        self.arrow.type_overrides()
+    }
+
+    fn preferred_types(&self, ty: ArrowTypeSystem) -> Vec<ArrowTypeSystem> {
+        self.arrow.preferred_types(ty)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn allocate<S: AsRef<str>>(
+        &mut self,
+        nrow: usize,
+        names: &[S],
+        schema: &[ArrowTypeSystem],
+        data_order: DataOrder,
+    ) {
+        self.arrow.allocate(nrow, names, schema, data_order)?;
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn partition(&mut self, counts: usize) -> Vec<Self::Partition<'_>> {
+        let names = self.arrow.names();
+        let mut sinks: Vec<Box<dyn RecordBatchSink>> = vec![];
+        if self.options.file_per_partition {
+            fs::create_dir_all(&self.path)?;
+            for i in 0..counts {
+                let path = self.path.join(format!("part-{}.csv", i));
+                sinks.push(Box::new(FileSink {
+                    writer: self.options.writer(&path, names)?,
+                    options: Arc::clone(&self.options),
+                }));
+                self.files.push(path);
+            }
+        } else {
+            let writer = Arc::new(Mutex::new(self.options.writer(&self.path, names)?));
+            for _ in 0..counts {
+                sinks.push(Box::new(SharedFileSink {
+                    writer: Arc::clone(&writer),
+                    options: Arc::clone(&self.options),
+                }));
+            }
+            self.writer = Some(writer);
+            self.files.push(self.path.clone());
+        }
+
+        sinks
+            .into_iter()
+            .map(|sink| {
+                ArrowPartitionWriter::new(
+                    self.arrow.schema().to_vec(),
+                    sink,
+                    self.arrow.arrow_schema(),
+                    RECORD_BATCH_SIZE,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    }
+
+    fn schema(&self) -> &[ArrowTypeSystem] {
+        self.arrow.schema()
+    }
+}
+
+/// Writes the rows of a partition to its own file, which is flushed with the partition.
+struct FileSink {
+    writer: Writer<File>,
+    options: Arc<CSVWriteOptions>,
+}
+
+impl RecordBatchSink for FileSink {
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: RecordBatch) {
+        self.options.write_rows(&mut self.writer, &rb)?;
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn finish(&mut self) {
+        self.writer.flush()?;
+    }
+}
+
+/// Writes the rows of a partition to the file shared by all of them, see
+/// [`CSVDestination::finish`]. The rows of a batch are kept together.
+struct SharedFileSink {
+    writer: Arc<Mutex<Writer<File>>>,
+    options: Arc<CSVWriteOptions>,
+}
+
+impl RecordBatchSink for SharedFileSink {
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: RecordBatch) {
+        let mut writer = self
+            .writer
+            .lock()
+            .map_err(|e| anyhow!("mutex poisoned {}", e))?;
+        self.options.write_rows(&mut writer, &rb)?;
+    }
+}
@@ -3,15 +3,128 @@
 
 #[cfg(feature = "dst_arrow")]
 pub mod arrow;
+#[cfg(feature = "dst_arrow_ipc")]
+pub mod arrow_ipc;
 #[cfg(feature = "dst_arrow")]
 pub mod arrowstream;
 
 #[cfg(feature = "dst_arrow2")]
 pub mod arrow2;
 
+#[cfg(feature = "dst_csv")]
+pub mod csv;
+
+#[cfg(feature = "dst_parquet")]
+pub mod parquet;
+
 use crate::data_order::DataOrder;
 use crate::errors::ConnectorXError;
+use crate::sources::ColumnDescriptor;
 use crate::typesystem::{TypeAssoc, TypeSystem};
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+use fehler::{throw, throws};
+use serde_json::Value;
+use std::collections::HashMap;
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+use std::collections::HashSet;
+
+/// Options of the Arrow destinations for types with more than one representation.
+///
+/// The `arrow` destination implements all of them. The `arrow2` destination only implements
+/// `decimal128`, `uuid_binary`, `column_metadata` and `dictionary_columns`, the record batch
+/// iterator only `dictionary_columns`. Loading into a destination fails if an option it does
+/// not implement is set.
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+#[derive(Debug, Clone, Default)]
+pub struct ArrowOptions {
+    /// Store decimals as `Decimal128` with the precision and scale of their column
+    /// instead of `Float64`. Columns without them, e.g. computed ones, get `Decimal128(38, 10)`,
+    /// and a value that does not fit is an error rather than rounded.
+    pub decimal128: bool,
+    /// Keep the width of narrow integer and float columns (e.g. `Int16`, `Float32`)
+    /// instead of widening them to 64 bits.
+    pub keep_width: bool,
+    /// Store `hstore` columns as `Map<Utf8, Utf8>` instead of JSON text.
+    pub hstore_map: bool,
+    /// Parse JSON columns into nested arrays (e.g. `Struct`, `List`) instead of storing their
+    /// text. The type of a column is taken from `json_types`, or inferred from a sample of
+    /// its values.
+    pub json_nested: bool,
+    /// Store UUIDs as `FixedSizeBinary(16)` with the `arrow.uuid` extension name instead of
+    /// their text.
+    pub uuid_binary: bool,
+    /// Store timestamps in the unit matching the precision of their column, i.e. seconds,
+    /// milliseconds, microseconds or nanoseconds, instead of a fixed unit.
+    pub timestamp_unit: bool,
+    /// Tag time zone aware timestamps with the time zone of their column, e.g. the session
+    /// time zone of the source. The values are the same instants either way.
+    pub timestamp_tz: bool,
+    /// Read timestamps without time zone as local times in this zone, e.g. `Europe/Paris` or
+    /// `+02:00`, and tag them with it.
+    pub naive_timezone: Option<String>,
+    /// Store the source type name, precision, scale, nullability and table of each column
+    /// in the metadata of its field, under the `connectorx.*` keys. Only for the sources
+    /// that implement `Source::column_descriptors`.
+    pub column_metadata: bool,
+    /// Store these string columns as `Dictionary(Int32, Utf8)`, by column name. Enum columns
+    /// are always stored this way.
+    pub dictionary_columns: HashSet<String>,
+    /// The type of JSON columns by column name, see `json_nested`.
+    #[cfg(feature = "dst_arrow")]
+    pub json_types: HashMap<String, ::arrow::datatypes::DataType>,
+    /// The type of columns by column name, overriding the one picked by the transport, e.g.
+    /// `"LargeUtf8"` to read a decimal column as text. Types are given by the name of the
+    /// variant in the type system of the destination, and the nullability of a column is
+    /// kept. Loading fails if a column does not exist, the destination has no such type or
+    /// the transport has no rule from the source type to it.
+    pub type_overrides: HashMap<String, String>,
+}
+
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+impl ArrowOptions {
+    /// Fail if an option is set that is not in the `supported` ones of the destination `name`.
+    #[throws(ConnectorXError)]
+    pub(crate) fn check_supported(&self, name: &'static str, supported: &[&str]) {
+        let set = [
+            ("decimal128", self.decimal128),
+            ("keep_width", self.keep_width),
+            ("hstore_map", self.hstore_map),
+            ("json_nested", self.json_nested),
+            ("uuid_binary", self.uuid_binary),
+            ("timestamp_unit", self.timestamp_unit),
+            ("timestamp_tz", self.timestamp_tz),
+            ("naive_timezone", self.naive_timezone.is_some()),
+            ("column_metadata", self.column_metadata),
+            ("dictionary_columns", !self.dictionary_columns.is_empty()),
+        ];
+        if let Some((option, _)) = set
+            .iter()
+            .find(|(option, is_set)| *is_set && !supported.contains(option))
+        {
+            throw!(ConnectorXError::UnsupportedOption(option, name));
+        }
+    }
+
+    /// `type_overrides` in the types of the destination `name`, looked up by `from_name`.
+    #[throws(ConnectorXError)]
+    pub(crate) fn type_overrides_as<T>(
+        &self,
+        name: &'static str,
+        from_name: impl Fn(&str) -> Option<T>,
+    ) -> HashMap<String, T> {
+        self.type_overrides
+            .iter()
+            .map(|(col, ty)| match from_name(ty) {
+                Some(dt) => Ok((col.clone(), dt)),
+                None => Err(ConnectorXError::UnknownOverrideType(
+                    col.clone(),
+                    name,
+                    ty.clone(),
+                )),
+            })
+            .collect::<Result<_, _>>()?
+    }
+}
 
 /// A `Destination` is associated with a `TypeSystem` and a `PartitionDestination`.
 /// `PartitionDestination` allows multiple threads write data into the buffer owned by `Destination`.
@@ -27,6 +140,63 @@ pub trait Destination: Sized {
     /// in order to pre-allocate the buffer.
     fn needs_count(&self) -> bool;
 
+    /// Specify whether the destination needs the precision and scale of decimal
+    /// columns, which are then passed to `set_decimal_precision_scale` before `allocate`.
+    fn needs_decimal_precision_scale(&self) -> bool {
+        false
+    }
+
+    /// Set the precision and scale of each decimal column, see `Source::decimal_precision_scale`.
+    fn set_decimal_precision_scale(&mut self, _params: Vec<Option<(u8, i8)>>) {}
+
+    /// Specify whether the destination needs sample values of JSON columns, which are then
+    /// passed to `set_json_samples` before `allocate`.
+    fn needs_json_samples(&self) -> bool {
+        false
+    }
+
+    /// Set the sample values of each JSON column, see `Source::json_samples`.
+    fn set_json_samples(&mut self, _samples: Vec<Option<Vec<Value>>>) {}
+
+    /// Specify whether the destination needs the precision of timestamp columns, which is
+    /// then passed to `set_timestamp_precisions` before `allocate`.
+    fn needs_timestamp_precisions(&self) -> bool {
+        false
+    }
+
+    /// Set the precision of each timestamp column, see `Source::timestamp_precisions`.
+    fn set_timestamp_precisions(&mut self, _precisions: Vec<Option<u8>>) {}
+
+    /// Specify whether the destination needs the time zone of timestamp columns, which is
+    /// then passed to `set_timezones` before `allocate`.
+    fn needs_timezones(&self) -> bool {
+        false
+    }
+
+    /// Set the time zone of each timestamp column, see `Source::timezones`.
+    fn set_timezones(&mut self, _timezones: Vec<Option<String>>) {}
+
+    /// Specify whether the destination needs the source descriptor of each column, which is
+    /// then passed to `set_column_descriptors` before `allocate`.
+    fn needs_column_descriptors(&self) -> bool {
+        false
+    }
+
+    /// Set the source descriptor of each column, see `Source::column_descriptors`.
+    fn set_column_descriptors(&mut self, _descriptors: Vec<ColumnDescriptor>) {}
+
+    /// The types the user asked to store columns in by column name, which are used instead
+    /// of the types picked by the transport.
+    fn type_overrides(&self) -> Result<HashMap<String, Self::TypeSystem>, ConnectorXError> {
+        Ok(HashMap::new())
+    }
+
+    /// Types the destination would rather store a column of type `ty` in, most preferred first.
+    /// The first one the transport can convert the source type to is used instead of `ty`.
+    fn preferred_types(&self, _ty: Self::TypeSystem) -> Vec<Self::TypeSystem> {
+        vec![]
+    }
+
     /// Construct the `Destination`.
     /// This allocates the memory based on the types of each columns
     /// and the number of rows.
@@ -0,0 +1,244 @@
+//! Destination implementation writing Parquet files.
+//!
+//! The columns are built the same way as in the [`arrow`](super::arrow) destination, and each
+//! partition writes its record batches to the file as they fill, so that the result is never
+//! held in memory as a whole.
+
+use super::arrow::{
+    ArrowDestination, ArrowDestinationError, ArrowPartitionWriter, ArrowTypeSystem, RecordBatchSink,
+};
+use super::{ArrowOptions, Destination};
+use crate::constants::RECORD_BATCH_SIZE;
+use crate::data_order::DataOrder;
+use crate::errors::Result as CXResult;
+use crate::sources::ColumnDescriptor;
+use anyhow::anyhow;
+use arrow::record_batch::RecordBatch;
+use fehler::throws;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::Compression,
+    file::properties::{WriterProperties, DEFAULT_MAX_ROW_GROUP_SIZE},
+};
+use serde_json::Value;
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// Options of the [`ParquetDestination`].
+#[derive(Debug, Clone)]
+pub struct ParquetOptions {
+    /// Compression of the column chunks.
+    pub compression: Compression,
+    /// Maximum number of rows of a row group.
+    pub row_group_size: usize,
+    /// Write each partition to its own file in the directory of the destination, named
+    /// `part-<i>.parquet`, instead of writing all of them to a single file.
+    pub file_per_partition: bool,
+    /// Options of the Arrow columns the files are written from.
+    pub arrow: ArrowOptions,
+}
+
+impl Default for ParquetOptions {
+    fn default() -> Self {
+        ParquetOptions {
+            compression: Compression::SNAPPY,
+            row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            file_per_partition: false,
+            arrow: ArrowOptions::default(),
+        }
+    }
+}
+
+pub struct ParquetDestination {
+    arrow: ArrowDestination,
+    path: PathBuf,
+    options: ParquetOptions,
+    writer: Option<Arc<Mutex<ArrowWriter<File>>>>,
+    files: Vec<PathBuf>,
+}
+
+impl ParquetDestination {
+    /// Write to the file `path`, or to files in the directory `path` if
+    /// `file_per_partition` is set.
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        Self::new_with_options(path, ParquetOptions::default())
+    }
+
+    pub fn new_with_options<P: Into<PathBuf>>(path: P, options: ParquetOptions) -> Self {
+        ParquetDestination {
+            arrow: ArrowDestination::new_with_options(options.arrow.clone()),
+            path: path.into(),
+            options,
+            writer: None,
+            files: vec![],
+        }
+    }
+
+    /// Close the files and return their paths. The single file is only complete once this
+    /// is called, after the partitions are finalized.
+    #[throws(ArrowDestinationError)]
+    pub fn finish(self) -> Vec<PathBuf> {
+        if let Some(writer) = self.writer {
+            let writer = Arc::try_unwrap(writer)
+                .map_err(|_| anyhow!("Partitions are not freed"))?
+                .into_inner()
+                .map_err(|e| anyhow!("mutex poisoned {}", e))?;
+            writer.close()?;
+        }
+        self.files
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn create_writer(&self, path: &Path) -> ArrowWriter<File> {
+        let props = WriterProperties::builder()
+            .set_compression(self.options.compression)
+            .set_max_row_group_size(self.options.row_group_size)
+            .build();
+        ArrowWriter::try_new(File::create(path)?, self.arrow.arrow_schema(), Some(props))?
+    }
+}
+
+impl Destination for ParquetDestination {
+    const DATA_ORDERS: &'static [DataOrder] = ArrowDestination::DATA_ORDERS;
+    type TypeSystem = ArrowTypeSystem;
+    type Partition<'a> = ArrowPartitionWriter;
+    type Error = ArrowDestinationError;
+
+    fn needs_count(&self) -> bool {
+        self.arrow.needs_count()
+    }
+
+    fn needs_decimal_precision_scale(&self) -> bool {
+        self.arrow.needs_decimal_precision_scale()
+    }
+
+    fn set_decimal_precision_scale(&mut self, params: Vec<Option<(u8, i8)>>) {
+        self.arrow.set_decimal_precision_scale(params)
+    }
+
+    fn needs_json_samples(&self) -> bool {
+        self.arrow.needs_json_samples()
+    }
+
+    fn set_json_samples(&mut self, samples: Vec<Option<Vec<Value>>>) {
+        self.arrow.set_json_samples(samples)
+    }
+
+    fn needs_timestamp_precisions(&self) -> bool {
+        self.arrow.needs_timestamp_precisions()
+    }
+
+    fn set_timestamp_precisions(&mut self, precisions: Vec<Option<u8>>) {
+        self.arrow.set_timestamp_precisions(precisions)
+    }
+
+    fn needs_timezones(&self) -> bool {
+        self.arrow.needs_timezones()
+    }
+
+    fn set_timezones(&mut self, timezones: Vec<Option<String>>) {
+        self.arrow.set_timezones(timezones)
+    }
+
+    fn needs_column_descriptors(&self) -> bool {
+        self.arrow.needs_column_descriptors()
+    }
+
+    fn set_column_descriptors(&mut self, descriptors: Vec<ColumnDescriptor>) {
+        self.arrow.set_column_descriptors(descriptors)
+    }
+
+    fn type_overrides(&self) -> CXResult<HashMap<String, ArrowTypeSystem>> {
+        self.arrow.type_overrides()
+    }
+
+    fn preferred_types(&self, ty: ArrowTypeSystem) -> Vec<ArrowTypeSystem> {
+        self.arrow.preferred_types(ty)
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn allocate<S: AsRef<str>>(
+        &mut self,
+        nrow: usize,
+        names: &[S],
+        schema: &[ArrowTypeSystem],
+        data_order: DataOrder,
+    ) {
+        self.arrow.allocate(nrow, names, schema, data_order)?;
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn partition(&mut self, counts: usize) -> Vec<Self::Partition<'_>> {
+        // a partition hands over its batch once it fills, so keep them below the row groups
+        let batch_size = RECORD_BATCH_SIZE.min(self.options.row_group_size);
+        let mut sinks: Vec<Box<dyn RecordBatchSink>> = vec![];
+        if self.options.file_per_partition {
+            fs::create_dir_all(&self.path)?;
+            for i in 0..counts {
+                let path = self.path.join(format!("part-{}.parquet", i));
+                let writer = self.create_writer(&path)?;
+                sinks.push(Box::new(FileSink(Some(writer))));
+                self.files.push(path);
+            }
+        } else {
+            let writer = Arc::new(Mutex::new(self.create_writer(&self.path)?));
+            for _ in 0..counts {
+                sinks.push(Box::new(SharedFileSink(Arc::clone(&writer))));
+            }
+            self.writer = Some(writer);
+            self.files.push(self.path.clone());
+        }
+
+        sinks
+            .into_iter()
+            .map(|sink| {
+                ArrowPartitionWriter::new(
+                    self.arrow.schema().to_vec(),
+                    sink,
+                    self.arrow.arrow_schema(),
+                    batch_size,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    }
+
+    fn schema(&self) -> &[ArrowTypeSystem] {
+        self.arrow.schema()
+    }
+}
+
+/// Writes the batches of a partition to its own file, which is closed with the partition.
+struct FileSink(Option<ArrowWriter<File>>);
+
+impl RecordBatchSink for FileSink {
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: RecordBatch) {
+        self.0
+            .as_mut()
+            .ok_or_else(|| anyhow!("parquet file is closed"))?
+            .write(&rb)?;
+    }
+
+    #[throws(ArrowDestinationError)]
+    fn finish(&mut self) {
+        if let Some(writer) = self.0.take() {
+            writer.close()?;
+        }
+    }
+}
+
+/// Writes the batches of a partition to the file shared by all of them, see
+/// [`ParquetDestination::finish`].
+struct SharedFileSink(Arc<Mutex<ArrowWriter<File>>>);
+
+impl RecordBatchSink for SharedFileSink {
+    #[throws(ArrowDestinationError)]
+    fn write(&mut self, rb: RecordBatch) {
+        let mut writer = self.0.lock().map_err(|e| anyhow!("mutex poisoned {}", e))?;
+        writer.write(&rb)?;
+    }
+}
@@ -1,6 +1,7 @@
 ///! This module provides [`dispatcher::Dispatcher`], the core struct in ConnectorX
 ///! that drives the data loading from a source to a destination.
 use crate::{
+    constants::JSON_SAMPLE_ROWS,
     data_order::{coordinate, DataOrder},
     destinations::{Destination, DestinationPartition},
     errors::{ConnectorXError, Result as CXResult},
@@ -59,15 +60,12 @@ where
         let dorder = coordinate(S::DATA_ORDERS, D::DATA_ORDERS)?;
         self.src.set_data_order(dorder)?;
         self.src.set_queries(self.queries.as_slice());
-        self.src.set_origin_query(self.origin_query);
+        self.src.set_origin_query(self.origin_query.take());
 
         debug!("Fetching metadata");
         self.src.fetch_metadata()?;
         let src_schema = self.src.schema();
-        let dst_schema = src_schema
-            .iter()
-            .map(|&s| TP::convert_typesystem(s))
-            .collect::<CXResult<Vec<_>>>()?;
+        let dst_schema = self.dst_schema(&src_schema)?;
         let names = self.src.names();
 
         let mut total_rows = if self.dst.needs_count() {
@@ -103,7 +101,7 @@ where
         self.dst.allocate(total_rows, &names, &dst_schema, dorder)?;
 
         debug!("Create destination partition");
-        let dst_partitions = self.dst.partition(self.queries.len())?;
+        let dst_partitions = self.dst.partition(src_partitions.len())?;
 
         Ok((
             dorder,
@@ -198,6 +196,67 @@ where
         Ok(())
     }
 
+    /// Convert the source schema to the destination schema, using the types the user asked
+    /// for, or else the types preferred by the destination when the transport supports them.
+    fn dst_schema(
+        &mut self,
+        src_schema: &[S::TypeSystem],
+    ) -> Result<Vec<D::TypeSystem>, TP::Error> {
+        let names = self.src.names();
+        let overrides = self.dst.type_overrides()?;
+        if let Some(name) = overrides.keys().find(|name| !names.contains(name)) {
+            return Err(ConnectorXError::UnknownOverrideColumn(name.clone()).into());
+        }
+        let dst_schema = src_schema
+            .iter()
+            .zip_eq(&names)
+            .map(|(&s, name)| {
+                if let Some(&d) = overrides.get(name) {
+                    return TP::convert_typesystem_to(s, d).map_err(|e| match e {
+                        ConnectorXError::NoConversionRule(from, to) => {
+                            ConnectorXError::UnsupportedTypeOverride(name.clone(), from, to)
+                        }
+                        e => e,
+                    });
+                }
+                let default = TP::convert_typesystem(s)?;
+                let preferred = self
+                    .dst
+                    .preferred_types(default)
+                    .into_iter()
+                    .find(|&d| TP::can_convert(s, d));
+                Ok(preferred.unwrap_or(default))
+            })
+            .collect::<CXResult<Vec<_>>>()?;
+
+        if self.dst.needs_decimal_precision_scale() {
+            debug!("Fetch precision and scale of decimal columns");
+            let params = self.src.decimal_precision_scale()?;
+            self.dst.set_decimal_precision_scale(params);
+        }
+        if self.dst.needs_json_samples() {
+            debug!("Fetch samples of JSON columns");
+            let samples = self.src.json_samples(JSON_SAMPLE_ROWS)?;
+            self.dst.set_json_samples(samples);
+        }
+        if self.dst.needs_timestamp_precisions() {
+            debug!("Fetch precision of timestamp columns");
+            let precisions = self.src.timestamp_precisions()?;
+            self.dst.set_timestamp_precisions(precisions);
+        }
+        if self.dst.needs_timezones() {
+            debug!("Fetch time zone of timestamp columns");
+            let timezones = self.src.timezones()?;
+            self.dst.set_timezones(timezones);
+        }
+        if self.dst.needs_column_descriptors() {
+            debug!("Fetch source descriptors of columns");
+            let descriptors = self.src.column_descriptors()?;
+            self.dst.set_column_descriptors(descriptors);
+        }
+        Ok(dst_schema)
+    }
+
     /// Only fetch the metadata (header) of the destination.
     pub fn get_meta(&mut self) -> Result<(), TP::Error> {
         let dorder = coordinate(S::DATA_ORDERS, D::DATA_ORDERS)?;
@@ -206,10 +265,7 @@ where
         self.src.set_origin_query(self.origin_query.clone());
         self.src.fetch_metadata()?;
         let src_schema = self.src.schema();
-        let dst_schema = src_schema
-            .iter()
-            .map(|&s| TP::convert_typesystem(s))
-            .collect::<CXResult<Vec<_>>>()?;
+        let dst_schema = self.dst_schema(&src_schema)?;
         let names = self.src.names();
         self.dst.allocate(0, &names, &dst_schema, dorder)?;
         Ok(())
@@ -66,6 +66,14 @@ pub enum ConnectorXOutError {
     #[error(transparent)]
     SQLiteError(#[from] rusqlite::Error),
 
+    #[cfg(feature = "src_duckdb")]
+    #[error(transparent)]
+    DuckDBSourceError(#[from] crate::sources::duckdb::DuckDBSourceError),
+
+    #[cfg(feature = "src_duckdb")]
+    #[error(transparent)]
+    DuckDBError(#[from] duckdb::Error),
+
     #[cfg(feature = "src_oracle")]
     #[error(transparent)]
     OracleSourceError(#[from] crate::sources::oracle::OracleSourceError),
@@ -74,6 +82,14 @@ pub enum ConnectorXOutError {
     #[error(transparent)]
     OracleError(#[from] r2d2_oracle::oracle::Error),
 
+    #[cfg(feature = "src_parquet")]
+    #[error(transparent)]
+    ParquetSourceError(#[from] crate::sources::parquet::ParquetSourceError),
+
+    #[cfg(feature = "src_clickhouse")]
+    #[error(transparent)]
+    ClickHouseSourceError(#[from] crate::sources::clickhouse::ClickHouseSourceError),
+
     #[cfg(feature = "src_bigquery")]
     #[error(transparent)]
     BigQuerySourceError(#[from] crate::sources::bigquery::BigQuerySourceError),
@@ -118,6 +134,22 @@ pub enum ConnectorXOutError {
     #[error(transparent)]
     SQLiteArrow2TransportError(#[from] crate::transports::SQLiteArrow2TransportError),
 
+    #[cfg(all(feature = "src_duckdb", feature = "dst_arrow"))]
+    #[error(transparent)]
+    DuckDBArrowTransportError(#[from] crate::transports::DuckDBArrowTransportError),
+
+    #[cfg(all(feature = "src_duckdb", feature = "dst_arrow2"))]
+    #[error(transparent)]
+    DuckDBArrow2TransportError(#[from] crate::transports::DuckDBArrow2TransportError),
+
+    #[cfg(all(feature = "src_parquet", feature = "dst_arrow"))]
+    #[error(transparent)]
+    ParquetArrowTransportError(#[from] crate::transports::ParquetArrowTransportError),
+
+    #[cfg(all(feature = "src_parquet", feature = "dst_arrow2"))]
+    #[error(transparent)]
+    ParquetArrow2TransportError(#[from] crate::transports::ParquetArrow2TransportError),
+
     #[cfg(all(feature = "src_mssql", feature = "dst_arrow"))]
     #[error(transparent)]
     MsSQLArrowTransportError(#[from] crate::transports::MsSQLArrowTransportError),
@@ -142,6 +174,26 @@ pub enum ConnectorXOutError {
     #[error(transparent)]
     BigqueryArrow2TransportError(#[from] crate::transports::BigQueryArrow2TransportError),
 
+    #[cfg(all(feature = "src_clickhouse", feature = "dst_arrow"))]
+    #[error(transparent)]
+    ClickHouseArrowTransportError(#[from] crate::transports::ClickHouseArrowTransportError),
+
+    #[cfg(all(feature = "src_clickhouse", feature = "dst_arrow2"))]
+    #[error(transparent)]
+    ClickHouseArrow2TransportError(#[from] crate::transports::ClickHouseArrow2TransportError),
+
+    #[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow"))]
+    #[error(transparent)]
+    ArrowIPCArrowTransportError(#[from] crate::transports::ArrowIPCArrowTransportError),
+
+    #[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow2"))]
+    #[error(transparent)]
+    ArrowIPCArrow2TransportError(#[from] crate::transports::ArrowIPCArrow2TransportError),
+
+    #[cfg(all(feature = "src_dummy", feature = "dst_arrow2"))]
+    #[error(transparent)]
+    DummyArrow2TransportError(#[from] crate::transports::DummyArrow2TransportError),
+
     /// Any other errors that are too trivial to be put here explicitly.
     #[error(transparent)]
     Other(#[from] anyhow::Error),
@@ -166,6 +218,21 @@ pub enum ConnectorXError {
     #[error("No conversion rule from {0} to {1}.")]
     NoConversionRule(String, String),
 
+    #[error("Cannot override the type of column {0}: no conversion rule from {1} to {2}.")]
+    UnsupportedTypeOverride(String, String, String),
+
+    #[error("Cannot override the type of column {0}: the {1} destination has no type {2}.")]
+    UnknownOverrideType(String, &'static str, String),
+
+    #[error("Cannot override the type of column {0}: there is no such column.")]
+    UnknownOverrideColumn(String),
+
+    #[error("Option {0} is not supported by the {1} destination.")]
+    UnsupportedOption(&'static str, &'static str),
+
+    #[error("Cannot write column {0} of type {1}.")]
+    UnsupportedWriteType(String, String),
+
     #[error("Only support single query with SELECT statement, got {0}.")]
     SqlQueryNotSupported(String),
 
@@ -1,3 +1,5 @@
+#[cfg(feature = "src_clickhouse")]
+use crate::sources::clickhouse::{ArrowStreamProtocol, RowBinaryProtocol};
 #[cfg(feature = "src_mysql")]
 use crate::sources::mysql::{BinaryProtocol as MySQLBinaryProtocol, TextProtocol};
 #[cfg(feature = "src_postgres")]
@@ -19,14 +21,25 @@ use postgres_openssl::MakeTlsConnector;
 #[allow(unused_imports)]
 use std::sync::Arc;
 
-#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
 #[throws(ConnectorXOutError)]
 pub fn get_arrow(
     source_conn: &SourceConn,
     origin_query: Option<String>,
     queries: &[CXQuery<String>],
 ) -> ArrowDestination {
-    let mut destination = ArrowDestination::new();
+    get_arrow_with_options(source_conn, origin_query, queries, ArrowOptions::default())?
+}
+
+/// Same as `get_arrow`, storing the values as specified by `options`.
+#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
+#[throws(ConnectorXOutError)]
+pub fn get_arrow_with_options(
+    source_conn: &SourceConn,
+    origin_query: Option<String>,
+    queries: &[CXQuery<String>],
+    options: ArrowOptions,
+) -> ArrowDestination {
+    let mut destination = ArrowDestination::new_with_options(options);
     let protocol = source_conn.proto.as_str();
     debug!("Protocol: {}", protocol);
 
@@ -190,6 +203,19 @@ pub fn get_arrow(
             );
             dispatcher.run()?;
         }
+        #[cfg(feature = "src_duckdb")]
+        SourceType::DuckDB => {
+            // remove the first "duckdb://" manually since url.path is not correct for windows
+            let path = &source_conn.conn.as_str()[9..];
+            let source = DuckDBSource::new(path, queries.len())?;
+            let dispatcher = Dispatcher::<_, _, DuckDBArrowTransport>::new(
+                source,
+                &mut destination,
+                queries,
+                origin_query,
+            );
+            dispatcher.run()?;
+        }
         #[cfg(feature = "src_mssql")]
         SourceType::MsSQL => {
             let rt = Arc::new(tokio::runtime::Runtime::new().expect("Failed to create runtime"));
@@ -225,6 +251,43 @@ pub fn get_arrow(
             );
             dispatcher.run()?;
         }
+        #[cfg(feature = "src_arrow_ipc")]
+        SourceType::ArrowIPC => {
+            let source = ArrowIPCSource::from_url(&source_conn.conn);
+            let dispatcher = Dispatcher::<_, _, ArrowIPCArrowTransport>::new(
+                source,
+                &mut destination,
+                queries,
+                origin_query,
+            );
+            dispatcher.run()?;
+        }
+        #[cfg(feature = "src_clickhouse")]
+        SourceType::ClickHouse => match protocol {
+            "binary" => {
+                let source = ClickHouseSource::<RowBinaryProtocol>::new(&source_conn.conn[..])?;
+                let dispatcher =
+                    Dispatcher::<_, _, ClickHouseArrowTransport<RowBinaryProtocol>>::new(
+                        source,
+                        &mut destination,
+                        queries,
+                        origin_query,
+                    );
+                dispatcher.run()?;
+            }
+            "arrow" => {
+                let source = ClickHouseSource::<ArrowStreamProtocol>::new(&source_conn.conn[..])?;
+                let dispatcher =
+                    Dispatcher::<_, _, ClickHouseArrowTransport<ArrowStreamProtocol>>::new(
+                        source,
+                        &mut destination,
+                        queries,
+                        origin_query,
+                    );
+                dispatcher.run()?;
+            }
+            _ => unimplemented!("{} protocol not supported", protocol),
+        },
         _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
             "{:?}",
             source_conn.ty
@@ -234,14 +297,32 @@ pub fn get_arrow(
     destination
 }
 
-#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
 pub fn new_record_batch_iter(
     source_conn: &SourceConn,
     origin_query: Option<String>,
     queries: &[CXQuery<String>],
     batch_size: usize,
 ) -> Box<dyn RecordBatchIterator> {
-    let destination = ArrowStreamDestination::new_with_batch_size(batch_size);
+    new_record_batch_iter_with_options(
+        source_conn,
+        origin_query,
+        queries,
+        batch_size,
+        ArrowOptions::default(),
+    )
+}
+
+/// Same as `new_record_batch_iter`, storing the values as specified by `options`.
+/// Panics if an option the record batch iterator does not implement is set, see `ArrowOptions`.
+#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
+pub fn new_record_batch_iter_with_options(
+    source_conn: &SourceConn,
+    origin_query: Option<String>,
+    queries: &[CXQuery<String>],
+    batch_size: usize,
+    options: ArrowOptions,
+) -> Box<dyn RecordBatchIterator> {
+    let destination = ArrowStreamDestination::new_with_options(batch_size, options);
     let protocol = source_conn.proto.as_str();
     debug!("Protocol: {}", protocol);
 
@@ -384,6 +465,20 @@ pub fn new_record_batch_iter(
             .unwrap();
             return Box::new(batch_iter);
         }
+        #[cfg(feature = "src_duckdb")]
+        SourceType::DuckDB => {
+            // remove the first "duckdb://" manually since url.path is not correct for windows
+            let path = &source_conn.conn.as_str()[9..];
+            let source = DuckDBSource::new(path, queries.len()).unwrap();
+            let batch_iter = ArrowBatchIter::<_, DuckDBArrowStreamTransport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )
+            .unwrap();
+            return Box::new(batch_iter);
+        }
         #[cfg(feature = "src_mssql")]
         SourceType::MsSQL => {
             let rt = Arc::new(tokio::runtime::Runtime::new().expect("Failed to create runtime"));
@@ -422,6 +517,42 @@ pub fn new_record_batch_iter(
             .unwrap();
             return Box::new(batch_iter);
         }
+        #[cfg(feature = "src_arrow_ipc")]
+        SourceType::ArrowIPC => {
+            let source = ArrowIPCSource::from_url(&source_conn.conn);
+            let batch_iter = ArrowBatchIter::<_, ArrowIPCArrowStreamTransport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )
+            .unwrap();
+            return Box::new(batch_iter);
+        }
+        #[cfg(feature = "src_clickhouse")]
+        SourceType::ClickHouse => match protocol {
+            "binary" => {
+                let source =
+                    ClickHouseSource::<RowBinaryProtocol>::new(&source_conn.conn[..]).unwrap();
+                let batch_iter = ArrowBatchIter::<
+                    _,
+                    ClickHouseArrowStreamTransport<RowBinaryProtocol>,
+                >::new(source, destination, origin_query, queries)
+                .unwrap();
+                return Box::new(batch_iter);
+            }
+            "arrow" => {
+                let source =
+                    ClickHouseSource::<ArrowStreamProtocol>::new(&source_conn.conn[..]).unwrap();
+                let batch_iter = ArrowBatchIter::<
+                    _,
+                    ClickHouseArrowStreamTransport<ArrowStreamProtocol>,
+                >::new(source, destination, origin_query, queries)
+                .unwrap();
+                return Box::new(batch_iter);
+            }
+            _ => unimplemented!("{} protocol not supported", protocol),
+        },
         _ => {}
     }
     panic!("not supported!");
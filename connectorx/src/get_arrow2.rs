@@ -1,3 +1,5 @@
+#[cfg(feature = "src_clickhouse")]
+use crate::sources::clickhouse::{ArrowStreamProtocol, RowBinaryProtocol};
 #[cfg(feature = "src_mysql")]
 use crate::sources::mysql::{BinaryProtocol as MySQLBinaryProtocol, TextProtocol};
 #[cfg(feature = "src_postgres")]
@@ -5,7 +7,7 @@ use crate::sources::postgres::{
     rewrite_tls_args, BinaryProtocol as PgBinaryProtocol, CSVProtocol, CursorProtocol,
     SimpleProtocol,
 };
-use crate::{prelude::*, sql::CXQuery};
+use crate::{arrow2_batch_iter::Arrow2BatchIter, errors::OutResult, prelude::*, sql::CXQuery};
 use fehler::{throw, throws};
 use log::debug;
 #[cfg(feature = "src_postgres")]
@@ -15,14 +17,25 @@ use postgres_openssl::MakeTlsConnector;
 #[allow(unused_imports)]
 use std::sync::Arc;
 
-#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
 #[throws(ConnectorXOutError)]
 pub fn get_arrow2(
     source_conn: &SourceConn,
     origin_query: Option<String>,
     queries: &[CXQuery<String>],
 ) -> Arrow2Destination {
-    let mut destination = Arrow2Destination::new();
+    get_arrow2_with_options(source_conn, origin_query, queries, ArrowOptions::default())?
+}
+
+/// Same as `get_arrow2`, storing the values as specified by `options`.
+#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
+#[throws(ConnectorXOutError)]
+pub fn get_arrow2_with_options(
+    source_conn: &SourceConn,
+    origin_query: Option<String>,
+    queries: &[CXQuery<String>],
+    options: ArrowOptions,
+) -> Arrow2Destination {
+    let mut destination = Arrow2Destination::new_with_options(options);
     let protocol = source_conn.proto.as_str();
     debug!("Protocol: {}", protocol);
 
@@ -144,7 +157,10 @@ pub fn get_arrow2(
                     dispatcher.run()?;
                 }
 
-                _ => unimplemented!("{} protocol not supported", protocol),
+                _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+                    "{:?} with protocol {}",
+                    source_conn.ty, protocol
+                ))),
             }
         }
         #[cfg(feature = "src_mysql")]
@@ -171,7 +187,10 @@ pub fn get_arrow2(
                 );
                 dispatcher.run()?;
             }
-            _ => unimplemented!("{} protocol not supported", protocol),
+            _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+                "{:?} with protocol {}",
+                source_conn.ty, protocol
+            ))),
         },
         #[cfg(feature = "src_sqlite")]
         SourceType::SQLite => {
@@ -186,6 +205,19 @@ pub fn get_arrow2(
             );
             dispatcher.run()?;
         }
+        #[cfg(feature = "src_duckdb")]
+        SourceType::DuckDB => {
+            // remove the first "duckdb://" manually since url.path is not correct for windows
+            let path = &source_conn.conn.as_str()[9..];
+            let source = DuckDBSource::new(path, queries.len())?;
+            let dispatcher = Dispatcher::<_, _, DuckDBArrow2Transport>::new(
+                source,
+                &mut destination,
+                queries,
+                origin_query,
+            );
+            dispatcher.run()?;
+        }
         #[cfg(feature = "src_mssql")]
         SourceType::MsSQL => {
             let rt = Arc::new(tokio::runtime::Runtime::new().expect("Failed to create runtime"));
@@ -221,6 +253,46 @@ pub fn get_arrow2(
             );
             dispatcher.run()?;
         }
+        #[cfg(feature = "src_arrow_ipc")]
+        SourceType::ArrowIPC => {
+            let source = ArrowIPCSource::from_url(&source_conn.conn);
+            let dispatcher = Dispatcher::<_, _, ArrowIPCArrow2Transport>::new(
+                source,
+                &mut destination,
+                queries,
+                origin_query,
+            );
+            dispatcher.run()?;
+        }
+        #[cfg(feature = "src_clickhouse")]
+        SourceType::ClickHouse => match protocol {
+            "binary" => {
+                let source = ClickHouseSource::<RowBinaryProtocol>::new(&source_conn.conn[..])?;
+                let dispatcher =
+                    Dispatcher::<_, _, ClickHouseArrow2Transport<RowBinaryProtocol>>::new(
+                        source,
+                        &mut destination,
+                        queries,
+                        origin_query,
+                    );
+                dispatcher.run()?;
+            }
+            "arrow" => {
+                let source = ClickHouseSource::<ArrowStreamProtocol>::new(&source_conn.conn[..])?;
+                let dispatcher =
+                    Dispatcher::<_, _, ClickHouseArrow2Transport<ArrowStreamProtocol>>::new(
+                        source,
+                        &mut destination,
+                        queries,
+                        origin_query,
+                    );
+                dispatcher.run()?;
+            }
+            _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+                "{:?} with protocol {}",
+                source_conn.ty, protocol
+            ))),
+        },
         _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
             "{:?}",
             source_conn.ty
@@ -229,3 +301,267 @@ pub fn get_arrow2(
 
     destination
 }
+
+pub fn new_arrow2_batch_iter(
+    source_conn: &SourceConn,
+    origin_query: Option<String>,
+    queries: &[CXQuery<String>],
+    batch_size: usize,
+) -> OutResult<Box<dyn Arrow2BatchIterator>> {
+    new_arrow2_batch_iter_with_options(
+        source_conn,
+        origin_query,
+        queries,
+        batch_size,
+        ArrowOptions::default(),
+    )
+}
+
+/// Same as `new_arrow2_batch_iter`, storing the values as specified by `options`.
+#[allow(unreachable_code, unreachable_patterns, unused_variables, unused_mut)]
+pub fn new_arrow2_batch_iter_with_options(
+    source_conn: &SourceConn,
+    origin_query: Option<String>,
+    queries: &[CXQuery<String>],
+    batch_size: usize,
+    options: ArrowOptions,
+) -> OutResult<Box<dyn Arrow2BatchIterator>> {
+    let destination = Arrow2Destination::new_streaming(batch_size, options);
+    let protocol = source_conn.proto.as_str();
+    debug!("Protocol: {}", protocol);
+
+    let batch_iter: Box<dyn Arrow2BatchIterator> = match source_conn.ty {
+        #[cfg(feature = "src_postgres")]
+        SourceType::Postgres => {
+            let (config, tls) = rewrite_tls_args(&source_conn.conn)?;
+            match (protocol, tls) {
+                ("csv", Some(tls_conn)) => {
+                    let sb = PostgresSource::<CSVProtocol, MakeTlsConnector>::new(
+                        config,
+                        tls_conn,
+                        queries.len(),
+                    )?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<CSVProtocol, MakeTlsConnector>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("csv", None) => {
+                    let sb =
+                        PostgresSource::<CSVProtocol, NoTls>::new(config, NoTls, queries.len())?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<CSVProtocol, NoTls>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("binary", Some(tls_conn)) => {
+                    let sb = PostgresSource::<PgBinaryProtocol, MakeTlsConnector>::new(
+                        config,
+                        tls_conn,
+                        queries.len(),
+                    )?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<PgBinaryProtocol, MakeTlsConnector>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("binary", None) => {
+                    let sb = PostgresSource::<PgBinaryProtocol, NoTls>::new(
+                        config,
+                        NoTls,
+                        queries.len(),
+                    )?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<PgBinaryProtocol, NoTls>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("cursor", Some(tls_conn)) => {
+                    let sb = PostgresSource::<CursorProtocol, MakeTlsConnector>::new(
+                        config,
+                        tls_conn,
+                        queries.len(),
+                    )?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<CursorProtocol, MakeTlsConnector>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("cursor", None) => {
+                    let sb =
+                        PostgresSource::<CursorProtocol, NoTls>::new(config, NoTls, queries.len())?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<CursorProtocol, NoTls>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("simple", Some(tls_conn)) => {
+                    let sb = PostgresSource::<SimpleProtocol, MakeTlsConnector>::new(
+                        config,
+                        tls_conn,
+                        queries.len(),
+                    )?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<SimpleProtocol, MakeTlsConnector>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+                ("simple", None) => {
+                    let sb =
+                        PostgresSource::<SimpleProtocol, NoTls>::new(config, NoTls, queries.len())?;
+                    Box::new(Arrow2BatchIter::<
+                        _,
+                        PostgresArrow2Transport<SimpleProtocol, NoTls>,
+                    >::new(
+                        sb, destination, origin_query, queries
+                    )?)
+                }
+
+                _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+                    "{:?} with protocol {}",
+                    source_conn.ty, protocol
+                ))),
+            }
+        }
+        #[cfg(feature = "src_mysql")]
+        SourceType::MySQL => match protocol {
+            "binary" => {
+                let source =
+                    MySQLSource::<MySQLBinaryProtocol>::new(&source_conn.conn[..], queries.len())?;
+                Box::new(Arrow2BatchIter::<
+                    _,
+                    MySQLArrow2Transport<MySQLBinaryProtocol>,
+                >::new(
+                    source, destination, origin_query, queries
+                )?)
+            }
+            "text" => {
+                let source =
+                    MySQLSource::<TextProtocol>::new(&source_conn.conn[..], queries.len())?;
+                Box::new(
+                    Arrow2BatchIter::<_, MySQLArrow2Transport<TextProtocol>>::new(
+                        source,
+                        destination,
+                        origin_query,
+                        queries,
+                    )?,
+                )
+            }
+            _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+                "{:?} with protocol {}",
+                source_conn.ty, protocol
+            ))),
+        },
+        #[cfg(feature = "src_sqlite")]
+        SourceType::SQLite => {
+            // remove the first "sqlite://" manually since url.path is not correct for windows
+            let path = &source_conn.conn.as_str()[9..];
+            let source = SQLiteSource::new(path, queries.len())?;
+            Box::new(Arrow2BatchIter::<_, SQLiteArrow2Transport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )?)
+        }
+        #[cfg(feature = "src_duckdb")]
+        SourceType::DuckDB => {
+            // remove the first "duckdb://" manually since url.path is not correct for windows
+            let path = &source_conn.conn.as_str()[9..];
+            let source = DuckDBSource::new(path, queries.len())?;
+            Box::new(Arrow2BatchIter::<_, DuckDBArrow2Transport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )?)
+        }
+        #[cfg(feature = "src_mssql")]
+        SourceType::MsSQL => {
+            let rt = Arc::new(tokio::runtime::Runtime::new().expect("Failed to create runtime"));
+            let source = MsSQLSource::new(rt, &source_conn.conn[..], queries.len())?;
+            Box::new(Arrow2BatchIter::<_, MsSQLArrow2Transport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )?)
+        }
+        #[cfg(feature = "src_oracle")]
+        SourceType::Oracle => {
+            let source = OracleSource::new(&source_conn.conn[..], queries.len())?;
+            Box::new(Arrow2BatchIter::<_, OracleArrow2Transport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )?)
+        }
+        #[cfg(feature = "src_bigquery")]
+        SourceType::BigQuery => {
+            let rt = Arc::new(tokio::runtime::Runtime::new().expect("Failed to create runtime"));
+            let source = BigQuerySource::new(rt, &source_conn.conn[..])?;
+            Box::new(Arrow2BatchIter::<_, BigQueryArrow2Transport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )?)
+        }
+        #[cfg(feature = "src_arrow_ipc")]
+        SourceType::ArrowIPC => {
+            let source = ArrowIPCSource::from_url(&source_conn.conn);
+            Box::new(Arrow2BatchIter::<_, ArrowIPCArrow2Transport>::new(
+                source,
+                destination,
+                origin_query,
+                queries,
+            )?)
+        }
+        #[cfg(feature = "src_clickhouse")]
+        SourceType::ClickHouse => match protocol {
+            "binary" => {
+                let source = ClickHouseSource::<RowBinaryProtocol>::new(&source_conn.conn[..])?;
+                Box::new(Arrow2BatchIter::<
+                    _,
+                    ClickHouseArrow2Transport<RowBinaryProtocol>,
+                >::new(
+                    source, destination, origin_query, queries
+                )?)
+            }
+            "arrow" => {
+                let source = ClickHouseSource::<ArrowStreamProtocol>::new(&source_conn.conn[..])?;
+                Box::new(Arrow2BatchIter::<
+                    _,
+                    ClickHouseArrow2Transport<ArrowStreamProtocol>,
+                >::new(
+                    source, destination, origin_query, queries
+                )?)
+            }
+            _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+                "{:?} with protocol {}",
+                source_conn.ty, protocol
+            ))),
+        },
+        _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+            "{:?}",
+            source_conn.ty
+        ))),
+    };
+
+    Ok(batch_iter)
+}
@@ -130,20 +130,30 @@
 //! - [x] SQL Server
 //! - [x] Oracle
 //! - [x] BigQuery
+//! - [x] DuckDB
+//! - [x] ClickHouse
+//! - [x] Parquet
+//! - [x] JSON Lines
+//! - [x] Arrow IPC / Feather
 //!
 //! ## Destinations
 //! - [x] Arrow
 //! - [x] Arrow2
+//! - [x] Parquet
+//! - [x] Arrow IPC
+//! - [x] CSV
 //!
 //! # Feature gates
 //! By default, ConnectorX does not enable any sources / destinations to keep the dependencies minimal.
-//! Instead, we provide following features for you to opt-in: `src_sqlite`, `src_postgres`, `src_mysql`, `src_mssql`, `src_oracle`, `dst_arrow`, `dst_arrow2`.
+//! Instead, we provide following features for you to opt-in: `src_sqlite`, `src_postgres`, `src_mysql`, `src_mssql`, `src_oracle`, `src_duckdb`, `src_clickhouse`, `src_parquet`, `src_jsonl`, `src_arrow_ipc`, `dst_arrow`, `dst_arrow2`, `dst_arrow_ipc`, `dst_csv`, `dst_parquet`.
 //! For example, if you'd like to load data from Postgres to Arrow, you can enable `src_postgres` and `dst_arrow` in `Cargo.toml`.
 //! This will enable [`sources::postgres`], [`destinations::arrow`] and [`transports::PostgresArrowTransport`].
 
 pub mod typesystem;
 #[macro_use]
 mod macros;
+#[cfg(feature = "dst_arrow2")]
+pub mod arrow2_batch_iter;
 #[cfg(feature = "dst_arrow")]
 pub mod arrow_batch_iter;
 pub mod constants;
@@ -153,6 +163,8 @@ mod dispatcher;
 pub mod errors;
 #[cfg(feature = "fed_exec")]
 pub mod fed_dispatcher;
+#[cfg(feature = "fed_exec")]
+pub mod fed_provider;
 #[cfg(feature = "federation")]
 pub mod fed_rewriter;
 #[cfg(feature = "dst_arrow")]
@@ -167,43 +179,81 @@ pub mod sql;
 pub mod transports;
 #[doc(hidden)]
 pub mod utils;
+#[cfg(all(
+    feature = "dst_arrow",
+    any(
+        feature = "src_postgres",
+        feature = "src_mysql",
+        feature = "src_sqlite",
+        feature = "src_mssql"
+    )
+))]
+pub mod write_arrow;
 
 pub mod prelude {
+    #[cfg(feature = "dst_arrow2")]
+    pub use crate::arrow2_batch_iter::Arrow2BatchIterator;
     #[cfg(feature = "dst_arrow")]
     pub use crate::arrow_batch_iter::{set_global_num_thread, RecordBatchIterator};
     pub use crate::data_order::{coordinate, DataOrder};
     #[cfg(feature = "dst_arrow")]
     pub use crate::destinations::arrow::{ArrowDestination, ArrowPartitionWriter, ArrowTypeSystem};
     #[cfg(feature = "dst_arrow2")]
-    pub use crate::destinations::arrow2::Arrow2Destination;
+    pub use crate::destinations::arrow2::{
+        Arrow2Destination, Arrow2TypeSystem, ArrowPartitionWriter as Arrow2PartitionWriter,
+    };
+    #[cfg(feature = "dst_arrow_ipc")]
+    pub use crate::destinations::arrow_ipc::{ArrowIPCDestination, IPCFormat};
     #[cfg(feature = "dst_arrow")]
     pub use crate::destinations::arrowstream::{
         ArrowDestination as ArrowStreamDestination,
         ArrowPartitionWriter as ArrowStreamPartitionWriter,
         ArrowTypeSystem as ArrowStreamTypeSystem,
     };
+    #[cfg(feature = "dst_csv")]
+    pub use crate::destinations::csv::{CSVDestination, CSVWriteOptions};
+    #[cfg(feature = "dst_parquet")]
+    pub use crate::destinations::parquet::{ParquetDestination, ParquetOptions};
+    #[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+    pub use crate::destinations::ArrowOptions;
     pub use crate::destinations::{Consume, Destination, DestinationPartition};
     pub use crate::dispatcher::Dispatcher;
     pub use crate::errors::{ConnectorXError, ConnectorXOutError};
     #[cfg(feature = "federation")]
     pub use crate::fed_rewriter::{rewrite_sql, FederatedDataSourceInfo, Plan};
     #[cfg(feature = "dst_arrow")]
-    pub use crate::get_arrow::{get_arrow, new_record_batch_iter};
+    pub use crate::get_arrow::{
+        get_arrow, get_arrow_with_options, new_record_batch_iter,
+        new_record_batch_iter_with_options,
+    };
     #[cfg(feature = "dst_arrow2")]
-    pub use crate::get_arrow2::get_arrow2;
+    pub use crate::get_arrow2::{
+        get_arrow2, get_arrow2_with_options, new_arrow2_batch_iter,
+        new_arrow2_batch_iter_with_options,
+    };
     pub use crate::source_router::*;
+    #[cfg(feature = "src_arrow_ipc")]
+    pub use crate::sources::arrow_ipc::ArrowIPCSource;
     #[cfg(feature = "src_bigquery")]
     pub use crate::sources::bigquery::BigQuerySource;
+    #[cfg(feature = "src_clickhouse")]
+    pub use crate::sources::clickhouse::ClickHouseSource;
     #[cfg(feature = "src_csv")]
     pub use crate::sources::csv::CSVSource;
+    #[cfg(feature = "src_duckdb")]
+    pub use crate::sources::duckdb::DuckDBSource;
     #[cfg(feature = "src_dummy")]
     pub use crate::sources::dummy::DummySource;
+    #[cfg(feature = "src_jsonl")]
+    pub use crate::sources::jsonl::JSONLSource;
     #[cfg(feature = "src_mssql")]
     pub use crate::sources::mssql::MsSQLSource;
     #[cfg(feature = "src_mysql")]
     pub use crate::sources::mysql::MySQLSource;
     #[cfg(feature = "src_oracle")]
     pub use crate::sources::oracle::OracleSource;
+    #[cfg(feature = "src_parquet")]
+    pub use crate::sources::parquet::ParquetSource;
     #[cfg(feature = "src_postgres")]
     pub use crate::sources::postgres::PostgresSource;
     #[cfg(feature = "src_sqlite")]
@@ -217,4 +267,14 @@ pub mod prelude {
         ParameterizedFunc, ParameterizedOn, Realize, Transport, TypeAssoc, TypeConversion,
         TypeSystem,
     };
+    #[cfg(all(
+        feature = "dst_arrow",
+        any(
+            feature = "src_postgres",
+            feature = "src_mysql",
+            feature = "src_sqlite",
+            feature = "src_mssql"
+        )
+    ))]
+    pub use crate::write_arrow::{write_arrow, WriteMode, WriteOptions};
 }
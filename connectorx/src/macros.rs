@@ -56,6 +56,20 @@ macro_rules! impl_typesystem {
         )+
 
         impl_typesystem!(@realize $TS $([ [$($V)+] => $NT ])+ );
+
+        impl $TS {
+            /// The non-nullable type of the variant named `name`, e.g. `"Int64"`, if there is one.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(
+                        $(
+                            stringify!($V) => Some($TS::$V(false)),
+                        )+
+                    )+
+                    _ => None,
+                }
+            }
+        }
     };
 
     (@typeassoc $TS:tt [$($V:tt)+], $NT:ty) => {
@@ -175,6 +189,8 @@ macro_rules! impl_transport {
             type Error = $ET;
 
             impl_transport!(@cvtts [$TSS, $TSD] $([ $($TOKENS)+ ])*);
+            impl_transport!(@cvtto [$TSS, $TSD] $([ $($TOKENS)+ ])*);
+            impl_transport!(@cancvt [$TSS, $TSD] $([ $($TOKENS)+ ])*);
             impl_transport!(@process [$TSS, $TSD] $([ $($TOKENS)+ ])*);
             impl_transport!(@processor [$TSS, $TSD] $([ $($TOKENS)+ ])*, $([ $($TOKENS)+ ])*);
         }
@@ -184,7 +200,10 @@ macro_rules! impl_transport {
         fn convert_typesystem(ts: Self::TSS) -> $crate::errors::Result<Self::TSD> {
             match ts {
                 $(
+                    // the first rule of a source type is the default, later ones can be preferred
+                    #[allow(unreachable_patterns)]
                     $TSS::$V1(true) => Ok($TSD::$V2(true)),
+                    #[allow(unreachable_patterns)]
                     $TSS::$V1(false) => Ok($TSD::$V2(false)),
                 )*
                 #[allow(unreachable_patterns)]
@@ -195,6 +214,34 @@ macro_rules! impl_transport {
         }
     };
 
+    (@cvtto [$TSS:tt, $TSD:tt] $( [$V1:tt [$T1:ty] => $V2:tt [$T2:ty] | conversion $HOW:ident] )*) => {
+        fn convert_typesystem_to(ts1: Self::TSS, ts2: Self::TSD) -> $crate::errors::Result<Self::TSD> {
+            match (ts1, ts2) {
+                $(
+                    #[allow(unreachable_patterns)]
+                    ($TSS::$V1(nullable), $TSD::$V2(_)) => Ok($TSD::$V2(nullable)),
+                )*
+                #[allow(unreachable_patterns)]
+                _ => fehler::throw!($crate::errors::ConnectorXError::NoConversionRule(
+                    format!("{:?}", ts1), format!("{:?}", ts2)
+                ))
+            }
+        }
+    };
+
+    (@cancvt [$TSS:tt, $TSD:tt] $( [$V1:tt [$T1:ty] => $V2:tt [$T2:ty] | conversion $HOW:ident] )*) => {
+        fn can_convert(ts1: Self::TSS, ts2: Self::TSD) -> bool {
+            match (ts1, ts2) {
+                $(
+                    ($TSS::$V1(true), $TSD::$V2(true)) => true,
+                    ($TSS::$V1(false), $TSD::$V2(false)) => true,
+                )*
+                #[allow(unreachable_patterns)]
+                _ => false,
+            }
+        }
+    };
+
     (@process [$TSS:tt, $TSD:tt] $([ $V1:tt [$T1:ty] => $V2:tt [$T2:ty] | conversion $HOW:ident ])*) => {
         fn process<'s, 'd, 'r>(
             ts1: Self::TSS,
@@ -2,6 +2,8 @@ use crate::errors::{ConnectorXOutError, OutResult};
 use crate::source_router::{SourceConn, SourceType};
 #[cfg(feature = "src_bigquery")]
 use crate::sources::bigquery::BigQueryDialect;
+#[cfg(feature = "src_clickhouse")]
+use crate::sources::clickhouse::ClickHouseClient;
 #[cfg(feature = "src_mssql")]
 use crate::sources::mssql::{mssql_config, FloatN, IntN, MsSQLTypeSystem};
 #[cfg(feature = "src_mysql")]
@@ -16,6 +18,8 @@ use crate::sources::trino::TrinoDialect;
 use crate::sql::get_partition_range_query_sep;
 use crate::sql::{get_partition_range_query, single_col_partition_query, CXQuery};
 use anyhow::anyhow;
+#[cfg(feature = "src_duckdb")]
+use duckdb::types::ValueRef;
 use fehler::{throw, throws};
 #[cfg(feature = "src_bigquery")]
 use gcp_bigquery_client;
@@ -23,10 +27,16 @@ use gcp_bigquery_client;
 use r2d2_mysql::mysql::{prelude::Queryable, Opts, Pool, Row};
 #[cfg(feature = "src_sqlite")]
 use rusqlite::{types::Type, Connection};
+#[cfg(any(feature = "src_postgres", feature = "src_duckdb"))]
+use rust_decimal::prelude::ToPrimitive;
 #[cfg(feature = "src_postgres")]
-use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal::Decimal;
 #[cfg(feature = "src_postgres")]
 use rust_decimal_macros::dec;
+#[cfg(feature = "src_clickhouse")]
+use sqlparser::dialect::ClickHouseDialect;
+#[cfg(feature = "src_duckdb")]
+use sqlparser::dialect::DuckDbDialect;
 #[cfg(feature = "src_mssql")]
 use sqlparser::dialect::MsSqlDialect;
 #[cfg(feature = "src_mysql")]
@@ -94,6 +104,8 @@ pub fn get_col_range(source_conn: &SourceConn, query: &str, col: &str) -> OutRes
         SourceType::Postgres => pg_get_partition_range(&source_conn.conn, query, col),
         #[cfg(feature = "src_sqlite")]
         SourceType::SQLite => sqlite_get_partition_range(&source_conn.conn, query, col),
+        #[cfg(feature = "src_duckdb")]
+        SourceType::DuckDB => duckdb_get_partition_range(&source_conn.conn, query, col),
         #[cfg(feature = "src_mysql")]
         SourceType::MySQL => mysql_get_partition_range(&source_conn.conn, query, col),
         #[cfg(feature = "src_mssql")]
@@ -104,6 +116,8 @@ pub fn get_col_range(source_conn: &SourceConn, query: &str, col: &str) -> OutRes
         SourceType::BigQuery => bigquery_get_partition_range(&source_conn.conn, query, col),
         #[cfg(feature = "src_trino")]
         SourceType::Trino => trino_get_partition_range(&source_conn.conn, query, col),
+        #[cfg(feature = "src_clickhouse")]
+        SourceType::ClickHouse => clickhouse_get_partition_range(&source_conn.conn, query, col),
         _ => unimplemented!("{:?} not implemented!", source_conn.ty),
     }
 }
@@ -125,6 +139,10 @@ pub fn get_part_query(
         SourceType::SQLite => {
             single_col_partition_query(query, col, lower, upper, &SQLiteDialect {})?
         }
+        #[cfg(feature = "src_duckdb")]
+        SourceType::DuckDB => {
+            single_col_partition_query(query, col, lower, upper, &DuckDbDialect {})?
+        }
         #[cfg(feature = "src_mysql")]
         SourceType::MySQL => {
             single_col_partition_query(query, col, lower, upper, &MySqlDialect {})?
@@ -145,6 +163,10 @@ pub fn get_part_query(
         SourceType::Trino => {
             single_col_partition_query(query, col, lower, upper, &TrinoDialect {})?
         }
+        #[cfg(feature = "src_clickhouse")]
+        SourceType::ClickHouse => {
+            single_col_partition_query(query, col, lower, upper, &ClickHouseDialect {})?
+        }
         _ => unimplemented!("{:?} not implemented!", source_conn.ty),
     };
     CXQuery::Wrapped(query)
@@ -256,6 +278,45 @@ fn sqlite_get_partition_range(conn: &Url, query: &str, col: &str) -> (i64, i64)
     (min_v, max_v)
 }
 
+#[cfg(feature = "src_duckdb")]
+#[throws(ConnectorXOutError)]
+fn duckdb_get_partition_range(conn: &Url, query: &str, col: &str) -> (i64, i64) {
+    // remove the first "duckdb://" manually since url.path is not correct for windows and for relative path
+    let conn = duckdb::Connection::open(&conn.as_str()[9..])?;
+    let range_query = get_partition_range_query(query, col, &DuckDbDialect {})?;
+    let mut stmt = conn.prepare(range_query.as_str())?;
+    let mut rows = stmt.query([])?;
+    let row = rows
+        .next()?
+        .ok_or_else(|| anyhow!("DuckDB range query returned no rows"))?;
+
+    let parse = |i: usize| -> Result<i64, ConnectorXOutError> {
+        let v = match row.get_ref(i)? {
+            ValueRef::Null => 0,
+            ValueRef::TinyInt(v) => v as i64,
+            ValueRef::SmallInt(v) => v as i64,
+            ValueRef::Int(v) => v as i64,
+            ValueRef::BigInt(v) => v,
+            ValueRef::HugeInt(v) => v as i64,
+            ValueRef::UTinyInt(v) => v as i64,
+            ValueRef::USmallInt(v) => v as i64,
+            ValueRef::UInt(v) => v as i64,
+            ValueRef::UBigInt(v) => v as i64,
+            ValueRef::Float(v) => v as i64,
+            ValueRef::Double(v) => v as i64,
+            ValueRef::Decimal(v) => v
+                .to_i64()
+                .ok_or_else(|| anyhow!("decimal {} does not fit in int64", v))?,
+            _ => throw!(anyhow!(
+                "Partition can only be done on int or float columns"
+            )),
+        };
+        Ok(v)
+    };
+
+    (parse(0)?, parse(1)?)
+}
+
 #[cfg(feature = "src_mysql")]
 #[throws(ConnectorXOutError)]
 fn mysql_get_partition_range(conn: &Url, query: &str, col: &str) -> (i64, i64) {
@@ -538,3 +599,28 @@ fn trino_get_partition_range(conn: &Url, query: &str, col: &str) -> (i64, i64) {
 
     (result._col0, result._col1)
 }
+
+#[cfg(feature = "src_clickhouse")]
+#[throws(ConnectorXOutError)]
+fn clickhouse_get_partition_range(conn: &Url, query: &str, col: &str) -> (i64, i64) {
+    let client = ClickHouseClient::new(conn)?;
+    let range_query = get_partition_range_query(query, col, &ClickHouseDialect {})?;
+    let text = client.query_text(&range_query)?;
+
+    // the result is a single tab-separated row, with `\N` for NULL
+    let values: Vec<&str> = text.trim_end_matches('\n').split('\t').collect();
+    if values.len() != 2 {
+        throw!(anyhow!("ClickHouse range query returned {:?}", text));
+    }
+    let parse = |v: &str| -> Result<i64, ConnectorXOutError> {
+        match v {
+            "\\N" => Ok(0),
+            v => v
+                .parse::<i64>()
+                .or_else(|_| v.parse::<f64>().map(|v| v as i64))
+                .map_err(|_| anyhow!("Partition can only be done on int or float columns").into()),
+        }
+    };
+
+    (parse(values[0])?, parse(values[1])?)
+}
@@ -15,6 +15,8 @@ pub enum SourceType {
     BigQuery,
     DuckDB,
     Trino,
+    ClickHouse,
+    ArrowIPC,
     Unknown,
 }
 
@@ -60,6 +62,8 @@ impl TryFrom<&str> for SourceConn {
             "bigquery" => Ok(SourceConn::new(SourceType::BigQuery, url, proto)),
             "duckdb" => Ok(SourceConn::new(SourceType::DuckDB, url, proto)),
             "trino" => Ok(SourceConn::new(SourceType::Trino, url, proto)),
+            "clickhouse" => Ok(SourceConn::new(SourceType::ClickHouse, url, proto)),
+            "arrow" | "feather" => Ok(SourceConn::new(SourceType::ArrowIPC, url, proto)),
             _ => Ok(SourceConn::new(SourceType::Unknown, url, proto)),
         }
     }
@@ -0,0 +1,21 @@
+use arrow::datatypes::DataType;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ArrowIPCSourceError {
+    #[error("Unsupported Arrow type {0:?}")]
+    UnsupportedType(DataType),
+
+    #[error(transparent)]
+    ConnectorXError(#[from] crate::errors::ConnectorXError),
+
+    #[error(transparent)]
+    ArrowError(#[from] arrow::error::ArrowError),
+
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+
+    /// Any other errors that are too trivial to be put here explicitly.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
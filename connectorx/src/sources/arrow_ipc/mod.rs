@@ -0,0 +1,392 @@
+//! Source implementation for Arrow IPC files, including Feather (v2) files.
+//! Both the IPC file format and the IPC stream format are supported.
+
+mod errors;
+mod typesystem;
+
+pub use self::errors::ArrowIPCSourceError;
+pub use self::typesystem::ArrowIPCTypeSystem;
+use super::{PartitionParser, Produce, Source, SourcePartition};
+use crate::{data_order::DataOrder, errors::ConnectorXError, sql::CXQuery};
+use arrow::{
+    array::{Array, AsArray},
+    compute::cast,
+    datatypes::{
+        ArrowPrimitiveType, Date32Type, Date64Type, Decimal128Type, Field, Float32Type,
+        Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, Schema, SchemaRef,
+        Time64NanosecondType, TimestampMicrosecondType, TimestampNanosecondType, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
+    },
+    ipc::reader::{FileReader, StreamReader},
+    record_batch::RecordBatch,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use fehler::{throw, throws};
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::Read;
+use std::sync::Arc;
+use url::Url;
+
+/// Magic bytes at the start of files in the IPC file format, stream files do not have them.
+const ARROW_MAGIC: &[u8; 6] = b"ARROW1";
+
+/// Read Arrow IPC files, each query being the path of a file.
+/// A file becomes one partition, or one partition per record batch if `split_batches` is set
+/// and the file is in the IPC file format, which allows to seek to a batch.
+pub struct ArrowIPCSource {
+    split_batches: bool,
+    files: Vec<CXQuery<String>>,
+    names: Vec<String>,
+    schema: Vec<ArrowIPCTypeSystem>,
+}
+
+impl ArrowIPCSource {
+    pub fn new(split_batches: bool) -> Self {
+        Self {
+            split_batches,
+            files: vec![],
+            names: vec![],
+            schema: vec![],
+        }
+    }
+
+    /// The source of an `arrow://` (or `feather://`) connection, whose queries are the paths
+    /// of the files. The files are split by record batch if the connection has
+    /// `split_batches=true`, e.g. `arrow://?split_batches=true`.
+    pub fn from_url(url: &Url) -> Self {
+        Self::new(
+            url.query_pairs()
+                .any(|(k, v)| k == "split_batches" && v == "true"),
+        )
+    }
+}
+
+#[throws(ArrowIPCSourceError)]
+fn is_file_format(fname: &str) -> bool {
+    let mut magic = [0u8; 6];
+    let mut file = File::open(fname)?;
+    file.read_exact(&mut magic).is_ok() && &magic == ARROW_MAGIC
+}
+
+#[throws(ArrowIPCSourceError)]
+fn read_schema(fname: &str) -> SchemaRef {
+    let file = File::open(fname)?;
+    if is_file_format(fname)? {
+        FileReader::try_new(file, None)?.schema()
+    } else {
+        StreamReader::try_new(file, None)?.schema()
+    }
+}
+
+impl Source for ArrowIPCSource {
+    const DATA_ORDERS: &'static [DataOrder] = &[DataOrder::RowMajor];
+    type Partition = ArrowIPCSourcePartition;
+    type TypeSystem = ArrowIPCTypeSystem;
+    type Error = ArrowIPCSourceError;
+
+    #[throws(ArrowIPCSourceError)]
+    fn set_data_order(&mut self, data_order: DataOrder) {
+        if !matches!(data_order, DataOrder::RowMajor) {
+            throw!(ConnectorXError::UnsupportedDataOrder(data_order))
+        }
+    }
+
+    fn set_queries<Q: ToString>(&mut self, queries: &[CXQuery<Q>]) {
+        self.files = queries.iter().map(|q| q.map(Q::to_string)).collect();
+    }
+
+    fn set_origin_query(&mut self, _query: Option<String>) {}
+
+    #[throws(ArrowIPCSourceError)]
+    fn fetch_metadata(&mut self) {
+        assert!(!self.files.is_empty());
+        let schema = read_schema(self.files[0].as_str())?;
+
+        self.names = schema.fields().iter().map(|f| f.name().clone()).collect();
+        self.schema = schema
+            .fields()
+            .iter()
+            .map(|f| ArrowIPCTypeSystem::try_from(f.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+
+    #[throws(ArrowIPCSourceError)]
+    fn result_rows(&mut self) -> Option<usize> {
+        None
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    fn schema(&self) -> Vec<Self::TypeSystem> {
+        self.schema.clone()
+    }
+
+    #[throws(ArrowIPCSourceError)]
+    fn partition(self) -> Vec<Self::Partition> {
+        let mut partitions = vec![];
+        for file in &self.files {
+            let fname = file.as_str();
+            if self.split_batches && is_file_format(fname)? {
+                let nbatches = FileReader::try_new(File::open(fname)?, None)?.num_batches();
+                for i in 0..nbatches {
+                    partitions.push(ArrowIPCSourcePartition::new(
+                        file.clone(),
+                        Some(i),
+                        self.schema.len(),
+                    ));
+                }
+            } else {
+                partitions.push(ArrowIPCSourcePartition::new(
+                    file.clone(),
+                    None,
+                    self.schema.len(),
+                ));
+            }
+        }
+        partitions
+    }
+}
+
+pub struct ArrowIPCSourcePartition {
+    file: CXQuery<String>,
+    batch: Option<usize>,
+    batches: Option<Vec<RecordBatch>>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl ArrowIPCSourcePartition {
+    /// `batch` is the index of the only record batch to read, all the batches are read if `None`.
+    pub fn new(file: CXQuery<String>, batch: Option<usize>, ncols: usize) -> Self {
+        Self {
+            file,
+            batch,
+            batches: None,
+            nrows: 0,
+            ncols,
+        }
+    }
+
+    /// Read the record batches of the partition, casting the arrays to the canonical
+    /// types expected by the parser.
+    #[throws(ArrowIPCSourceError)]
+    fn load(&mut self) {
+        if self.batches.is_none() {
+            let fname = self.file.as_str();
+            let file = File::open(fname)?;
+            let batches = if is_file_format(fname)? {
+                let mut reader = FileReader::try_new(file, None)?;
+                match self.batch {
+                    Some(i) => {
+                        reader.set_index(i)?;
+                        reader.next().transpose()?.into_iter().collect()
+                    }
+                    None => reader.collect::<Result<Vec<_>, _>>()?,
+                }
+            } else {
+                StreamReader::try_new(file, None)?.collect::<Result<Vec<_>, _>>()?
+            };
+
+            let batches = batches
+                .iter()
+                .map(canonicalize)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            self.nrows = batches.iter().map(|b| b.num_rows()).sum();
+            self.batches = Some(batches);
+        }
+    }
+}
+
+/// Cast the arrays of a record batch to the canonical types of their columns.
+#[throws(ArrowIPCSourceError)]
+fn canonicalize(batch: &RecordBatch) -> RecordBatch {
+    let mut fields = vec![];
+    let mut columns = vec![];
+    for (field, col) in batch.schema().fields().iter().zip(batch.columns()) {
+        let dt = ArrowIPCTypeSystem::canonical_type(field.data_type());
+        columns.push(cast(col, &dt)?);
+        fields.push(Field::new(field.name(), dt, field.is_nullable()));
+    }
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?
+}
+
+impl SourcePartition for ArrowIPCSourcePartition {
+    type TypeSystem = ArrowIPCTypeSystem;
+    type Parser<'a> = ArrowIPCSourcePartitionParser<'a>;
+    type Error = ArrowIPCSourceError;
+
+    #[throws(ArrowIPCSourceError)]
+    fn result_rows(&mut self) {
+        self.load()?;
+    }
+
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[throws(ArrowIPCSourceError)]
+    fn parser(&mut self) -> Self::Parser<'_> {
+        self.load()?;
+        ArrowIPCSourcePartitionParser::new(self.batches.as_deref().unwrap_or_default(), self.ncols)
+    }
+}
+
+pub struct ArrowIPCSourcePartitionParser<'a> {
+    batches: &'a [RecordBatch],
+    ncols: usize,
+    next_batch: usize,
+    current_batch: usize,
+    current_row: usize,
+    current_col: usize,
+}
+
+impl<'a> ArrowIPCSourcePartitionParser<'a> {
+    pub fn new(batches: &'a [RecordBatch], ncols: usize) -> Self {
+        Self {
+            batches,
+            ncols,
+            next_batch: 0,
+            current_batch: 0,
+            current_row: 0,
+            current_col: 0,
+        }
+    }
+
+    fn next_loc(&mut self) -> (&'a dyn Array, usize) {
+        let batches: &'a [RecordBatch] = self.batches;
+        let (row, col) = (self.current_row, self.current_col);
+        self.current_row += (self.current_col + 1) / self.ncols;
+        self.current_col = (self.current_col + 1) % self.ncols;
+        (batches[self.current_batch].column(col).as_ref(), row)
+    }
+
+    /// Convert the next value, which must not be null.
+    #[throws(ArrowIPCSourceError)]
+    fn convert_next<T>(&mut self, conv: fn(&dyn Array, usize) -> Option<T>) -> T {
+        let (array, row) = self.next_loc();
+        if array.is_null(row) {
+            throw!(ConnectorXError::cannot_produce::<T>(Some("null".into())));
+        }
+        conv(array, row).ok_or_else(|| {
+            ConnectorXError::cannot_produce::<T>(Some(format!("{:?}", array.data_type())))
+        })?
+    }
+
+    /// Convert the next value, null values are `None`.
+    #[throws(ArrowIPCSourceError)]
+    fn convert_next_opt<T>(&mut self, conv: fn(&dyn Array, usize) -> Option<T>) -> Option<T> {
+        let (array, row) = self.next_loc();
+        if array.is_null(row) {
+            return None;
+        }
+        let v = conv(array, row).ok_or_else(|| {
+            ConnectorXError::cannot_produce::<Option<T>>(Some(format!("{:?}", array.data_type())))
+        })?;
+
+        Some(v)
+    }
+}
+
+impl<'a> PartitionParser<'a> for ArrowIPCSourcePartitionParser<'a> {
+    type TypeSystem = ArrowIPCTypeSystem;
+    type Error = ArrowIPCSourceError;
+
+    /// Hand out one record batch at a time.
+    #[throws(ArrowIPCSourceError)]
+    fn fetch_next(&mut self) -> (usize, bool) {
+        assert!(self.current_col == 0);
+        if self.next_batch >= self.batches.len() {
+            return (0, true);
+        }
+
+        self.current_batch = self.next_batch;
+        self.current_row = 0;
+        self.next_batch += 1;
+
+        (
+            self.batches[self.current_batch].num_rows(),
+            self.next_batch == self.batches.len(),
+        )
+    }
+}
+
+fn primitive<T: ArrowPrimitiveType>(array: &dyn Array, i: usize) -> Option<T::Native> {
+    array.as_primitive_opt::<T>().map(|a| a.value(i))
+}
+
+macro_rules! impl_produce {
+    ($($t: ty => $conv: expr,)+) => {
+        $(
+            impl<'r, 'a> Produce<'r, $t> for ArrowIPCSourcePartitionParser<'a> {
+                type Error = ArrowIPCSourceError;
+
+                #[throws(ArrowIPCSourceError)]
+                fn produce(&'r mut self) -> $t {
+                    self.convert_next($conv)?
+                }
+            }
+
+            impl<'r, 'a> Produce<'r, Option<$t>> for ArrowIPCSourcePartitionParser<'a> {
+                type Error = ArrowIPCSourceError;
+
+                #[throws(ArrowIPCSourceError)]
+                fn produce(&'r mut self) -> Option<$t> {
+                    self.convert_next_opt($conv)?
+                }
+            }
+        )+
+    };
+}
+
+impl_produce!(
+    bool => |a, i| a.as_boolean_opt().map(|a| a.value(i)),
+    i8 => primitive::<Int8Type>,
+    i16 => primitive::<Int16Type>,
+    i32 => primitive::<Int32Type>,
+    i64 => primitive::<Int64Type>,
+    u8 => primitive::<UInt8Type>,
+    u16 => primitive::<UInt16Type>,
+    u32 => primitive::<UInt32Type>,
+    u64 => primitive::<UInt64Type>,
+    f32 => primitive::<Float32Type>,
+    f64 => primitive::<Float64Type>,
+    Decimal => |a, i| {
+        let a = a.as_primitive_opt::<Decimal128Type>()?;
+        Decimal::try_from_i128_with_scale(a.value(i), u32::try_from(a.scale()).ok()?).ok()
+    },
+    String => |a, i| {
+        a.as_string_opt::<i32>()
+            .map(|a| a.value(i).to_string())
+            .or_else(|| a.as_string_opt::<i64>().map(|a| a.value(i).to_string()))
+    },
+    Vec<u8> => |a, i| {
+        a.as_binary_opt::<i32>()
+            .map(|a| a.value(i).to_vec())
+            .or_else(|| a.as_binary_opt::<i64>().map(|a| a.value(i).to_vec()))
+    },
+    NaiveDate => |a, i| a.as_primitive_opt::<Date32Type>()?.value_as_date(i),
+    // both Date64 and Timestamp columns are read as NaiveDateTime
+    NaiveDateTime => |a, i| {
+        a.as_primitive_opt::<Date64Type>()
+            .and_then(|a| a.value_as_datetime(i))
+            .or_else(|| a.as_primitive_opt::<TimestampMicrosecondType>()?.value_as_datetime(i))
+            .or_else(|| a.as_primitive_opt::<TimestampNanosecondType>()?.value_as_datetime(i))
+    },
+    NaiveTime => |a, i| a.as_primitive_opt::<Time64NanosecondType>()?.value_as_time(i),
+    // timestamps are stored as UTC whatever their time zone
+    DateTime<Utc> => |a, i| {
+        a.as_primitive_opt::<TimestampMicrosecondType>()
+            .and_then(|a| a.value_as_datetime(i))
+            .or_else(|| a.as_primitive_opt::<TimestampNanosecondType>()?.value_as_datetime(i))
+            .map(|v| v.and_utc())
+    },
+);
@@ -0,0 +1,105 @@
+use super::errors::ArrowIPCSourceError;
+use arrow::datatypes::{DataType, Field, TimeUnit};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use fehler::{throw, throws};
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ArrowIPCTypeSystem {
+    Boolean(bool),
+    Int8(bool),
+    Int16(bool),
+    Int32(bool),
+    Int64(bool),
+    UInt8(bool),
+    UInt16(bool),
+    UInt32(bool),
+    UInt64(bool),
+    Float32(bool),
+    Float64(bool),
+    Decimal(bool),
+    Utf8(bool),
+    Binary(bool),
+    Date32(bool),
+    Date64(bool),
+    Time64(bool),
+    Timestamp(bool),
+    TimestampTz(bool),
+    TimestampNano(bool),
+    TimestampTzNano(bool),
+}
+
+impl_typesystem! {
+    system = ArrowIPCTypeSystem,
+    mappings = {
+        { Boolean => bool }
+        { Int8 => i8 }
+        { Int16 => i16 }
+        { Int32 => i32 }
+        { Int64 => i64 }
+        { UInt8 => u8 }
+        { UInt16 => u16 }
+        { UInt32 => u32 }
+        { UInt64 => u64 }
+        { Float32 => f32 }
+        { Float64 => f64 }
+        { Decimal => Decimal }
+        { Utf8 => String }
+        { Binary => Vec<u8> }
+        { Date32 => NaiveDate }
+        { Date64 | Timestamp | TimestampNano => NaiveDateTime }
+        { Time64 => NaiveTime }
+        { TimestampTz | TimestampTzNano => DateTime<Utc> }
+    }
+}
+
+impl ArrowIPCTypeSystem {
+    /// The type the arrays of a column are cast to before being parsed, so that a single
+    /// array type has to be handled for each of the temporal types whatever their unit.
+    /// Nanosecond timestamps are kept as is, the other units are cast to microseconds which
+    /// cover a wider range of dates.
+    pub fn canonical_type(dt: &DataType) -> DataType {
+        match dt {
+            DataType::Time32(_) | DataType::Time64(_) => DataType::Time64(TimeUnit::Nanosecond),
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => dt.clone(),
+            DataType::Timestamp(_, tz) => DataType::Timestamp(TimeUnit::Microsecond, tz.clone()),
+            dt => dt.clone(),
+        }
+    }
+}
+
+impl TryFrom<&Field> for ArrowIPCTypeSystem {
+    type Error = ArrowIPCSourceError;
+
+    #[throws(ArrowIPCSourceError)]
+    fn try_from(field: &Field) -> Self {
+        use ArrowIPCTypeSystem::*;
+
+        let nullable = field.is_nullable();
+        match field.data_type() {
+            DataType::Boolean => Boolean(nullable),
+            DataType::Int8 => Int8(nullable),
+            DataType::Int16 => Int16(nullable),
+            DataType::Int32 => Int32(nullable),
+            DataType::Int64 => Int64(nullable),
+            DataType::UInt8 => UInt8(nullable),
+            DataType::UInt16 => UInt16(nullable),
+            DataType::UInt32 => UInt32(nullable),
+            DataType::UInt64 => UInt64(nullable),
+            DataType::Float32 => Float32(nullable),
+            DataType::Float64 => Float64(nullable),
+            DataType::Decimal128(_, _) => Decimal(nullable),
+            DataType::Utf8 | DataType::LargeUtf8 => Utf8(nullable),
+            DataType::Binary | DataType::LargeBinary => Binary(nullable),
+            DataType::Date32 => Date32(nullable),
+            DataType::Date64 => Date64(nullable),
+            DataType::Time32(_) | DataType::Time64(_) => Time64(nullable),
+            DataType::Timestamp(TimeUnit::Nanosecond, None) => TimestampNano(nullable),
+            DataType::Timestamp(TimeUnit::Nanosecond, Some(_)) => TimestampTzNano(nullable),
+            DataType::Timestamp(_, None) => Timestamp(nullable),
+            DataType::Timestamp(_, Some(_)) => TimestampTz(nullable),
+            dt => throw!(ArrowIPCSourceError::UnsupportedType(dt.clone())),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ClickHouseSourceError {
+    #[error("Unsupported ClickHouse type {0}")]
+    UnsupportedType(String),
+
+    #[error("ClickHouse server responded {0}: {1}")]
+    ServerError(reqwest::StatusCode, String),
+
+    #[error(transparent)]
+    ConnectorXError(#[from] crate::errors::ConnectorXError),
+
+    #[error(transparent)]
+    ReqwestError(#[from] reqwest::Error),
+
+    #[error(transparent)]
+    ArrowError(#[from] arrow::error::ArrowError),
+
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+
+    #[error(transparent)]
+    UrlParseError(#[from] url::ParseError),
+
+    #[error(transparent)]
+    UrlDecodeError(#[from] std::string::FromUtf8Error),
+
+    /// Any other errors that are too trivial to be put here explicitly.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,223 @@
+use super::errors::ClickHouseSourceError;
+use chrono::{DateTime, NaiveDate, Utc};
+use fehler::{throw, throws};
+use rust_decimal::Decimal;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ClickHouseTypeSystem {
+    Bool(bool),
+    Int8(bool),
+    Int16(bool),
+    Int32(bool),
+    Int64(bool),
+    UInt8(bool),
+    UInt16(bool),
+    UInt32(bool),
+    UInt64(bool),
+    Float32(bool),
+    Float64(bool),
+    Decimal(bool),
+    String(bool),
+    Date(bool),
+    DateTime(bool),
+}
+
+impl_typesystem! {
+    system = ClickHouseTypeSystem,
+    mappings = {
+        { Bool => bool }
+        { Int8 => i8 }
+        { Int16 => i16 }
+        { Int32 => i32 }
+        { Int64 => i64 }
+        { UInt8 => u8 }
+        { UInt16 => u16 }
+        { UInt32 => u32 }
+        { UInt64 => u64 }
+        { Float32 => f32 }
+        { Float64 => f64 }
+        { Decimal => Decimal }
+        { String => String }
+        { Date => NaiveDate }
+        { DateTime => DateTime<Utc> }
+    }
+}
+
+/// The physical layout of a ClickHouse column. On top of its `ClickHouseTypeSystem`,
+/// decoding a value needs the width of decimals, the length of fixed strings and the
+/// precision of `DateTime64`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ClickHouseType {
+    Bool,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float32,
+    Float64,
+    Decimal { precision: u32, scale: u32 },
+    String,
+    FixedString(usize),
+    Date,
+    Date32,
+    DateTime,
+    DateTime64(u32),
+}
+
+/// Strip `wrapper(...)` from a type name, e.g. `Nullable(String)` gives `String`.
+fn unwrap_type<'a>(name: &'a str, wrapper: &str) -> Option<&'a str> {
+    name.strip_prefix(wrapper)?
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+impl ClickHouseType {
+    /// Parse a type name as reported by ClickHouse, e.g. `LowCardinality(Nullable(String))`
+    /// or `DateTime64(3, 'UTC')`, into its layout and whether it is nullable.
+    #[throws(ClickHouseSourceError)]
+    pub fn parse(name: &str) -> (Self, bool) {
+        use ClickHouseType::*;
+
+        let name = name.trim();
+        // LowCardinality only changes how the column is stored, not how it is sent
+        if let Some(inner) = unwrap_type(name, "LowCardinality") {
+            return Self::parse(inner)?;
+        }
+        if let Some(inner) = unwrap_type(name, "Nullable") {
+            return (Self::parse(inner)?.0, true);
+        }
+
+        let (base, args) = match name.find('(') {
+            Some(i) if name.ends_with(')') => (
+                &name[..i],
+                name[i + 1..name.len() - 1]
+                    .split(',')
+                    .map(|arg| arg.trim())
+                    .collect(),
+            ),
+            _ => (name, vec![]),
+        };
+        let arg = |i: usize| -> Option<u32> { args.get(i)?.parse().ok() };
+        let unsupported = || ClickHouseSourceError::UnsupportedType(name.to_string());
+
+        let ty = match base {
+            "Bool" => Bool,
+            "Int8" => Int8,
+            "Int16" => Int16,
+            "Int32" => Int32,
+            "Int64" => Int64,
+            "UInt8" => UInt8,
+            "UInt16" => UInt16,
+            "UInt32" => UInt32,
+            "UInt64" => UInt64,
+            "Float32" => Float32,
+            "Float64" => Float64,
+            "Decimal" => Decimal {
+                precision: arg(0).ok_or_else(unsupported)?,
+                scale: arg(1).unwrap_or(0),
+            },
+            "Decimal32" => Decimal {
+                precision: 9,
+                scale: arg(0).ok_or_else(unsupported)?,
+            },
+            "Decimal64" => Decimal {
+                precision: 18,
+                scale: arg(0).ok_or_else(unsupported)?,
+            },
+            "Decimal128" => Decimal {
+                precision: 38,
+                scale: arg(0).ok_or_else(unsupported)?,
+            },
+            "String" => String,
+            "FixedString" => FixedString(arg(0).ok_or_else(unsupported)? as usize),
+            "Date" => Date,
+            "Date32" => Date32,
+            // the time zone of a column only affects how it is displayed
+            "DateTime" => DateTime,
+            "DateTime64" => DateTime64(arg(0).filter(|&p| p <= 9).ok_or_else(unsupported)?),
+            _ => throw!(unsupported()),
+        };
+
+        if let Decimal { precision, .. } = ty {
+            // Decimal256 does not fit in a rust Decimal
+            if precision > 38 {
+                throw!(unsupported());
+            }
+        }
+
+        (ty, false)
+    }
+
+    /// The time zone in a type name such as `DateTime('Asia/Shanghai')` or
+    /// `Nullable(DateTime64(3, 'UTC'))`, `None` if it has none.
+    pub fn timezone(name: &str) -> Option<String> {
+        let name = name.trim();
+        if let Some(inner) =
+            unwrap_type(name, "LowCardinality").or_else(|| unwrap_type(name, "Nullable"))
+        {
+            return Self::timezone(inner);
+        }
+
+        let args = unwrap_type(name, "DateTime").or_else(|| unwrap_type(name, "DateTime64"))?;
+        let tz = args
+            .split(',')
+            .map(|arg| arg.trim())
+            .find(|arg| arg.starts_with('\''))?;
+        Some(tz.trim_matches('\'').to_string())
+    }
+
+    /// Number of fractional second digits of `DateTime` and `DateTime64`.
+    pub fn timestamp_precision(&self) -> Option<u8> {
+        match self {
+            ClickHouseType::DateTime => Some(0),
+            ClickHouseType::DateTime64(precision) => Some(*precision as u8),
+            _ => None,
+        }
+    }
+
+    /// Size of a value in the `RowBinary` format, `None` for variable-length strings.
+    pub fn fixed_size(&self) -> Option<usize> {
+        use ClickHouseType::*;
+
+        match self {
+            Bool | Int8 | UInt8 => Some(1),
+            Int16 | UInt16 | Date => Some(2),
+            Int32 | UInt32 | Float32 | Date32 | DateTime => Some(4),
+            Int64 | UInt64 | Float64 | DateTime64(_) => Some(8),
+            Decimal { precision, .. } if *precision <= 9 => Some(4),
+            Decimal { precision, .. } if *precision <= 18 => Some(8),
+            Decimal { .. } => Some(16),
+            FixedString(n) => Some(*n),
+            String => None,
+        }
+    }
+}
+
+impl From<(ClickHouseType, bool)> for ClickHouseTypeSystem {
+    fn from(ty: (ClickHouseType, bool)) -> Self {
+        use ClickHouseTypeSystem::*;
+
+        let (ty, nullable) = ty;
+        match ty {
+            ClickHouseType::Bool => Bool(nullable),
+            ClickHouseType::Int8 => Int8(nullable),
+            ClickHouseType::Int16 => Int16(nullable),
+            ClickHouseType::Int32 => Int32(nullable),
+            ClickHouseType::Int64 => Int64(nullable),
+            ClickHouseType::UInt8 => UInt8(nullable),
+            ClickHouseType::UInt16 => UInt16(nullable),
+            ClickHouseType::UInt32 => UInt32(nullable),
+            ClickHouseType::UInt64 => UInt64(nullable),
+            ClickHouseType::Float32 => Float32(nullable),
+            ClickHouseType::Float64 => Float64(nullable),
+            ClickHouseType::Decimal { .. } => Decimal(nullable),
+            ClickHouseType::String | ClickHouseType::FixedString(_) => String(nullable),
+            ClickHouseType::Date | ClickHouseType::Date32 => Date(nullable),
+            ClickHouseType::DateTime | ClickHouseType::DateTime64(_) => DateTime(nullable),
+        }
+    }
+}
@@ -8,44 +8,243 @@ pub use self::typesystem::CSVTypeSystem;
 use super::{PartitionParser, Produce, Source, SourcePartition};
 use crate::{data_order::DataOrder, errors::ConnectorXError, sql::CXQuery};
 use anyhow::anyhow;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use fehler::{throw, throws};
 #[cfg(feature = "src_csv")]
 use regex::{Regex, RegexBuilder};
+use rust_decimal::Decimal;
 use std::collections::HashSet;
 use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::str::FromStr;
+
+/// f64 keeps 15 significant decimal digits, longer fixed-point values are read as decimals.
+const F64_SIGNIFICANT_DIGITS: usize = 15;
+
+/// Number of bytes inspected after a split offset to find the start of the next record.
+const RESYNC_WINDOW: u64 = 1 << 20;
+
+/// Dialect of the CSV files and options of the schema inference.
+#[derive(Debug, Clone)]
+pub struct CSVOptions {
+    /// Field delimiter, `,` by default.
+    pub delimiter: u8,
+    /// Quote character, `"` by default.
+    pub quote: u8,
+    /// Escape character for quotes inside quoted fields. Quotes are escaped by doubling them if `None`.
+    pub escape: Option<u8>,
+    /// Whether the first record is a header. Columns are named `column_{i}` otherwise.
+    pub has_header: bool,
+    /// Values read as null besides the empty field, e.g. `NULL` or `NA`.
+    pub null_values: Vec<String>,
+    /// `chrono` formats tried in order to parse dates.
+    pub date_formats: Vec<String>,
+    /// `chrono` formats tried in order to parse times.
+    pub time_formats: Vec<String>,
+    /// `chrono` formats tried in order to parse timestamps without time zone, which are taken as UTC.
+    /// Timestamps with an offset, e.g. RFC 3339, are always accepted.
+    pub datetime_formats: Vec<String>,
+    /// Number of records to inspect when inferring the schema, the whole file is scanned if `None`.
+    pub infer_sample_size: Option<usize>,
+}
+
+impl Default for CSVOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            escape: None,
+            has_header: true,
+            null_values: vec![],
+            date_formats: vec!["%Y-%m-%d".into()],
+            time_formats: vec!["%H:%M:%S%.f".into()],
+            datetime_formats: vec!["%Y-%m-%dT%H:%M:%S%.f".into(), "%Y-%m-%d %H:%M:%S%.f".into()],
+            infer_sample_size: Some(50),
+        }
+    }
+}
+
+impl CSVOptions {
+    fn reader_builder(&self) -> csv::ReaderBuilder {
+        let mut builder = csv::ReaderBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.escape.is_none())
+            .has_headers(self.has_header);
+        builder
+    }
+
+    fn is_null(&self, v: &str) -> bool {
+        v.is_empty() || self.null_values.iter().any(|n| n == v)
+    }
+
+    fn parse_date(&self, v: &str) -> Option<NaiveDate> {
+        self.date_formats
+            .iter()
+            .find_map(|fmt| NaiveDate::parse_from_str(v, fmt).ok())
+    }
+
+    fn parse_time(&self, v: &str) -> Option<NaiveTime> {
+        self.time_formats
+            .iter()
+            .find_map(|fmt| NaiveTime::parse_from_str(v, fmt).ok())
+    }
+
+    fn parse_datetime(&self, v: &str) -> Option<DateTime<Utc>> {
+        if let Ok(dt) = v.parse::<DateTime<Utc>>() {
+            return Some(dt);
+        }
+        self.datetime_formats
+            .iter()
+            .find_map(|fmt| NaiveDateTime::parse_from_str(v, fmt).ok())
+            .or_else(|| self.parse_date(v).and_then(|d| d.and_hms_opt(0, 0, 0)))
+            .map(|dt| dt.and_utc())
+    }
+}
 
 pub struct CSVSource {
     schema: Vec<CSVTypeSystem>,
+    options: CSVOptions,
+    partition_num: usize,
     files: Vec<CXQuery<String>>,
     names: Vec<String>,
 }
 
 impl CSVSource {
     pub fn new(schema: &[CSVTypeSystem]) -> Self {
+        Self::with_options(schema, CSVOptions::default())
+    }
+
+    /// Read files of the given dialect, the schema is inferred with `options` if `schema` is empty.
+    pub fn with_options(schema: &[CSVTypeSystem], options: CSVOptions) -> Self {
+        Self::new_with_partitions(schema, options, 1)
+    }
+
+    /// Split each file into `partition_num` byte ranges which are parsed in parallel.
+    pub fn new_with_partitions(
+        schema: &[CSVTypeSystem],
+        options: CSVOptions,
+        partition_num: usize,
+    ) -> Self {
         CSVSource {
             schema: schema.to_vec(),
+            options,
+            partition_num: partition_num.max(1),
             files: vec![],
             names: vec![],
         }
     }
 
+    /// Split the records of a file into at most `partition_num` byte ranges of similar size.
+    /// A line break may be inside a quoted field, so each split offset is moved to the start
+    /// of the next record with [`resync`](Self::resync). The records are scanned from the start
+    /// of the file instead if that is ambiguous.
+    #[throws(CSVSourceError)]
+    fn split_file(&self, fname: &str) -> Vec<(u64, u64)> {
+        let mut file = File::open(fname)?;
+        let size = file.metadata()?.len();
+        let mut reader = self
+            .options
+            .reader_builder()
+            .from_reader(File::open(fname)?);
+        // without a header this is the first record, which gives the number of fields too
+        let ncols = reader.byte_headers()?.len();
+        let start = match self.options.has_header {
+            true => reader.position().byte(),
+            false => 0,
+        };
+        let num = self.partition_num as u64;
+
+        let mut bounds = vec![start];
+        for i in 1..num {
+            let offset = start + (size - start) * i / num;
+            match self.resync(&mut file, offset, size, ncols)? {
+                Some(pos) => {
+                    if pos > *bounds.last().unwrap() && pos < size {
+                        bounds.push(pos);
+                    }
+                }
+                None => {
+                    bounds = self.scan_bounds(reader, start, size)?;
+                    break;
+                }
+            }
+        }
+        bounds.push(size);
+        bounds.dedup();
+        bounds.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
+    /// Find the start of the first record after `offset` by parsing the next bytes twice,
+    /// once taking `offset` as outside of a quoted field and once as inside of it. The
+    /// reading is kept if it is the only one where the quotes are well placed and the records
+    /// have `ncols` fields, e.g. it is the only one reaching the end of the file outside of a
+    /// quoted field. Returns `None` if no single reading is left.
+    ///
+    /// A reading which never leaves the quoted field is dropped, so a quoted field spanning
+    /// more than [`RESYNC_WINDOW`] bytes is not supported as a split point.
+    #[throws(CSVSourceError)]
+    fn resync(&self, file: &mut File, offset: u64, size: u64, ncols: usize) -> Option<u64> {
+        let mut window = vec![];
+        file.seek(SeekFrom::Start(offset))?;
+        file.take(RESYNC_WINDOW).read_to_end(&mut window)?;
+        let eof = offset + window.len() as u64 >= size;
+
+        let readings: Vec<_> = [false, true]
+            .iter()
+            .filter_map(|&quoted| {
+                let mut reading = Resync::new(quoted);
+                for (i, &c) in window.iter().enumerate() {
+                    if !reading.step(c, offset + i as u64, ncols, &self.options) {
+                        return None;
+                    }
+                }
+                reading.finish(eof, ncols)
+            })
+            .collect();
+        match readings.as_slice() {
+            [Some(pos)] => Some(*pos),
+            // the rest of the file is a single record
+            [None] if eof => Some(size),
+            _ => None,
+        }
+    }
+
+    /// Scan the records from `start` to split at the first record boundaries after the
+    /// split offsets.
+    #[throws(CSVSourceError)]
+    fn scan_bounds<R: Read>(&self, mut reader: csv::Reader<R>, start: u64, size: u64) -> Vec<u64> {
+        let num = self.partition_num as u64;
+        let mut bounds = vec![start];
+        let mut record = csv::ByteRecord::new();
+        while (bounds.len() as u64) < num && reader.read_byte_record(&mut record)? {
+            let pos = reader.position().byte();
+            if pos < size && pos >= start + (size - start) * bounds.len() as u64 / num {
+                bounds.push(pos);
+            }
+        }
+        bounds
+    }
+
     #[throws(CSVSourceError)]
     pub fn infer_schema(&mut self) -> Vec<CSVTypeSystem> {
         // regular expressions for infer CSVTypeSystem from string
-        let decimal_re: Regex = Regex::new(r"^-?(\d+\.\d+)$")?;
-        let integer_re: Regex = Regex::new(r"^-?(\d+)$")?;
+        let decimal_re: Regex = Regex::new(r"^[-+]?(\d+\.\d+)$")?;
+        let float_re: Regex = Regex::new(r"^[-+]?(\d+(\.\d*)?|\.\d+)([eE][-+]?\d+)?$")?;
+        let integer_re: Regex = Regex::new(r"^[-+]?(\d+)$")?;
         let boolean_re: Regex = RegexBuilder::new(r"^(true)$|^(false)$")
             .case_insensitive(true)
             .build()?;
-        let datetime_re: Regex = Regex::new(r"^\d{4}-\d\d-\d\dT\d\d:\d\d:\d\d$")?;
 
         // read max_records rows to infer possible CSVTypeSystems for each field
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(true)
+        let mut reader = self
+            .options
+            .reader_builder()
             .from_reader(File::open(self.files[0].as_str())?);
 
-        let max_records_to_read = 50;
+        let max_records_to_read = self.options.infer_sample_size.unwrap_or(usize::MAX);
         let num_cols = self.names.len();
 
         let mut column_types: Vec<HashSet<CSVTypeSystem>> = vec![HashSet::new(); num_cols];
@@ -59,7 +258,7 @@ impl CSVSource {
             }
             for field_counter in 0..num_cols {
                 if let Some(string) = record.get(field_counter) {
-                    if string.is_empty() {
+                    if self.options.is_null(string) {
                         nulls[field_counter] = true;
                     } else {
                         let dt: CSVTypeSystem;
@@ -68,11 +267,26 @@ impl CSVSource {
                             dt = CSVTypeSystem::String(false);
                         } else if boolean_re.is_match(string) {
                             dt = CSVTypeSystem::Bool(false);
+                        } else if integer_re.is_match(string) {
+                            dt = if string.parse::<i64>().is_ok() {
+                                CSVTypeSystem::I64(false)
+                            } else {
+                                CSVTypeSystem::Decimal(false)
+                            };
                         } else if decimal_re.is_match(string) {
+                            let digits = string.chars().filter(|c| c.is_ascii_digit()).count();
+                            dt = if digits > F64_SIGNIFICANT_DIGITS {
+                                CSVTypeSystem::Decimal(false)
+                            } else {
+                                CSVTypeSystem::F64(false)
+                            };
+                        } else if float_re.is_match(string) {
                             dt = CSVTypeSystem::F64(false);
-                        } else if integer_re.is_match(string) {
-                            dt = CSVTypeSystem::I64(false);
-                        } else if datetime_re.is_match(string) {
+                        } else if self.options.parse_date(string).is_some() {
+                            dt = CSVTypeSystem::Date(false);
+                        } else if self.options.parse_time(string).is_some() {
+                            dt = CSVTypeSystem::Time(false);
+                        } else if self.options.parse_datetime(string).is_some() {
                             dt = CSVTypeSystem::DateTime(false);
                         } else {
                             dt = CSVTypeSystem::String(false);
@@ -89,45 +303,47 @@ impl CSVSource {
         for field_counter in 0..num_cols {
             let possibilities = &column_types[field_counter];
             let has_nulls = nulls[field_counter];
-
-            match possibilities.len() {
-                1 => {
-                    for dt in possibilities.iter() {
-                        match *dt {
-                            CSVTypeSystem::I64(false) => {
-                                schema.push(CSVTypeSystem::I64(has_nulls));
-                            }
-                            CSVTypeSystem::F64(false) => {
-                                schema.push(CSVTypeSystem::F64(has_nulls));
-                            }
-                            CSVTypeSystem::Bool(false) => {
-                                schema.push(CSVTypeSystem::Bool(has_nulls));
-                            }
-                            CSVTypeSystem::String(false) => {
-                                schema.push(CSVTypeSystem::String(has_nulls));
-                            }
-                            CSVTypeSystem::DateTime(false) => {
-                                schema.push(CSVTypeSystem::DateTime(has_nulls));
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-                2 => {
-                    if possibilities.contains(&CSVTypeSystem::I64(false))
-                        && possibilities.contains(&CSVTypeSystem::F64(false))
-                    {
-                        // Integer && Float -> Float
-                        schema.push(CSVTypeSystem::F64(has_nulls));
-                    } else {
-                        // Conflicting CSVTypeSystems -> String
-                        schema.push(CSVTypeSystem::String(has_nulls));
-                    }
-                }
-                _ => {
-                    // Conflicting CSVTypeSystems -> String
-                    schema.push(CSVTypeSystem::String(has_nulls));
-                }
+            let only = |candidates: &[CSVTypeSystem]| {
+                possibilities.iter().all(|dt| candidates.contains(dt))
+            };
+
+            if possibilities.is_empty() {
+                // no values at all
+                schema.push(CSVTypeSystem::String(has_nulls));
+            } else if possibilities.len() == 1 {
+                let dt = *possibilities.iter().next().unwrap();
+                schema.push(match dt {
+                    CSVTypeSystem::I64(_) => CSVTypeSystem::I64(has_nulls),
+                    CSVTypeSystem::F64(_) => CSVTypeSystem::F64(has_nulls),
+                    CSVTypeSystem::Decimal(_) => CSVTypeSystem::Decimal(has_nulls),
+                    CSVTypeSystem::Bool(_) => CSVTypeSystem::Bool(has_nulls),
+                    CSVTypeSystem::String(_) => CSVTypeSystem::String(has_nulls),
+                    CSVTypeSystem::DateTime(_) => CSVTypeSystem::DateTime(has_nulls),
+                    CSVTypeSystem::Date(_) => CSVTypeSystem::Date(has_nulls),
+                    CSVTypeSystem::Time(_) => CSVTypeSystem::Time(has_nulls),
+                });
+            } else if possibilities.contains(&CSVTypeSystem::Decimal(false))
+                && only(&[
+                    CSVTypeSystem::I64(false),
+                    CSVTypeSystem::F64(false),
+                    CSVTypeSystem::Decimal(false),
+                ])
+            {
+                // Integer/Float && Decimal -> Decimal, as f64 would lose digits of the decimals
+                schema.push(CSVTypeSystem::Decimal(has_nulls));
+            } else if only(&[
+                CSVTypeSystem::I64(false),
+                CSVTypeSystem::F64(false),
+                CSVTypeSystem::Decimal(false),
+            ]) {
+                // Integer && Float -> Float
+                schema.push(CSVTypeSystem::F64(has_nulls));
+            } else if only(&[CSVTypeSystem::Date(false), CSVTypeSystem::DateTime(false)]) {
+                // Date && DateTime -> DateTime
+                schema.push(CSVTypeSystem::DateTime(has_nulls));
+            } else {
+                // Conflicting CSVTypeSystems -> String
+                schema.push(CSVTypeSystem::String(has_nulls));
             }
         }
         schema
@@ -155,18 +371,24 @@ impl Source for CSVSource {
 
     #[throws(CSVSourceError)]
     fn fetch_metadata(&mut self) {
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(true)
+        let mut reader = self
+            .options
+            .reader_builder()
             .from_reader(File::open(self.files[0].as_str())?);
-        let header = reader.headers()?;
 
-        self.names = header.iter().map(|s| s.to_string()).collect();
+        self.names = if self.options.has_header {
+            reader.headers()?.iter().map(|s| s.to_string()).collect()
+        } else {
+            let mut record = csv::StringRecord::new();
+            reader.read_record(&mut record)?;
+            (0..record.len()).map(|i| format!("column_{}", i)).collect()
+        };
 
         if self.schema.is_empty() {
             self.schema = self.infer_schema()?;
         }
 
-        assert_eq!(header.len(), self.schema.len());
+        assert_eq!(self.names.len(), self.schema.len());
     }
 
     #[throws(CSVSourceError)]
@@ -185,15 +407,128 @@ impl Source for CSVSource {
     #[throws(CSVSourceError)]
     fn partition(self) -> Vec<Self::Partition> {
         let mut partitions = vec![];
-        for file in self.files {
-            partitions.push(CSVSourcePartition::new(file)?);
+        for file in &self.files {
+            if self.partition_num > 1 {
+                for range in self.split_file(file.as_str())? {
+                    partitions.push(CSVSourcePartition::with_options(
+                        file.clone(),
+                        Some(range),
+                        self.options.clone(),
+                    ));
+                }
+            } else {
+                partitions.push(CSVSourcePartition::with_options(
+                    file.clone(),
+                    None,
+                    self.options.clone(),
+                ));
+            }
         }
         partitions
     }
 }
 
+#[derive(Clone, Copy)]
+enum ResyncState {
+    Field { start: bool },
+    Quoted,
+    Escaped,
+    // a quote in a quoted field, which ends it unless it is doubled
+    Quote,
+}
+
+/// One reading of the bytes following a split offset, see [`CSVSource::resync`].
+struct Resync {
+    state: ResyncState,
+    // whether the reading got out of the quoted field it started in
+    closed: bool,
+    // the first record boundary, after which the records are complete and can be checked
+    boundary: Option<u64>,
+    fields: usize,
+    empty: bool,
+}
+
+impl Resync {
+    fn new(quoted: bool) -> Self {
+        Self {
+            state: match quoted {
+                true => ResyncState::Quoted,
+                false => ResyncState::Field { start: true },
+            },
+            closed: !quoted,
+            boundary: None,
+            fields: 0,
+            empty: true,
+        }
+    }
+
+    /// Read the byte `c` at `pos`, returns false if the reading is not possible.
+    fn step(&mut self, c: u8, pos: u64, ncols: usize, options: &CSVOptions) -> bool {
+        use ResyncState::*;
+
+        match self.state {
+            Field { start } if c == options.quote => {
+                if !start {
+                    return false;
+                }
+                self.state = Quoted;
+                self.empty = false;
+            }
+            Quoted if Some(c) == options.escape => self.state = Escaped,
+            Quoted if c == options.quote => self.state = Quote,
+            Quoted | Escaped => self.state = Quoted,
+            Quote if c == options.quote && options.escape.is_none() => self.state = Quoted,
+            Field { .. } | Quote if c == options.delimiter => {
+                self.closed = true;
+                self.state = Field { start: true };
+                self.fields += 1;
+                self.empty = false;
+            }
+            Field { .. } | Quote if c == b'\n' || c == b'\r' => {
+                self.closed = true;
+                self.state = Field { start: true };
+                if !self.end_record(ncols) {
+                    return false;
+                }
+                match self.boundary {
+                    // skip the rest of a `\r\n` and the empty lines
+                    Some(b) if b == pos && self.empty => self.boundary = Some(pos + 1),
+                    Some(_) => {}
+                    None => self.boundary = Some(pos + 1),
+                }
+                self.fields = 0;
+                self.empty = true;
+            }
+            Field { .. } => {
+                self.state = Field { start: false };
+                self.empty = false;
+            }
+            Quote => return false,
+        }
+        true
+    }
+
+    /// Check the number of fields of a complete record.
+    fn end_record(&self, ncols: usize) -> bool {
+        self.empty || self.boundary.is_none() || self.fields + 1 == ncols
+    }
+
+    /// The first record boundary, or `None` if there is none. The outer `None` means the
+    /// reading is not possible.
+    fn finish(self, eof: bool, ncols: usize) -> Option<Option<u64>> {
+        match self.state {
+            ResyncState::Quoted | ResyncState::Escaped if eof || !self.closed => None,
+            _ if eof && !self.end_record(ncols) => None,
+            _ => Some(self.boundary),
+        }
+    }
+}
+
 pub struct CSVSourcePartition {
-    records: Vec<csv::StringRecord>,
+    fname: CXQuery<String>,
+    range: Option<(u64, u64)>,
+    options: CSVOptions,
+    records: Option<Vec<csv::StringRecord>>,
     counter: usize,
     nrows: usize,
     ncols: usize,
@@ -202,25 +537,57 @@ pub struct CSVSourcePartition {
 impl CSVSourcePartition {
     #[throws(CSVSourceError)]
     pub fn new(fname: CXQuery<String>) -> Self {
-        let reader = csv::ReaderBuilder::new()
-            .has_headers(true)
-            .from_reader(File::open(fname.as_str())?);
-        let mut records = vec![];
-        reader
-            .into_records()
-            .try_for_each(|v| -> Result<(), CSVSourceError> {
-                records.push(v.map_err(|e| anyhow!(e))?);
-                Ok(())
-            })?;
-
-        let nrows = records.len();
-        let ncols = if nrows > 0 { records[0].len() } else { 0 };
+        let mut partition = Self::with_options(fname, None, CSVOptions::default());
+        partition.load()?;
+        partition
+    }
 
+    /// `range` is the byte range of the records to read, the whole file is read if `None`.
+    pub fn with_options(
+        fname: CXQuery<String>,
+        range: Option<(u64, u64)>,
+        options: CSVOptions,
+    ) -> Self {
         Self {
-            records,
+            fname,
+            range,
+            options,
+            records: None,
             counter: 0,
-            nrows,
-            ncols,
+            nrows: 0,
+            ncols: 0,
+        }
+    }
+
+    /// Read the records of the partition, which is done lazily so that partitions load in parallel.
+    #[throws(CSVSourceError)]
+    fn load(&mut self) {
+        if self.records.is_none() {
+            let mut file = File::open(self.fname.as_str())?;
+            let reader: Box<dyn Read> = match self.range {
+                Some((start, end)) => {
+                    file.seek(SeekFrom::Start(start))?;
+                    Box::new(file.take(end - start))
+                }
+                None => Box::new(file),
+            };
+            let reader = self
+                .options
+                .reader_builder()
+                .has_headers(self.options.has_header && self.range.is_none())
+                .from_reader(reader);
+
+            let mut records = vec![];
+            reader
+                .into_records()
+                .try_for_each(|v| -> Result<(), CSVSourceError> {
+                    records.push(v.map_err(|e| anyhow!(e))?);
+                    Ok(())
+                })?;
+
+            self.nrows = records.len();
+            self.ncols = if self.nrows > 0 { records[0].len() } else { 0 };
+            self.records = Some(records);
         }
     }
 }
@@ -232,7 +599,9 @@ impl SourcePartition for CSVSourcePartition {
 
     /// The parameter `query` is the path of the csv file
     #[throws(CSVSourceError)]
-    fn result_rows(&mut self) {}
+    fn result_rows(&mut self) {
+        self.load()?;
+    }
 
     fn nrows(&self) -> usize {
         self.nrows
@@ -244,8 +613,10 @@ impl SourcePartition for CSVSourcePartition {
 
     #[throws(CSVSourceError)]
     fn parser(&mut self) -> Self::Parser<'_> {
+        self.load()?;
         CSVSourcePartitionParser {
-            records: &mut self.records,
+            records: self.records.as_deref().unwrap_or_default(),
+            options: &self.options,
             counter: &mut self.counter,
             ncols: self.ncols,
         }
@@ -253,113 +624,92 @@ impl SourcePartition for CSVSourcePartition {
 }
 
 pub struct CSVSourcePartitionParser<'a> {
-    records: &'a mut [csv::StringRecord],
+    records: &'a [csv::StringRecord],
+    options: &'a CSVOptions,
     counter: &'a mut usize,
     ncols: usize,
 }
 
 impl<'a> CSVSourcePartitionParser<'a> {
-    fn next_val(&mut self) -> &str {
-        let v: &str = self.records[*self.counter / self.ncols][*self.counter % self.ncols].as_ref();
+    fn next_val(&mut self) -> &'a str {
+        let records: &'a [csv::StringRecord] = self.records;
+        let v: &str = records[*self.counter / self.ncols][*self.counter % self.ncols].as_ref();
         *self.counter += 1;
 
         v
     }
-}
-
-impl<'a> PartitionParser<'a> for CSVSourcePartitionParser<'a> {
-    type TypeSystem = CSVTypeSystem;
-    type Error = CSVSourceError;
-
-    #[throws(CSVSourceError)]
-    fn fetch_next(&mut self) -> (usize, bool) {
-        (self.records.len(), true)
-    }
-}
-
-impl<'r, 'a> Produce<'r, i64> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
 
+    /// Parse the next value, which must not be null.
     #[throws(CSVSourceError)]
-    fn produce(&mut self) -> i64 {
+    fn parse_next<T, F>(&mut self, parse: F) -> T
+    where
+        F: FnOnce(&CSVOptions, &str) -> Option<T>,
+    {
         let v = self.next_val();
-        v.parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<i64>(Some(v.into())))?
+        parse(self.options, v)
+            .ok_or_else(|| ConnectorXError::cannot_produce::<T>(Some(v.into())))?
     }
-}
-
-impl<'r, 'a> Produce<'r, Option<i64>> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
 
+    /// Parse the next value, null values are `None`.
     #[throws(CSVSourceError)]
-    fn produce(&mut self) -> Option<i64> {
+    fn parse_next_opt<T, F>(&mut self, parse: F) -> Option<T>
+    where
+        F: FnOnce(&CSVOptions, &str) -> Option<T>,
+    {
         let v = self.next_val();
-        if v.is_empty() {
+        if self.options.is_null(v) {
             return None;
         }
-        let v = v
-            .parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<Option<i64>>(Some(v.into())))?;
+        let v = parse(self.options, v)
+            .ok_or_else(|| ConnectorXError::cannot_produce::<Option<T>>(Some(v.into())))?;
 
         Some(v)
     }
 }
 
-impl<'r, 'a> Produce<'r, f64> for CSVSourcePartitionParser<'a> {
+impl<'a> PartitionParser<'a> for CSVSourcePartitionParser<'a> {
+    type TypeSystem = CSVTypeSystem;
     type Error = CSVSourceError;
 
     #[throws(CSVSourceError)]
-    fn produce(&mut self) -> f64 {
-        let v = self.next_val();
-        v.parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<f64>(Some(v.into())))?
+    fn fetch_next(&mut self) -> (usize, bool) {
+        (self.records.len(), true)
     }
 }
 
-impl<'r, 'a> Produce<'r, Option<f64>> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
+macro_rules! impl_produce {
+    ($($t: ty => $parse: expr,)+) => {
+        $(
+            impl<'r, 'a> Produce<'r, $t> for CSVSourcePartitionParser<'a> {
+                type Error = CSVSourceError;
 
-    #[throws(CSVSourceError)]
-    fn produce(&mut self) -> Option<f64> {
-        let v = self.next_val();
-        if v.is_empty() {
-            return None;
-        }
-        let v = v
-            .parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<Option<f64>>(Some(v.into())))?;
-
-        Some(v)
-    }
-}
+                #[throws(CSVSourceError)]
+                fn produce(&'r mut self) -> $t {
+                    self.parse_next($parse)?
+                }
+            }
 
-impl<'r, 'a> Produce<'r, bool> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
+            impl<'r, 'a> Produce<'r, Option<$t>> for CSVSourcePartitionParser<'a> {
+                type Error = CSVSourceError;
 
-    #[throws(CSVSourceError)]
-    fn produce(&mut self) -> bool {
-        let v = self.next_val();
-        v.parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<bool>(Some(v.into())))?
-    }
+                #[throws(CSVSourceError)]
+                fn produce(&'r mut self) -> Option<$t> {
+                    self.parse_next_opt($parse)?
+                }
+            }
+        )+
+    };
 }
 
-impl<'r, 'a> Produce<'r, Option<bool>> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
-
-    #[throws(CSVSourceError)]
-    fn produce(&mut self) -> Option<bool> {
-        let v = self.next_val();
-        if v.is_empty() {
-            return None;
-        }
-        let v = v
-            .parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<Option<bool>>(Some(v.into())))?;
-
-        Some(v)
-    }
-}
+impl_produce!(
+    i64 => |_, v| v.parse().ok(),
+    f64 => |_, v| v.parse().ok(),
+    bool => |_, v: &str| v.to_lowercase().parse().ok(),
+    Decimal => |_, v| Decimal::from_str(v).or_else(|_| Decimal::from_scientific(v)).ok(),
+    NaiveDate => |opts: &CSVOptions, v| opts.parse_date(v),
+    NaiveTime => |opts: &CSVOptions, v| opts.parse_time(v),
+    DateTime<Utc> => |opts: &CSVOptions, v| opts.parse_datetime(v),
+);
 
 impl<'r, 'a> Produce<'r, String> for CSVSourcePartitionParser<'a> {
     type Error = CSVSourceError;
@@ -374,37 +724,14 @@ impl<'r, 'a> Produce<'r, String> for CSVSourcePartitionParser<'a> {
 impl<'a, 'r> Produce<'r, Option<String>> for CSVSourcePartitionParser<'a> {
     type Error = CSVSourceError;
 
+    /// Empty fields are empty strings, only the configured null tokens are null.
     #[throws(CSVSourceError)]
     fn produce(&'r mut self) -> Option<String> {
         let v = self.next_val();
-
-        Some(String::from(v))
-    }
-}
-
-impl<'r, 'a> Produce<'r, DateTime<Utc>> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
-
-    #[throws(CSVSourceError)]
-    fn produce(&mut self) -> DateTime<Utc> {
-        let v = self.next_val();
-        v.parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<DateTime<Utc>>(Some(v.into())))?
-    }
-}
-
-impl<'r, 'a> Produce<'r, Option<DateTime<Utc>>> for CSVSourcePartitionParser<'a> {
-    type Error = CSVSourceError;
-
-    #[throws(CSVSourceError)]
-    fn produce(&mut self) -> Option<DateTime<Utc>> {
-        let v = self.next_val();
-        if v.is_empty() {
+        if self.options.null_values.iter().any(|n| n == v) {
             return None;
         }
-        let v = v
-            .parse()
-            .map_err(|_| ConnectorXError::cannot_produce::<DateTime<Utc>>(Some(v.into())))?;
-        Some(v)
+
+        Some(String::from(v))
     }
 }
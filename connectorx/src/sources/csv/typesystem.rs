@@ -6,7 +6,8 @@
 // 3. Add `DataType::T => N` to the macro impl_transmit!.
 //
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use rust_decimal::Decimal;
 /// This is a dummy type system used in this library.
 /// For all the sources, their output values must be one of the types defined by DummyTypeSystem.
 /// For all the destinations, they must support writing any value whose type is defined by DummyTypeSystem.
@@ -17,6 +18,9 @@ pub enum CSVTypeSystem {
     Bool(bool),
     String(bool),
     DateTime(bool),
+    Date(bool),
+    Time(bool),
+    Decimal(bool),
 }
 
 impl_typesystem! {
@@ -27,5 +31,8 @@ impl_typesystem! {
         { Bool => bool }
         { String => String }
         { DateTime => DateTime<Utc> }
+        { Date => NaiveDate }
+        { Time => NaiveTime }
+        { Decimal => Decimal }
     }
 }
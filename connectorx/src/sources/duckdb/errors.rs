@@ -0,0 +1,24 @@
+use std::string::FromUtf8Error;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DuckDBSourceError {
+    #[error("Unsupported DuckDB type {0}")]
+    UnsupportedType(String),
+
+    #[error(transparent)]
+    ConnectorXError(#[from] crate::errors::ConnectorXError),
+
+    #[error(transparent)]
+    DuckDBError(#[from] duckdb::Error),
+
+    #[error(transparent)]
+    DuckDBPoolError(#[from] r2d2::Error),
+
+    #[error(transparent)]
+    DuckDBUrlDecodeError(#[from] FromUtf8Error),
+
+    /// Any other errors that are too trivial to be put here explicitly.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
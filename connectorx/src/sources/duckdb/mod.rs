@@ -0,0 +1,341 @@
+//! Source implementation for DuckDB embedded database.
+
+mod errors;
+mod typesystem;
+
+pub use self::errors::DuckDBSourceError;
+use crate::{
+    data_order::DataOrder,
+    errors::ConnectorXError,
+    sources::{PartitionParser, Produce, Source, SourcePartition},
+    sql::{count_query, limit1_query, CXQuery},
+    utils::DummyBox,
+};
+use anyhow::anyhow;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use duckdb::{types::ValueRef, DuckdbConnectionManager, Row, Rows, Statement};
+use fallible_streaming_iterator::FallibleStreamingIterator;
+use fehler::{throw, throws};
+use log::debug;
+use owning_ref::OwningHandle;
+use r2d2::{Pool, PooledConnection};
+use rust_decimal::Decimal;
+use sqlparser::dialect::DuckDbDialect;
+use std::convert::TryFrom;
+pub use typesystem::DuckDBTypeSystem;
+use urlencoding::decode;
+
+pub struct DuckDBSource {
+    pool: Pool<DuckdbConnectionManager>,
+    origin_query: Option<String>,
+    queries: Vec<CXQuery<String>>,
+    names: Vec<String>,
+    schema: Vec<DuckDBTypeSystem>,
+}
+
+impl DuckDBSource {
+    #[throws(DuckDBSourceError)]
+    pub fn new(conn: &str, nconn: usize) -> Self {
+        let decoded_conn = decode(conn)?.into_owned();
+        debug!("decoded conn: {}", decoded_conn);
+        let manager = DuckdbConnectionManager::file(decoded_conn)?;
+        let pool = r2d2::Pool::builder()
+            .max_size(nconn as u32)
+            .build(manager)?;
+
+        Self {
+            pool,
+            origin_query: None,
+            queries: vec![],
+            names: vec![],
+            schema: vec![],
+        }
+    }
+}
+
+impl Source for DuckDBSource
+where
+    DuckDBSourcePartition: SourcePartition<TypeSystem = DuckDBTypeSystem>,
+{
+    const DATA_ORDERS: &'static [DataOrder] = &[DataOrder::RowMajor];
+    type Partition = DuckDBSourcePartition;
+    type TypeSystem = DuckDBTypeSystem;
+    type Error = DuckDBSourceError;
+
+    #[throws(DuckDBSourceError)]
+    fn set_data_order(&mut self, data_order: DataOrder) {
+        if !matches!(data_order, DataOrder::RowMajor) {
+            throw!(ConnectorXError::UnsupportedDataOrder(data_order));
+        }
+    }
+
+    fn set_queries<Q: ToString>(&mut self, queries: &[CXQuery<Q>]) {
+        self.queries = queries.iter().map(|q| q.map(Q::to_string)).collect();
+    }
+
+    fn set_origin_query(&mut self, query: Option<String>) {
+        self.origin_query = query;
+    }
+
+    #[throws(DuckDBSourceError)]
+    fn fetch_metadata(&mut self) {
+        assert!(!self.queries.is_empty());
+        let conn = self.pool.get()?;
+
+        // unlike sqlite, the result schema of duckdb is typed even if the result set is empty,
+        // so we only need to look at the first query
+        let l1query = limit1_query(&self.queries[0], &DuckDbDialect {})?;
+        let mut stmt = conn.prepare(l1query.as_str())?;
+        let rows = stmt.query([])?;
+        let stmt = rows
+            .as_ref()
+            .ok_or_else(|| anyhow!("DuckDB statement is not executed"))?;
+
+        self.names = stmt.column_names();
+        self.schema = (0..stmt.column_count())
+            .map(|i| DuckDBTypeSystem::try_from(&stmt.column_type(i)))
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+
+    #[throws(DuckDBSourceError)]
+    fn result_rows(&mut self) -> Option<usize> {
+        match &self.origin_query {
+            Some(q) => {
+                let cxq = CXQuery::Naked(q.clone());
+                let conn = self.pool.get()?;
+                let nrows =
+                    conn.query_row(count_query(&cxq, &DuckDbDialect {})?.as_str(), [], |row| {
+                        Ok(row.get::<_, i64>(0)? as usize)
+                    })?;
+                Some(nrows)
+            }
+            None => None,
+        }
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    fn schema(&self) -> Vec<Self::TypeSystem> {
+        self.schema.clone()
+    }
+
+    #[throws(DuckDBSourceError)]
+    fn partition(self) -> Vec<Self::Partition> {
+        let mut ret = vec![];
+        for query in self.queries {
+            let conn = self.pool.get()?;
+
+            ret.push(DuckDBSourcePartition::new(conn, &query, &self.schema));
+        }
+        ret
+    }
+}
+
+pub struct DuckDBSourcePartition {
+    conn: PooledConnection<DuckdbConnectionManager>,
+    query: CXQuery<String>,
+    schema: Vec<DuckDBTypeSystem>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl DuckDBSourcePartition {
+    pub fn new(
+        conn: PooledConnection<DuckdbConnectionManager>,
+        query: &CXQuery<String>,
+        schema: &[DuckDBTypeSystem],
+    ) -> Self {
+        Self {
+            conn,
+            query: query.clone(),
+            schema: schema.to_vec(),
+            nrows: 0,
+            ncols: schema.len(),
+        }
+    }
+}
+
+impl SourcePartition for DuckDBSourcePartition {
+    type TypeSystem = DuckDBTypeSystem;
+    type Parser<'a> = DuckDBSourcePartitionParser<'a>;
+    type Error = DuckDBSourceError;
+
+    #[throws(DuckDBSourceError)]
+    fn result_rows(&mut self) {
+        self.nrows = self.conn.query_row(
+            count_query(&self.query, &DuckDbDialect {})?.as_str(),
+            [],
+            |row| Ok(row.get::<_, i64>(0)? as usize),
+        )?;
+    }
+
+    #[throws(DuckDBSourceError)]
+    fn parser(&mut self) -> Self::Parser<'_> {
+        DuckDBSourcePartitionParser::new(&self.conn, self.query.as_str(), &self.schema)?
+    }
+
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+}
+
+unsafe impl<'a> Send for DuckDBSourcePartitionParser<'a> {}
+
+pub struct DuckDBSourcePartitionParser<'a> {
+    rows: OwningHandle<Box<Statement<'a>>, DummyBox<Rows<'a>>>,
+    ncols: usize,
+    current_col: usize,
+    current_consumed: bool,
+    is_finished: bool,
+}
+
+impl<'a> DuckDBSourcePartitionParser<'a> {
+    #[throws(DuckDBSourceError)]
+    pub fn new(
+        conn: &'a PooledConnection<DuckdbConnectionManager>,
+        query: &str,
+        schema: &[DuckDBTypeSystem],
+    ) -> Self {
+        let stmt: Statement<'a> = conn.prepare(query)?;
+
+        // Safety: DummyBox borrows the on-heap stmt, which is owned by the OwningHandle.
+        // No matter how we move the owning handle (thus the Box<Statment>), the Statement
+        // keeps its address static on the heap, thus the borrow of MyRows keeps valid.
+        let rows: OwningHandle<Box<Statement<'a>>, DummyBox<Rows<'a>>> =
+            OwningHandle::new_with_fn(Box::new(stmt), |stmt: *const Statement<'a>| unsafe {
+                DummyBox((*(stmt as *mut Statement<'_>)).query([]).unwrap())
+            });
+        Self {
+            rows,
+            ncols: schema.len(),
+            current_col: 0,
+            current_consumed: true,
+            is_finished: false,
+        }
+    }
+
+    #[throws(DuckDBSourceError)]
+    fn next_loc(&mut self) -> (&Row<'_>, usize) {
+        self.current_consumed = true;
+        let row: &Row = (*self.rows)
+            .get()
+            .ok_or_else(|| anyhow!("DuckDB empty current row"))?;
+        let col = self.current_col;
+        self.current_col = (self.current_col + 1) % self.ncols;
+        (row, col)
+    }
+}
+
+impl<'a> PartitionParser<'a> for DuckDBSourcePartitionParser<'a> {
+    type TypeSystem = DuckDBTypeSystem;
+    type Error = DuckDBSourceError;
+
+    #[throws(DuckDBSourceError)]
+    fn fetch_next(&mut self) -> (usize, bool) {
+        assert!(self.current_col == 0);
+
+        if !self.current_consumed {
+            return (1, false);
+        } else if self.is_finished {
+            return (0, true);
+        }
+
+        match (*self.rows).next()? {
+            Some(_) => {
+                self.current_consumed = false;
+                (1, false)
+            }
+            None => {
+                self.is_finished = true;
+                (0, true)
+            }
+        }
+    }
+}
+
+macro_rules! impl_produce {
+    ($($t: ty,)+) => {
+        $(
+            impl<'r, 'a> Produce<'r, $t> for DuckDBSourcePartitionParser<'a> {
+                type Error = DuckDBSourceError;
+
+                #[throws(DuckDBSourceError)]
+                fn produce(&'r mut self) -> $t {
+                    let (row, col) = self.next_loc()?;
+                    let val = row.get(col)?;
+                    val
+                }
+            }
+
+            impl<'r, 'a> Produce<'r, Option<$t>> for DuckDBSourcePartitionParser<'a> {
+                type Error = DuckDBSourceError;
+
+                #[throws(DuckDBSourceError)]
+                fn produce(&'r mut self) -> Option<$t> {
+                    let (row, col) = self.next_loc()?;
+                    let val = row.get(col)?;
+                    val
+                }
+            }
+        )+
+    };
+}
+
+impl_produce!(
+    bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+    String,
+    Vec<u8>,
+    NaiveDate,
+    NaiveTime,
+    NaiveDateTime,
+    DateTime<Utc>,
+);
+
+// duckdb does not implement `FromSql` for `Decimal`, read it from the raw value instead
+impl<'r, 'a> Produce<'r, Decimal> for DuckDBSourcePartitionParser<'a> {
+    type Error = DuckDBSourceError;
+
+    #[throws(DuckDBSourceError)]
+    fn produce(&'r mut self) -> Decimal {
+        let (row, col) = self.next_loc()?;
+        match row.get_ref(col)? {
+            ValueRef::Decimal(v) => v,
+            v => throw!(ConnectorXError::cannot_produce::<Decimal>(Some(format!(
+                "{:?}",
+                v
+            )))),
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<Decimal>> for DuckDBSourcePartitionParser<'a> {
+    type Error = DuckDBSourceError;
+
+    #[throws(DuckDBSourceError)]
+    fn produce(&'r mut self) -> Option<Decimal> {
+        let (row, col) = self.next_loc()?;
+        match row.get_ref(col)? {
+            ValueRef::Decimal(v) => Some(v),
+            ValueRef::Null => None,
+            v => throw!(ConnectorXError::cannot_produce::<Option<Decimal>>(Some(
+                format!("{:?}", v)
+            ))),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::errors::DuckDBSourceError;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use duckdb::arrow::datatypes::DataType;
+use fehler::{throw, throws};
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DuckDBTypeSystem {
+    Bool(bool),
+    TinyInt(bool),
+    SmallInt(bool),
+    Integer(bool),
+    BigInt(bool),
+    UTinyInt(bool),
+    USmallInt(bool),
+    UInteger(bool),
+    UBigInt(bool),
+    Float(bool),
+    Double(bool),
+    Decimal(bool),
+    Varchar(bool),
+    Blob(bool),
+    Date(bool),
+    Time(bool),
+    Timestamp(bool),
+    TimestampTz(bool),
+}
+
+impl_typesystem! {
+    system = DuckDBTypeSystem,
+    mappings = {
+        { Bool => bool }
+        { TinyInt => i8 }
+        { SmallInt => i16 }
+        { Integer => i32 }
+        { BigInt => i64 }
+        { UTinyInt => u8 }
+        { USmallInt => u16 }
+        { UInteger => u32 }
+        { UBigInt => u64 }
+        { Float => f32 }
+        { Double => f64 }
+        { Decimal => Decimal }
+        { Varchar => String }
+        { Blob => Vec<u8> }
+        { Date => NaiveDate }
+        { Time => NaiveTime }
+        { Timestamp => NaiveDateTime }
+        { TimestampTz => DateTime<Utc> }
+    }
+}
+
+// DuckDB exposes the result schema of a statement as arrow data types,
+// derive our type system from them. Every column is considered nullable.
+impl TryFrom<&DataType> for DuckDBTypeSystem {
+    type Error = DuckDBSourceError;
+
+    #[throws(DuckDBSourceError)]
+    fn try_from(ty: &DataType) -> Self {
+        use DuckDBTypeSystem::*;
+        match ty {
+            DataType::Boolean => Bool(true),
+            DataType::Int8 => TinyInt(true),
+            DataType::Int16 => SmallInt(true),
+            DataType::Int32 => Integer(true),
+            DataType::Int64 => BigInt(true),
+            DataType::UInt8 => UTinyInt(true),
+            DataType::UInt16 => USmallInt(true),
+            DataType::UInt32 => UInteger(true),
+            DataType::UInt64 => UBigInt(true),
+            DataType::Float32 => Float(true),
+            DataType::Float64 => Double(true),
+            DataType::Decimal128(_, _) => Decimal(true),
+            DataType::Utf8 | DataType::LargeUtf8 => Varchar(true),
+            DataType::Binary | DataType::LargeBinary => Blob(true),
+            DataType::Date32 => Date(true),
+            DataType::Time64(_) => Time(true),
+            DataType::Timestamp(_, None) => Timestamp(true),
+            DataType::Timestamp(_, Some(_)) => TimestampTz(true),
+            _ => throw!(DuckDBSourceError::UnsupportedType(format!("{:?}", ty))),
+        }
+    }
+}
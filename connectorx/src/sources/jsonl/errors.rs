@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum JSONLSourceError {
+    #[error(transparent)]
+    ConnectorXError(#[from] crate::errors::ConnectorXError),
+
+    #[error(transparent)]
+    JSONError(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    IOError(#[from] std::io::Error),
+
+    /// Any other errors that are too trivial to be put here explicitly.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
@@ -0,0 +1,426 @@
+//! Source implementation for JSON Lines files, one JSON object per line.
+//!
+//! Nested objects are flattened into columns named by the path of their fields joined by `.`,
+//! e.g. `{"a": {"b": 1}}` gives the column `a.b`.
+
+mod errors;
+mod typesystem;
+
+pub use self::errors::JSONLSourceError;
+pub use self::typesystem::JSONLTypeSystem;
+use super::{PartitionParser, Produce, Source, SourcePartition};
+use crate::{data_order::DataOrder, errors::ConnectorXError, sql::CXQuery};
+use anyhow::anyhow;
+use fehler::{throw, throws};
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+
+pub struct JSONLSource {
+    infer_sample_size: Option<usize>,
+    partition_num: usize,
+    files: Vec<CXQuery<String>>,
+    names: Vec<String>,
+    schema: Vec<JSONLTypeSystem>,
+}
+
+impl JSONLSource {
+    /// `infer_sample_size` is the number of lines inspected to infer the schema,
+    /// the whole file is scanned if `None`.
+    pub fn new(infer_sample_size: Option<usize>) -> Self {
+        Self::new_with_partitions(infer_sample_size, 1)
+    }
+
+    /// Split each file into `partition_num` byte ranges which are parsed in parallel.
+    pub fn new_with_partitions(infer_sample_size: Option<usize>, partition_num: usize) -> Self {
+        Self {
+            infer_sample_size,
+            partition_num: partition_num.max(1),
+            files: vec![],
+            names: vec![],
+            schema: vec![],
+        }
+    }
+
+    /// Infer the columns from the first file, in the order they are first met.
+    #[throws(JSONLSourceError)]
+    fn infer_schema(&mut self) {
+        let reader = BufReader::new(File::open(self.files[0].as_str())?);
+        let max_records_to_read = self.infer_sample_size.unwrap_or(usize::MAX);
+
+        let mut names: Vec<String> = vec![];
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut column_types: Vec<HashSet<JSONLTypeSystem>> = vec![];
+        let mut nulls: Vec<bool> = vec![];
+        let mut nrecords = 0;
+
+        for line in reader.lines() {
+            if nrecords >= max_records_to_read {
+                break;
+            }
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut present = vec![false; names.len()];
+            for (name, value) in flatten(parse_line(&line)?) {
+                let idx = *index.entry(name.clone()).or_insert_with(|| {
+                    names.push(name);
+                    column_types.push(HashSet::new());
+                    // the column is missing from the previous records
+                    nulls.push(nrecords > 0);
+                    present.push(false);
+                    names.len() - 1
+                });
+                present[idx] = true;
+                match value {
+                    Value::Null => nulls[idx] = true,
+                    Value::Bool(_) => {
+                        column_types[idx].insert(JSONLTypeSystem::Bool(false));
+                    }
+                    Value::Number(n) if n.is_i64() => {
+                        column_types[idx].insert(JSONLTypeSystem::I64(false));
+                    }
+                    Value::Number(_) => {
+                        column_types[idx].insert(JSONLTypeSystem::F64(false));
+                    }
+                    _ => {
+                        column_types[idx].insert(JSONLTypeSystem::String(false));
+                    }
+                }
+            }
+            for (idx, p) in present.into_iter().enumerate() {
+                if !p {
+                    nulls[idx] = true;
+                }
+            }
+            nrecords += 1;
+        }
+
+        self.schema = column_types
+            .iter()
+            .zip(nulls)
+            .map(|(possibilities, has_nulls)| {
+                let only = |candidates: &[JSONLTypeSystem]| {
+                    possibilities.iter().all(|dt| candidates.contains(dt))
+                };
+                if possibilities.len() == 1 {
+                    match possibilities.iter().next().unwrap() {
+                        JSONLTypeSystem::Bool(_) => JSONLTypeSystem::Bool(has_nulls),
+                        JSONLTypeSystem::I64(_) => JSONLTypeSystem::I64(has_nulls),
+                        JSONLTypeSystem::F64(_) => JSONLTypeSystem::F64(has_nulls),
+                        JSONLTypeSystem::String(_) => JSONLTypeSystem::String(has_nulls),
+                    }
+                } else if !possibilities.is_empty()
+                    && only(&[JSONLTypeSystem::I64(false), JSONLTypeSystem::F64(false)])
+                {
+                    // Integer && Float -> Float
+                    JSONLTypeSystem::F64(has_nulls)
+                } else {
+                    // only nulls or conflicting types -> String
+                    JSONLTypeSystem::String(has_nulls)
+                }
+            })
+            .collect();
+        self.names = names;
+    }
+
+    /// Split a file into at most `partition_num` byte ranges of similar size, aligned to lines.
+    #[throws(JSONLSourceError)]
+    fn split_file(&self, fname: &str) -> Vec<(u64, u64)> {
+        let size = File::open(fname)?.metadata()?.len();
+        let mut bounds = vec![0];
+        for i in 1..self.partition_num as u64 {
+            let target = size * i / self.partition_num as u64;
+            if target <= *bounds.last().unwrap() {
+                continue;
+            }
+            // start from the previous byte to keep `target` if it is already the start of a line,
+            // line breaks cannot appear inside a JSON value of the line
+            let mut file = BufReader::new(File::open(fname)?);
+            file.seek(SeekFrom::Start(target - 1))?;
+            let n = file.read_until(b'\n', &mut vec![])?;
+            let pos = target - 1 + n as u64;
+            if pos >= size {
+                break;
+            }
+            bounds.push(pos);
+        }
+        bounds.push(size);
+        bounds.dedup();
+        bounds.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+}
+
+#[throws(JSONLSourceError)]
+fn parse_line(line: &str) -> Map<String, Value> {
+    match serde_json::from_str(line)? {
+        Value::Object(obj) => obj,
+        v => throw!(anyhow!("JSON Lines record is not an object: {}", v)),
+    }
+}
+
+/// Flatten nested objects into `(path, value)` pairs.
+fn flatten(obj: Map<String, Value>) -> Vec<(String, Value)> {
+    fn flatten_into(prefix: Option<&str>, obj: Map<String, Value>, out: &mut Vec<(String, Value)>) {
+        for (key, value) in obj {
+            let name = match prefix {
+                Some(prefix) => format!("{}.{}", prefix, key),
+                None => key,
+            };
+            match value {
+                Value::Object(nested) => flatten_into(Some(&name), nested, out),
+                v => out.push((name, v)),
+            }
+        }
+    }
+
+    let mut out = vec![];
+    flatten_into(None, obj, &mut out);
+    out
+}
+
+impl Source for JSONLSource {
+    const DATA_ORDERS: &'static [DataOrder] = &[DataOrder::RowMajor];
+    type Partition = JSONLSourcePartition;
+    type TypeSystem = JSONLTypeSystem;
+    type Error = JSONLSourceError;
+
+    #[throws(JSONLSourceError)]
+    fn set_data_order(&mut self, data_order: DataOrder) {
+        if !matches!(data_order, DataOrder::RowMajor) {
+            throw!(ConnectorXError::UnsupportedDataOrder(data_order))
+        }
+    }
+
+    fn set_queries<Q: ToString>(&mut self, queries: &[CXQuery<Q>]) {
+        self.files = queries.iter().map(|q| q.map(Q::to_string)).collect();
+    }
+
+    fn set_origin_query(&mut self, _query: Option<String>) {}
+
+    #[throws(JSONLSourceError)]
+    fn fetch_metadata(&mut self) {
+        assert!(!self.files.is_empty());
+        self.infer_schema()?;
+    }
+
+    #[throws(JSONLSourceError)]
+    fn result_rows(&mut self) -> Option<usize> {
+        None
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    fn schema(&self) -> Vec<Self::TypeSystem> {
+        self.schema.clone()
+    }
+
+    #[throws(JSONLSourceError)]
+    fn partition(self) -> Vec<Self::Partition> {
+        let index: HashMap<String, usize> = self
+            .names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.clone(), i))
+            .collect();
+
+        let mut partitions = vec![];
+        for file in &self.files {
+            let ranges = if self.partition_num > 1 {
+                self.split_file(file.as_str())?
+                    .into_iter()
+                    .map(Some)
+                    .collect()
+            } else {
+                vec![None]
+            };
+            for range in ranges {
+                partitions.push(JSONLSourcePartition::new(
+                    file.clone(),
+                    range,
+                    index.clone(),
+                ));
+            }
+        }
+        partitions
+    }
+}
+
+pub struct JSONLSourcePartition {
+    fname: CXQuery<String>,
+    range: Option<(u64, u64)>,
+    index: HashMap<String, usize>,
+    rows: Option<Vec<Vec<Value>>>,
+    counter: usize,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl JSONLSourcePartition {
+    /// `range` is the byte range of the lines to read, the whole file is read if `None`.
+    /// `index` maps the flattened field names to the column positions.
+    pub fn new(
+        fname: CXQuery<String>,
+        range: Option<(u64, u64)>,
+        index: HashMap<String, usize>,
+    ) -> Self {
+        let ncols = index.len();
+        Self {
+            fname,
+            range,
+            index,
+            rows: None,
+            counter: 0,
+            nrows: 0,
+            ncols,
+        }
+    }
+
+    /// Read the lines of the partition, which is done lazily so that partitions load in parallel.
+    /// Fields that are not part of the schema are ignored and missing fields are null.
+    #[throws(JSONLSourceError)]
+    fn load(&mut self) {
+        if self.rows.is_none() {
+            let mut file = File::open(self.fname.as_str())?;
+            let reader: Box<dyn Read> = match self.range {
+                Some((start, end)) => {
+                    file.seek(SeekFrom::Start(start))?;
+                    Box::new(file.take(end - start))
+                }
+                None => Box::new(file),
+            };
+
+            let mut rows = vec![];
+            for line in BufReader::new(reader).lines() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let mut row = vec![Value::Null; self.ncols];
+                for (name, value) in flatten(parse_line(&line)?) {
+                    if let Some(&idx) = self.index.get(&name) {
+                        row[idx] = value;
+                    }
+                }
+                rows.push(row);
+            }
+
+            self.nrows = rows.len();
+            self.rows = Some(rows);
+        }
+    }
+}
+
+impl SourcePartition for JSONLSourcePartition {
+    type TypeSystem = JSONLTypeSystem;
+    type Parser<'a> = JSONLSourcePartitionParser<'a>;
+    type Error = JSONLSourceError;
+
+    #[throws(JSONLSourceError)]
+    fn result_rows(&mut self) {
+        self.load()?;
+    }
+
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[throws(JSONLSourceError)]
+    fn parser(&mut self) -> Self::Parser<'_> {
+        self.load()?;
+        JSONLSourcePartitionParser {
+            rows: self.rows.as_deref_mut().unwrap_or_default(),
+            counter: &mut self.counter,
+            ncols: self.ncols,
+        }
+    }
+}
+
+pub struct JSONLSourcePartitionParser<'a> {
+    rows: &'a mut [Vec<Value>],
+    counter: &'a mut usize,
+    ncols: usize,
+}
+
+impl<'a> JSONLSourcePartitionParser<'a> {
+    fn next_val(&mut self) -> Value {
+        let v =
+            std::mem::take(&mut self.rows[*self.counter / self.ncols][*self.counter % self.ncols]);
+        *self.counter += 1;
+
+        v
+    }
+
+    /// Convert the next value, which must not be null.
+    #[throws(JSONLSourceError)]
+    fn convert_next<T>(&mut self, conv: fn(&Value) -> Option<T>) -> T {
+        let v = self.next_val();
+        conv(&v).ok_or_else(|| ConnectorXError::cannot_produce::<T>(Some(v.to_string())))?
+    }
+
+    /// Convert the next value, null values are `None`.
+    #[throws(JSONLSourceError)]
+    fn convert_next_opt<T>(&mut self, conv: fn(&Value) -> Option<T>) -> Option<T> {
+        let v = self.next_val();
+        if v.is_null() {
+            return None;
+        }
+        let v = conv(&v)
+            .ok_or_else(|| ConnectorXError::cannot_produce::<Option<T>>(Some(v.to_string())))?;
+
+        Some(v)
+    }
+}
+
+impl<'a> PartitionParser<'a> for JSONLSourcePartitionParser<'a> {
+    type TypeSystem = JSONLTypeSystem;
+    type Error = JSONLSourceError;
+
+    #[throws(JSONLSourceError)]
+    fn fetch_next(&mut self) -> (usize, bool) {
+        (self.rows.len(), true)
+    }
+}
+
+macro_rules! impl_produce {
+    ($($t: ty => $conv: expr,)+) => {
+        $(
+            impl<'r, 'a> Produce<'r, $t> for JSONLSourcePartitionParser<'a> {
+                type Error = JSONLSourceError;
+
+                #[throws(JSONLSourceError)]
+                fn produce(&'r mut self) -> $t {
+                    self.convert_next($conv)?
+                }
+            }
+
+            impl<'r, 'a> Produce<'r, Option<$t>> for JSONLSourcePartitionParser<'a> {
+                type Error = JSONLSourceError;
+
+                #[throws(JSONLSourceError)]
+                fn produce(&'r mut self) -> Option<$t> {
+                    self.convert_next_opt($conv)?
+                }
+            }
+        )+
+    };
+}
+
+impl_produce!(
+    bool => Value::as_bool,
+    i64 => Value::as_i64,
+    f64 => Value::as_f64,
+    // arrays and values of other types are kept as JSON text
+    String => |v| match v {
+        Value::String(s) => Some(s.clone()),
+        v => Some(v.to_string()),
+    },
+);
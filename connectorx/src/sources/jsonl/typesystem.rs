@@ -0,0 +1,19 @@
+/// Types inferred from the values of a JSON Lines file.
+/// Arrays and values of conflicting types are kept as their JSON text in `String` columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JSONLTypeSystem {
+    Bool(bool),
+    I64(bool),
+    F64(bool),
+    String(bool),
+}
+
+impl_typesystem! {
+    system = JSONLTypeSystem,
+    mappings = {
+        { Bool => bool }
+        { I64 => i64 }
+        { F64 => f64 }
+        { String => String }
+    }
+}
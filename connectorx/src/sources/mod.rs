@@ -1,18 +1,28 @@
 //! This module defines four traits [`Source`], [`SourcePartition`], [`PartitionParser`], and [`Produce`]  to define a source.
 //! This module also contains source implementations for various databases.
 
+#[cfg(feature = "src_arrow_ipc")]
+pub mod arrow_ipc;
 #[cfg(feature = "src_bigquery")]
 pub mod bigquery;
+#[cfg(feature = "src_clickhouse")]
+pub mod clickhouse;
 #[cfg(feature = "src_csv")]
 pub mod csv;
+#[cfg(feature = "src_duckdb")]
+pub mod duckdb;
 #[cfg(feature = "src_dummy")]
 pub mod dummy;
+#[cfg(feature = "src_jsonl")]
+pub mod jsonl;
 #[cfg(feature = "src_mssql")]
 pub mod mssql;
 #[cfg(feature = "src_mysql")]
 pub mod mysql;
 #[cfg(feature = "src_oracle")]
 pub mod oracle;
+#[cfg(feature = "src_parquet")]
+pub mod parquet;
 #[cfg(feature = "src_postgres")]
 pub mod postgres;
 #[cfg(feature = "src_sqlite")]
@@ -20,12 +30,55 @@ pub mod sqlite;
 #[cfg(feature = "src_trino")]
 pub mod trino;
 
+use crate::constants::{
+    COLUMN_NULLABLE_KEY, COLUMN_PRECISION_KEY, COLUMN_SCALE_KEY, COLUMN_TABLE_KEY,
+    COLUMN_TYPE_NAME_KEY,
+};
 use crate::data_order::DataOrder;
 use crate::errors::ConnectorXError;
 use crate::sql::CXQuery;
 use crate::typesystem::{TypeAssoc, TypeSystem};
+use serde_json::Value;
 use std::fmt::Debug;
 
+/// A result column as described by the source database, `None` for what it does not report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnDescriptor {
+    /// Name of the type in the database, e.g. `numeric` or `Nullable(String)`.
+    pub type_name: Option<String>,
+    /// Precision of decimals, length of strings or fractional second digits of timestamps.
+    pub precision: Option<u32>,
+    /// Scale of decimals.
+    pub scale: Option<i32>,
+    /// Whether the column accepts nulls.
+    pub nullable: Option<bool>,
+    /// Table the column is read from.
+    pub table: Option<String>,
+}
+
+impl ColumnDescriptor {
+    /// The known parts of the descriptor as key-value pairs, e.g. for field metadata.
+    pub fn metadata(&self) -> Vec<(String, String)> {
+        let mut metadata = vec![];
+        if let Some(type_name) = &self.type_name {
+            metadata.push((COLUMN_TYPE_NAME_KEY.to_string(), type_name.clone()));
+        }
+        if let Some(precision) = self.precision {
+            metadata.push((COLUMN_PRECISION_KEY.to_string(), precision.to_string()));
+        }
+        if let Some(scale) = self.scale {
+            metadata.push((COLUMN_SCALE_KEY.to_string(), scale.to_string()));
+        }
+        if let Some(nullable) = self.nullable {
+            metadata.push((COLUMN_NULLABLE_KEY.to_string(), nullable.to_string()));
+        }
+        if let Some(table) = &self.table {
+            metadata.push((COLUMN_TABLE_KEY.to_string(), table.clone()));
+        }
+        metadata
+    }
+}
+
 pub trait Source {
     /// Supported data orders, ordering by preference.
     const DATA_ORDERS: &'static [DataOrder];
@@ -44,6 +97,31 @@ pub trait Source {
     fn fetch_metadata(&mut self) -> Result<(), Self::Error>;
     /// Get total number of rows if available
     fn result_rows(&mut self) -> Result<Option<usize>, Self::Error>;
+    /// Get the precision and scale of each column holding decimals if available, `None` for
+    /// the other columns and the decimal columns without a fixed precision.
+    fn decimal_precision_scale(&mut self) -> Result<Vec<Option<(u8, i8)>>, Self::Error> {
+        Ok(vec![])
+    }
+    /// Get up to `limit` non-null values of each JSON column of the first query, `None` for
+    /// the other columns.
+    fn json_samples(&mut self, _limit: usize) -> Result<Vec<Option<Vec<Value>>>, Self::Error> {
+        Ok(vec![])
+    }
+    /// Get the number of fractional second digits of each timestamp column if available,
+    /// `None` for the other columns.
+    fn timestamp_precisions(&mut self) -> Result<Vec<Option<u8>>, Self::Error> {
+        Ok(vec![])
+    }
+    /// Get the time zone of each time zone aware timestamp column if available, e.g. the
+    /// session time zone, `None` for the other columns including timestamps without time zone.
+    fn timezones(&mut self) -> Result<Vec<Option<String>>, Self::Error> {
+        Ok(vec![])
+    }
+    /// Get the descriptor of each column as reported by the database if available. Only the
+    /// Postgres, MySQL and ClickHouse sources implement it, the others return no descriptors.
+    fn column_descriptors(&mut self) -> Result<Vec<ColumnDescriptor>, Self::Error> {
+        Ok(vec![])
+    }
 
     fn names(&self) -> Vec<String>;
 
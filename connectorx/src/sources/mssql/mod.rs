@@ -211,6 +211,39 @@ where
         }
     }
 
+    #[throws(MsSQLSourceError)]
+    fn decimal_precision_scale(&mut self) -> Vec<Option<(u8, i8)>> {
+        let is_decimal = |ty: &MsSQLTypeSystem| {
+            matches!(
+                ty,
+                MsSQLTypeSystem::Numeric(_) | MsSQLTypeSystem::Decimal(_)
+            )
+        };
+        if !self.schema.iter().any(is_decimal) {
+            return vec![];
+        }
+
+        // the driver does not expose them, but the server describes the result of a query
+        let mut conn = self.rt.block_on(self.pool.get())?;
+        let query = self.queries[0].as_str();
+        let stream = self
+            .rt
+            .block_on(conn.query("EXEC sp_describe_first_result_set @tsql = @P1", &[&query]))?;
+        let rows = self.rt.block_on(stream.into_first_result())?;
+        rows.iter()
+            .filter(|row| !matches!(row.try_get::<bool, _>("is_hidden"), Ok(Some(true))))
+            .zip(&self.schema)
+            .map(|(row, ty)| -> Result<_, MsSQLSourceError> {
+                if !is_decimal(ty) {
+                    return Ok(None);
+                }
+                let precision = row.try_get::<u8, _>("precision")?;
+                let scale = row.try_get::<u8, _>("scale")?;
+                Ok(precision.zip(scale).map(|(p, s)| (p, s as i8)))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    }
+
     fn names(&self) -> Vec<String> {
         self.names.clone()
     }
@@ -8,7 +8,7 @@ use crate::constants::DB_BUFFER_SIZE;
 use crate::{
     data_order::DataOrder,
     errors::ConnectorXError,
-    sources::{PartitionParser, Produce, Source, SourcePartition},
+    sources::{ColumnDescriptor, PartitionParser, Produce, Source, SourcePartition},
     sql::{count_query, limit1_query, CXQuery},
 };
 use anyhow::anyhow;
@@ -23,13 +23,14 @@ use r2d2_mysql::{
             UTF8_GENERAL_CI,
         },
         prelude::Queryable,
-        Binary, Opts, OptsBuilder, QueryResult, Row, Text,
+        Binary, Column, Opts, OptsBuilder, QueryResult, Row, Text,
     },
     MySqlConnectionManager,
 };
 use rust_decimal::Decimal;
 use serde_json::Value;
 use sqlparser::dialect::MySqlDialect;
+use std::convert::TryFrom;
 use std::marker::PhantomData;
 pub use typesystem::MySQLTypeSystem;
 
@@ -50,6 +51,8 @@ pub struct MySQLSource<P> {
     queries: Vec<CXQuery<String>>,
     names: Vec<String>,
     schema: Vec<MySQLTypeSystem>,
+    decimals: Vec<Option<(u8, i8)>>,
+    descriptors: Vec<ColumnDescriptor>,
     _protocol: PhantomData<P>,
 }
 
@@ -67,11 +70,54 @@ impl<P> MySQLSource<P> {
             queries: vec![],
             names: vec![],
             schema: vec![],
+            decimals: vec![],
+            descriptors: vec![],
             _protocol: PhantomData,
         }
     }
 }
 
+/// Precision and scale of a `DECIMAL` column. The display length of the column counts the
+/// sign and the decimal point besides the digits.
+fn decimal_precision_scale(col: &Column) -> Option<(u8, i8)> {
+    match col.column_type() {
+        MySQLColumnType::MYSQL_TYPE_DECIMAL | MySQLColumnType::MYSQL_TYPE_NEWDECIMAL => {
+            let scale = col.decimals();
+            let unsigned = col.flags().contains(MySQLColumnFlags::UNSIGNED_FLAG);
+            let precision = col
+                .column_length()
+                .checked_sub(u32::from(scale > 0) + u32::from(!unsigned))?;
+            Some((u8::try_from(precision).ok()?, i8::try_from(scale).ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// Descriptor of a result column as sent by the server, e.g. type `newdecimal` for `DECIMAL`.
+fn column_descriptor(col: &Column) -> ColumnDescriptor {
+    let (precision, scale) = match col.column_type() {
+        MySQLColumnType::MYSQL_TYPE_DATETIME
+        | MySQLColumnType::MYSQL_TYPE_DATETIME2
+        | MySQLColumnType::MYSQL_TYPE_TIMESTAMP
+        | MySQLColumnType::MYSQL_TYPE_TIMESTAMP2
+        | MySQLColumnType::MYSQL_TYPE_TIME
+        | MySQLColumnType::MYSQL_TYPE_TIME2 => (Some(col.decimals().into()), None),
+        _ => match decimal_precision_scale(col) {
+            Some((precision, scale)) => (Some(precision.into()), Some(scale.into())),
+            None => (None, None),
+        },
+    };
+    let type_name = format!("{:?}", col.column_type());
+    let table = col.org_table_str();
+    ColumnDescriptor {
+        type_name: Some(type_name.trim_start_matches("MYSQL_TYPE_").to_lowercase()),
+        precision,
+        scale,
+        nullable: Some(!col.flags().contains(MySQLColumnFlags::NOT_NULL_FLAG)),
+        table: (!table.is_empty()).then(|| table.into_owned()),
+    }
+}
+
 impl<P> Source for MySQLSource<P>
 where
     MySQLSourcePartition<P>:
@@ -139,6 +185,8 @@ where
                     .unzip();
                 self.names = names;
                 self.schema = types;
+                self.decimals = stmt.columns().iter().map(decimal_precision_scale).collect();
+                self.descriptors = stmt.columns().iter().map(column_descriptor).collect();
             }
             Err(e) => {
                 warn!(
@@ -163,6 +211,13 @@ where
                                 .unzip();
                             self.names = names;
                             self.schema = types;
+                            self.decimals = row
+                                .columns_ref()
+                                .iter()
+                                .map(decimal_precision_scale)
+                                .collect();
+                            self.descriptors =
+                                row.columns_ref().iter().map(column_descriptor).collect();
                             return;
                         }
                         Ok(None) => {}
@@ -207,6 +262,16 @@ where
         }
     }
 
+    #[throws(MySQLSourceError)]
+    fn decimal_precision_scale(&mut self) -> Vec<Option<(u8, i8)>> {
+        self.decimals.clone()
+    }
+
+    #[throws(MySQLSourceError)]
+    fn column_descriptors(&mut self) -> Vec<ColumnDescriptor> {
+        self.descriptors.clone()
+    }
+
     fn names(&self) -> Vec<String> {
         self.names.clone()
     }
@@ -20,10 +20,12 @@ use owning_ref::OwningHandle;
 use r2d2::{Pool, PooledConnection};
 use r2d2_oracle::oracle::ResultSet;
 use r2d2_oracle::{
-    oracle::{Connector, Row, Statement},
+    oracle::{sql_type::OracleType, Connector, Row, Statement},
     OracleConnectionManager,
 };
+use rust_decimal::Decimal;
 use sqlparser::dialect::Dialect;
+use std::str::FromStr;
 use url::Url;
 use urlencoding::decode;
 
@@ -44,12 +46,33 @@ impl Dialect for OracleDialect {
     }
 }
 
+/// Precision and scale of a `NUMBER(p, s)` column with a positive scale.
+fn decimal_precision_scale(ty: &OracleType) -> Option<(u8, i8)> {
+    match ty {
+        OracleType::Number(precision, scale) if *precision > 0 && *scale > 0 => {
+            Some((*precision, *scale))
+        }
+        _ => None,
+    }
+}
+
+/// Number of fractional second digits of a date or timestamp column.
+fn timestamp_precision(ty: &OracleType) -> Option<u8> {
+    match ty {
+        OracleType::Date => Some(0),
+        OracleType::Timestamp(precision) | OracleType::TimestampTZ(precision) => Some(*precision),
+        _ => None,
+    }
+}
+
 pub struct OracleSource {
     pool: Pool<OracleManager>,
     origin_query: Option<String>,
     queries: Vec<CXQuery<String>>,
     names: Vec<String>,
     schema: Vec<OracleTypeSystem>,
+    decimal_precision_scale: Vec<Option<(u8, i8)>>,
+    timestamp_precisions: Vec<Option<u8>>,
 }
 
 #[throws(OracleSourceError)]
@@ -92,6 +115,8 @@ impl OracleSource {
             queries: vec![],
             names: vec![],
             schema: vec![],
+            decimal_precision_scale: vec![],
+            timestamp_precisions: vec![],
         }
     }
 }
@@ -133,6 +158,16 @@ where
             // -> (NumInt, Char) instead of (NumtFloat, Char)
             match conn.query(limit1_query_oracle(query)?.as_str(), &[]) {
                 Ok(rows) => {
+                    self.decimal_precision_scale = rows
+                        .column_info()
+                        .iter()
+                        .map(|col| decimal_precision_scale(col.oracle_type()))
+                        .collect();
+                    self.timestamp_precisions = rows
+                        .column_info()
+                        .iter()
+                        .map(|col| timestamp_precision(col.oracle_type()))
+                        .collect();
                     let (names, types) = rows
                         .column_info()
                         .iter()
@@ -181,6 +216,36 @@ where
         }
     }
 
+    #[throws(OracleSourceError)]
+    fn decimal_precision_scale(&mut self) -> Vec<Option<(u8, i8)>> {
+        self.decimal_precision_scale.clone()
+    }
+
+    #[throws(OracleSourceError)]
+    fn timestamp_precisions(&mut self) -> Vec<Option<u8>> {
+        self.timestamp_precisions.clone()
+    }
+
+    #[throws(OracleSourceError)]
+    fn timezones(&mut self) -> Vec<Option<String>> {
+        let is_tz = |ty: &OracleTypeSystem| {
+            matches!(
+                ty,
+                OracleTypeSystem::TimestampTz(_) | OracleTypeSystem::TimestampTzNano(_)
+            )
+        };
+        if !self.schema.iter().any(is_tz) {
+            return vec![];
+        }
+
+        let conn = self.pool.get()?;
+        let timezone = conn.query_row_as::<String>("SELECT SESSIONTIMEZONE FROM DUAL", &[])?;
+        self.schema
+            .iter()
+            .map(|ty| is_tz(ty).then(|| timezone.clone()))
+            .collect()
+    }
+
     fn names(&self) -> Vec<String> {
         self.names.clone()
     }
@@ -360,3 +425,32 @@ impl_produce_text!(
     DateTime<Utc>,
     Vec<u8>,
 );
+
+impl<'r, 'a> Produce<'r, Decimal> for OracleTextSourceParser<'a> {
+    type Error = OracleSourceError;
+
+    #[throws(OracleSourceError)]
+    fn produce(&'r mut self) -> Decimal {
+        let (ridx, cidx) = self.next_loc()?;
+        let v: String = self.rowbuf[ridx].get(cidx)?;
+        Decimal::from_str(&v)
+            .map_err(|_| ConnectorXError::cannot_produce::<Decimal>(Some(v)))?
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<Decimal>> for OracleTextSourceParser<'a> {
+    type Error = OracleSourceError;
+
+    #[throws(OracleSourceError)]
+    fn produce(&'r mut self) -> Option<Decimal> {
+        let (ridx, cidx) = self.next_loc()?;
+        let v: Option<String> = self.rowbuf[ridx].get(cidx)?;
+        match v {
+            Some(v) => Some(
+                Decimal::from_str(&v)
+                    .map_err(|_| ConnectorXError::cannot_produce::<Decimal>(Some(v)))?,
+            ),
+            None => None,
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
 use r2d2_oracle::oracle::sql_type::OracleType;
+use rust_decimal::Decimal;
 
 #[derive(Copy, Clone, Debug)]
 pub enum OracleTypeSystem {
     NumInt(bool),
     Float(bool),
     NumFloat(bool),
+    NumDecimal(bool),
     BinaryFloat(bool),
     BinaryDouble(bool),
     Blob(bool),
@@ -26,6 +28,7 @@ impl_typesystem! {
     mappings = {
         { NumInt => i64 }
         { Float | NumFloat | BinaryFloat | BinaryDouble => f64 }
+        { NumDecimal => Decimal }
         { Blob => Vec<u8>}
         { Clob | VarChar | Char | NVarChar | NChar => String }
         { Date | Timestamp | TimestampNano => NaiveDateTime }
@@ -39,6 +42,7 @@ impl<'a> From<&'a OracleType> for OracleTypeSystem {
         match ty {
             OracleType::Number(0, 0) => NumFloat(true),
             OracleType::Number(_, 0) => NumInt(true),
+            OracleType::Number(_, scale) if *scale > 0 => NumDecimal(true),
             OracleType::Number(_, _) => NumFloat(true),
             OracleType::Float(_) => Float(true),
             OracleType::BinaryFloat => BinaryFloat(true),
@@ -0,0 +1,489 @@
+//! Source implementation for Parquet files.
+
+mod errors;
+mod typesystem;
+
+pub use self::errors::ParquetSourceError;
+pub use self::typesystem::ParquetTypeSystem;
+use super::{PartitionParser, Produce, Source, SourcePartition};
+use crate::{
+    constants::DB_BUFFER_SIZE, data_order::DataOrder, errors::ConnectorXError, sql::CXQuery,
+};
+use anyhow::anyhow;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use fehler::{throw, throws};
+use parquet::{
+    file::{
+        reader::{FileReader, SerializedFileReader},
+        serialized_reader::ReadOptionsBuilder,
+    },
+    record::{reader::RowIter, Field},
+    schema::types::Type,
+};
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+use std::fs::File;
+
+/// Read Parquet files, each query being the path of a file.
+/// A file becomes one partition, or one partition per row group if `split_row_groups` is set.
+pub struct ParquetSource {
+    projection: Option<Vec<String>>,
+    split_row_groups: bool,
+    files: Vec<CXQuery<String>>,
+    names: Vec<String>,
+    schema: Vec<ParquetTypeSystem>,
+}
+
+impl ParquetSource {
+    /// `projection` is the list of columns to decode, all the columns of the file are read if `None`.
+    pub fn new(projection: Option<&[&str]>, split_row_groups: bool) -> Self {
+        Self {
+            projection: projection.map(|cols| cols.iter().map(|c| c.to_string()).collect()),
+            split_row_groups,
+            files: vec![],
+            names: vec![],
+            schema: vec![],
+        }
+    }
+
+    /// Build the schema passed to the row reader so that only the selected columns are decoded.
+    #[throws(ParquetSourceError)]
+    fn projected_schema(&self, reader: &SerializedFileReader<File>) -> Option<Type> {
+        match &self.projection {
+            None => None,
+            Some(_) => {
+                let root = reader.metadata().file_metadata().schema();
+                let fields = self
+                    .names
+                    .iter()
+                    .map(|name| {
+                        root.get_fields()
+                            .iter()
+                            .find(|f| f.name() == name)
+                            .cloned()
+                            .ok_or_else(|| anyhow!("column {} does not exist", name))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Some(
+                    Type::group_type_builder(root.name())
+                        .with_fields(fields)
+                        .build()?,
+                )
+            }
+        }
+    }
+}
+
+impl Source for ParquetSource {
+    const DATA_ORDERS: &'static [DataOrder] = &[DataOrder::RowMajor];
+    type Partition = ParquetSourcePartition;
+    type TypeSystem = ParquetTypeSystem;
+    type Error = ParquetSourceError;
+
+    #[throws(ParquetSourceError)]
+    fn set_data_order(&mut self, data_order: DataOrder) {
+        if !matches!(data_order, DataOrder::RowMajor) {
+            throw!(ConnectorXError::UnsupportedDataOrder(data_order))
+        }
+    }
+
+    fn set_queries<Q: ToString>(&mut self, queries: &[CXQuery<Q>]) {
+        self.files = queries.iter().map(|q| q.map(Q::to_string)).collect();
+    }
+
+    fn set_origin_query(&mut self, _query: Option<String>) {}
+
+    #[throws(ParquetSourceError)]
+    fn fetch_metadata(&mut self) {
+        assert!(!self.files.is_empty());
+        let reader = SerializedFileReader::new(File::open(self.files[0].as_str())?)?;
+        let descr = reader.metadata().file_metadata().schema_descr_ptr();
+
+        // the rows are read by top-level field, match the leaves by their root field so that
+        // a nested leaf is never taken for a top-level column (and is rejected below)
+        let columns: Vec<_> = match &self.projection {
+            None => descr.columns().to_vec(),
+            Some(cols) => cols
+                .iter()
+                .map(|name| {
+                    descr
+                        .columns()
+                        .iter()
+                        .find(|c| &c.path().parts()[0] == name)
+                        .cloned()
+                        .ok_or_else(|| anyhow!("column {} does not exist", name))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+        };
+
+        self.names = columns.iter().map(|c| c.name().to_string()).collect();
+        self.schema = columns
+            .iter()
+            .map(|c| ParquetTypeSystem::try_from(c.as_ref()))
+            .collect::<Result<Vec<_>, _>>()?;
+    }
+
+    #[throws(ParquetSourceError)]
+    fn result_rows(&mut self) -> Option<usize> {
+        let mut nrows = 0;
+        for file in &self.files {
+            let reader = SerializedFileReader::new(File::open(file.as_str())?)?;
+            nrows += reader.metadata().file_metadata().num_rows() as usize;
+        }
+        Some(nrows)
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.names.clone()
+    }
+
+    fn schema(&self) -> Vec<Self::TypeSystem> {
+        self.schema.clone()
+    }
+
+    #[throws(ParquetSourceError)]
+    fn partition(self) -> Vec<Self::Partition> {
+        let mut partitions = vec![];
+        for file in &self.files {
+            let reader = SerializedFileReader::new(File::open(file.as_str())?)?;
+            let projection = self.projected_schema(&reader)?;
+            let metadata = reader.metadata();
+
+            if self.split_row_groups {
+                for (i, rg) in metadata.row_groups().iter().enumerate() {
+                    partitions.push(ParquetSourcePartition::new(
+                        file.clone(),
+                        Some(i),
+                        projection.clone(),
+                        rg.num_rows() as usize,
+                        self.schema.len(),
+                    ));
+                }
+            } else {
+                partitions.push(ParquetSourcePartition::new(
+                    file.clone(),
+                    None,
+                    projection,
+                    metadata.file_metadata().num_rows() as usize,
+                    self.schema.len(),
+                ));
+            }
+        }
+        partitions
+    }
+}
+
+pub struct ParquetSourcePartition {
+    file: CXQuery<String>,
+    row_group: Option<usize>,
+    projection: Option<Type>,
+    nrows: usize,
+    ncols: usize,
+}
+
+impl ParquetSourcePartition {
+    pub fn new(
+        file: CXQuery<String>,
+        row_group: Option<usize>,
+        projection: Option<Type>,
+        nrows: usize,
+        ncols: usize,
+    ) -> Self {
+        Self {
+            file,
+            row_group,
+            projection,
+            nrows,
+            ncols,
+        }
+    }
+}
+
+impl SourcePartition for ParquetSourcePartition {
+    type TypeSystem = ParquetTypeSystem;
+    type Parser<'a> = ParquetSourcePartitionParser<'a>;
+    type Error = ParquetSourceError;
+
+    /// The number of rows is known from the file footer
+    #[throws(ParquetSourceError)]
+    fn result_rows(&mut self) {}
+
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    #[throws(ParquetSourceError)]
+    fn parser(&mut self) -> Self::Parser<'_> {
+        let file = File::open(self.file.as_str())?;
+        let reader = match self.row_group {
+            Some(idx) => {
+                let options = ReadOptionsBuilder::new()
+                    .with_predicate(Box::new(move |_, i| i == idx))
+                    .build();
+                SerializedFileReader::new_with_options(file, options)?
+            }
+            None => SerializedFileReader::new(file)?,
+        };
+        let reader: Box<dyn FileReader> = Box::new(reader);
+        let iter = RowIter::from_file_into(reader).project(self.projection.clone())?;
+
+        ParquetSourcePartitionParser::new(iter, self.ncols)
+    }
+}
+
+pub struct ParquetSourcePartitionParser<'a> {
+    iter: RowIter<'a>,
+    rowbuf: Vec<Vec<Field>>,
+    ncols: usize,
+    current_row: usize,
+    current_col: usize,
+    is_finished: bool,
+}
+
+impl<'a> ParquetSourcePartitionParser<'a> {
+    pub fn new(iter: RowIter<'a>, ncols: usize) -> Self {
+        Self {
+            iter,
+            rowbuf: Vec::with_capacity(DB_BUFFER_SIZE),
+            ncols,
+            current_row: 0,
+            current_col: 0,
+            is_finished: false,
+        }
+    }
+
+    fn next_val(&mut self) -> Field {
+        let (ridx, cidx) = (self.current_row, self.current_col);
+        self.current_row += (self.current_col + 1) / self.ncols;
+        self.current_col = (self.current_col + 1) % self.ncols;
+        std::mem::replace(&mut self.rowbuf[ridx][cidx], Field::Null)
+    }
+}
+
+impl<'a> PartitionParser<'a> for ParquetSourcePartitionParser<'a> {
+    type TypeSystem = ParquetTypeSystem;
+    type Error = ParquetSourceError;
+
+    #[throws(ParquetSourceError)]
+    fn fetch_next(&mut self) -> (usize, bool) {
+        assert!(self.current_col == 0);
+        let remaining_rows = self.rowbuf.len() - self.current_row;
+        if remaining_rows > 0 {
+            return (remaining_rows, self.is_finished);
+        } else if self.is_finished {
+            return (0, self.is_finished);
+        }
+
+        if !self.rowbuf.is_empty() {
+            self.rowbuf.drain(..);
+        }
+
+        for _ in 0..DB_BUFFER_SIZE {
+            if let Some(row) = self.iter.next() {
+                let row = row?;
+                self.rowbuf
+                    .push(row.get_column_iter().map(|(_, f)| f.clone()).collect());
+            } else {
+                self.is_finished = true;
+                break;
+            }
+        }
+        self.current_row = 0;
+        self.current_col = 0;
+
+        (self.rowbuf.len(), self.is_finished)
+    }
+}
+
+/// Convert a parquet value into the native type of a column,
+/// the physical values are widened to the type chosen by the type system.
+/// The field is handed back if it does not hold a value of the requested type.
+trait FromField: Sized {
+    fn from_field(field: Field) -> Result<Self, Field>;
+}
+
+macro_rules! impl_produce {
+    ($($t: ty,)+) => {
+        $(
+            impl<'r, 'a> Produce<'r, $t> for ParquetSourcePartitionParser<'a> {
+                type Error = ParquetSourceError;
+
+                #[throws(ParquetSourceError)]
+                fn produce(&'r mut self) -> $t {
+                    let field = self.next_val();
+                    match <$t>::from_field(field) {
+                        Ok(v) => v,
+                        Err(f) => throw!(ConnectorXError::cannot_produce::<$t>(Some(f.to_string()))),
+                    }
+                }
+            }
+
+            impl<'r, 'a> Produce<'r, Option<$t>> for ParquetSourcePartitionParser<'a> {
+                type Error = ParquetSourceError;
+
+                #[throws(ParquetSourceError)]
+                fn produce(&'r mut self) -> Option<$t> {
+                    let field = self.next_val();
+                    if matches!(field, Field::Null) {
+                        return None;
+                    }
+                    match <$t>::from_field(field) {
+                        Ok(v) => Some(v),
+                        Err(f) => throw!(ConnectorXError::cannot_produce::<Option<$t>>(Some(f.to_string()))),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_produce!(
+    bool,
+    i32,
+    i64,
+    u64,
+    f32,
+    f64,
+    Decimal,
+    String,
+    Vec<u8>,
+    NaiveDate,
+    NaiveDateTime,
+    DateTime<Utc>,
+);
+
+impl FromField for bool {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Bool(v) => Ok(v),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for i32 {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Byte(v) => Ok(v as i32),
+            Field::Short(v) => Ok(v as i32),
+            Field::Int(v) => Ok(v),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for i64 {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Byte(v) => Ok(v as i64),
+            Field::Short(v) => Ok(v as i64),
+            Field::Int(v) => Ok(v as i64),
+            Field::Long(v) => Ok(v),
+            Field::UByte(v) => Ok(v as i64),
+            Field::UShort(v) => Ok(v as i64),
+            Field::UInt(v) => Ok(v as i64),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for u64 {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::ULong(v) => Ok(v),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for f32 {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Float(v) => Ok(v),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for f64 {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Float(v) => Ok(v as f64),
+            Field::Double(v) => Ok(v),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for Decimal {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        let val = match &field {
+            // the unscaled value is stored as a big-endian two's complement integer
+            Field::Decimal(v) if !v.data().is_empty() && v.data().len() <= 16 => {
+                let data = v.data();
+                let fill = if data[0] & 0x80 != 0 { 0xff } else { 0x00 };
+                let mut bytes = [fill; 16];
+                bytes[16 - data.len()..].copy_from_slice(data);
+                Decimal::try_from_i128_with_scale(i128::from_be_bytes(bytes), v.scale() as u32).ok()
+            }
+            _ => None,
+        };
+        val.ok_or(field)
+    }
+}
+
+impl FromField for String {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Str(v) => Ok(v),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for Vec<u8> {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        match field {
+            Field::Bytes(v) => Ok(v.data().to_vec()),
+            Field::Str(v) => Ok(v.into_bytes()),
+            f => Err(f),
+        }
+    }
+}
+
+impl FromField for NaiveDate {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        let val = match field {
+            Field::Date(v) => NaiveDate::from_ymd_opt(1970, 1, 1)
+                .and_then(|epoch| epoch.checked_add_signed(Duration::days(v as i64))),
+            _ => None,
+        };
+        val.ok_or(field)
+    }
+}
+
+impl FromField for NaiveDateTime {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        DateTime::<Utc>::from_field(field).map(|v| v.naive_utc())
+    }
+}
+
+impl FromField for DateTime<Utc> {
+    fn from_field(field: Field) -> Result<Self, Field> {
+        let val = match field {
+            Field::TimestampMillis(v) => DateTime::from_timestamp_millis(v),
+            Field::TimestampMicros(v) => DateTime::from_timestamp_micros(v),
+            // nanosecond timestamps are not annotated by the row reader
+            Field::Long(v) => DateTime::from_timestamp(
+                v.div_euclid(1_000_000_000),
+                v.rem_euclid(1_000_000_000) as u32,
+            ),
+            _ => None,
+        };
+        val.ok_or(field)
+    }
+}
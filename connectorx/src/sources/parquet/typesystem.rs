@@ -0,0 +1,138 @@
+use super::errors::ParquetSourceError;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use fehler::{throw, throws};
+use parquet::basic::{ConvertedType, LogicalType, Repetition, Type as PhysicalType};
+use parquet::schema::types::ColumnDescriptor;
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ParquetTypeSystem {
+    Boolean(bool),
+    Int32(bool),
+    Int64(bool),
+    UInt64(bool),
+    Float(bool),
+    Double(bool),
+    Decimal(bool),
+    Utf8(bool),
+    Binary(bool),
+    Date(bool),
+    Timestamp(bool),
+    TimestampTz(bool),
+}
+
+impl_typesystem! {
+    system = ParquetTypeSystem,
+    mappings = {
+        { Boolean => bool }
+        { Int32 => i32 }
+        { Int64 => i64 }
+        { UInt64 => u64 }
+        { Float => f32 }
+        { Double => f64 }
+        { Decimal => Decimal }
+        { Utf8 => String }
+        { Binary => Vec<u8> }
+        { Date => NaiveDate }
+        { Timestamp => NaiveDateTime }
+        { TimestampTz => DateTime<Utc> }
+    }
+}
+
+// Derive the type of a leaf column from its physical type and its logical (or legacy converted)
+// annotation. Only flat schemas are supported, repeated and nested (e.g. struct) columns are
+// rejected.
+impl TryFrom<&ColumnDescriptor> for ParquetTypeSystem {
+    type Error = ParquetSourceError;
+
+    #[throws(ParquetSourceError)]
+    fn try_from(col: &ColumnDescriptor) -> Self {
+        use ParquetTypeSystem::*;
+
+        let nullable = match col.self_type().get_basic_info().repetition() {
+            Repetition::REQUIRED => false,
+            Repetition::OPTIONAL => true,
+            Repetition::REPEATED => throw!(ParquetSourceError::UnsupportedType(format!(
+                "repeated column {}",
+                col.path()
+            ))),
+        };
+        if col.max_rep_level() > 0 || col.path().parts().len() > 1 {
+            throw!(ParquetSourceError::UnsupportedType(format!(
+                "nested column {}",
+                col.path()
+            )));
+        }
+
+        match (
+            col.physical_type(),
+            col.logical_type(),
+            col.converted_type(),
+        ) {
+            (PhysicalType::BOOLEAN, _, _) => Boolean(nullable),
+            (_, Some(LogicalType::Decimal { .. }), _) | (_, _, ConvertedType::DECIMAL) => {
+                Decimal(nullable)
+            }
+            (PhysicalType::INT32, Some(LogicalType::Date), _)
+            | (PhysicalType::INT32, _, ConvertedType::DATE) => Date(nullable),
+            (
+                PhysicalType::INT32,
+                Some(LogicalType::Integer {
+                    is_signed: false, ..
+                }),
+                _,
+            )
+            | (PhysicalType::INT32, _, ConvertedType::UINT_8)
+            | (PhysicalType::INT32, _, ConvertedType::UINT_16)
+            | (PhysicalType::INT32, _, ConvertedType::UINT_32) => Int64(nullable),
+            (PhysicalType::INT32, None, ConvertedType::NONE)
+            | (PhysicalType::INT32, Some(LogicalType::Integer { .. }), _)
+            | (PhysicalType::INT32, _, ConvertedType::INT_8)
+            | (PhysicalType::INT32, _, ConvertedType::INT_16)
+            | (PhysicalType::INT32, _, ConvertedType::INT_32) => Int32(nullable),
+            (
+                PhysicalType::INT64,
+                Some(LogicalType::Timestamp {
+                    is_adjusted_to_u_t_c: true,
+                    ..
+                }),
+                _,
+            ) => TimestampTz(nullable),
+            (PhysicalType::INT64, Some(LogicalType::Timestamp { .. }), _)
+            | (PhysicalType::INT64, _, ConvertedType::TIMESTAMP_MILLIS)
+            | (PhysicalType::INT64, _, ConvertedType::TIMESTAMP_MICROS) => Timestamp(nullable),
+            (
+                PhysicalType::INT64,
+                Some(LogicalType::Integer {
+                    is_signed: false, ..
+                }),
+                _,
+            )
+            | (PhysicalType::INT64, _, ConvertedType::UINT_64) => UInt64(nullable),
+            (PhysicalType::INT64, None, ConvertedType::NONE)
+            | (PhysicalType::INT64, Some(LogicalType::Integer { .. }), _)
+            | (PhysicalType::INT64, _, ConvertedType::INT_64) => Int64(nullable),
+            // INT96 is the legacy timestamp representation written by Impala and Spark
+            (PhysicalType::INT96, _, _) => Timestamp(nullable),
+            (PhysicalType::FLOAT, _, _) => Float(nullable),
+            (PhysicalType::DOUBLE, _, _) => Double(nullable),
+            (PhysicalType::BYTE_ARRAY, Some(LogicalType::String), _)
+            | (PhysicalType::BYTE_ARRAY, Some(LogicalType::Enum), _)
+            | (PhysicalType::BYTE_ARRAY, Some(LogicalType::Json), _)
+            | (PhysicalType::BYTE_ARRAY, _, ConvertedType::UTF8)
+            | (PhysicalType::BYTE_ARRAY, _, ConvertedType::ENUM)
+            | (PhysicalType::BYTE_ARRAY, _, ConvertedType::JSON) => Utf8(nullable),
+            (PhysicalType::BYTE_ARRAY, _, _) | (PhysicalType::FIXED_LEN_BYTE_ARRAY, _, _) => {
+                Binary(nullable)
+            }
+            (ty, lty, cty) => throw!(ParquetSourceError::UnsupportedType(format!(
+                "{} ({:?}, {:?}, {})",
+                col.path(),
+                lty,
+                cty,
+                ty
+            ))),
+        }
+    }
+}
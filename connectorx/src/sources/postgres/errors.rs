@@ -2,6 +2,9 @@ use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum PostgresSourceError {
+    #[error("Unsupported Postgres type {0}")]
+    UnsupportedType(String),
+
     #[error(transparent)]
     ConnectorXError(#[from] crate::errors::ConnectorXError),
 
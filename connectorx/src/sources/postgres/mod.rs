@@ -2,17 +2,19 @@
 
 mod connection;
 mod errors;
+mod types;
 mod typesystem;
 
 pub use self::errors::PostgresSourceError;
 pub use connection::rewrite_tls_args;
+pub use types::{PgInterval, PgMoney};
 pub use typesystem::{PostgresTypePairs, PostgresTypeSystem};
 
 use crate::constants::DB_BUFFER_SIZE;
 use crate::{
     data_order::DataOrder,
     errors::ConnectorXError,
-    sources::{PartitionParser, Produce, Source, SourcePartition},
+    sources::{ColumnDescriptor, PartitionParser, Produce, Source, SourcePartition},
     sql::{count_query, CXQuery},
 };
 use anyhow::anyhow;
@@ -24,6 +26,7 @@ use postgres::{
     binary_copy::{BinaryCopyOutIter, BinaryCopyOutRow},
     fallible_iterator::FallibleIterator,
     tls::{MakeTlsConnect, TlsConnect},
+    types::{Oid, Type},
     Config, CopyOutReader, Row, RowIter, SimpleQueryMessage, Socket,
 };
 use r2d2::{Pool, PooledConnection};
@@ -34,6 +37,7 @@ use sqlparser::dialect::PostgreSqlDialect;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::marker::PhantomData;
+use types::{AsBuiltin, PgText};
 use uuid::Uuid;
 
 /// Protocol - Binary based bulk load
@@ -79,6 +83,137 @@ where
     }
 }
 
+/// Map a column type to `PostgresTypeSystem`. Types that are not known by name, such as
+/// domains and arrays of enums, are looked up by their OID in `pg_type`.
+#[throws(PostgresSourceError)]
+fn resolve_type<C>(conn: &mut PgConn<C>, ty: &Type) -> PostgresTypeSystem
+where
+    C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+    C::TlsConnect: Send,
+    C::Stream: Send,
+    <C::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    match PostgresTypeSystem::from_type(ty) {
+        Some(ts) => ts,
+        None => resolve_oid(conn, ty.oid())?,
+    }
+}
+
+#[throws(PostgresSourceError)]
+fn resolve_oid<C>(conn: &mut PgConn<C>, oid: Oid) -> PostgresTypeSystem
+where
+    C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+    C::TlsConnect: Send,
+    C::Stream: Send,
+    <C::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    if let Some(ts) = Type::from_oid(oid)
+        .as_ref()
+        .and_then(PostgresTypeSystem::from_type)
+    {
+        return ts;
+    }
+
+    let row = conn
+        .query_opt(
+            "SELECT typname, typtype, typbasetype, typelem, typcategory FROM pg_catalog.pg_type WHERE oid = $1",
+            &[&oid],
+        )?
+        .ok_or_else(|| anyhow!("Cannot find type with oid {}", oid))?;
+    let name: String = row.get(0);
+    let typtype: i8 = row.get(1);
+    let base: Oid = row.get(2);
+    let elem: Oid = row.get(3);
+    let category: i8 = row.get(4);
+
+    match (typtype as u8, category as u8) {
+        // domain
+        (b'd', _) => resolve_oid(conn, base)?,
+        (b'e', _) => PostgresTypeSystem::Enum(true),
+        (_, b'A') if elem != 0 => resolve_oid(conn, elem)?
+            .array_of()
+            .ok_or(PostgresSourceError::UnsupportedType(name))?,
+        _ => throw!(PostgresSourceError::UnsupportedType(name)),
+    }
+}
+
+/// A column of a table, as read from `pg_attribute`.
+struct TableColumn {
+    not_null: bool,
+    table: String,
+    typmod: i32,
+}
+
+/// Get the table columns in `origins`, given as the table OID and attribute number the
+/// server reports for a result column.
+#[throws(postgres::Error)]
+fn table_columns<C>(
+    conn: &mut PgConn<C>,
+    origins: &[Option<(Oid, i16)>],
+) -> HashMap<(Oid, i16), TableColumn>
+where
+    C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+    C::TlsConnect: Send,
+    C::Stream: Send,
+    <C::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    let oids: Vec<Oid> = origins.iter().flatten().map(|(oid, _)| *oid).collect();
+    if oids.is_empty() {
+        return HashMap::new();
+    }
+    let rows = conn.query(
+        "SELECT a.attrelid, a.attnum, a.attnotnull, c.relname::text, a.atttypmod FROM pg_catalog.pg_attribute a JOIN pg_catalog.pg_class c ON c.oid = a.attrelid WHERE a.attrelid = ANY($1) AND a.attnum > 0",
+        &[&oids],
+    )?;
+    rows.iter()
+        .map(|row| {
+            let column = TableColumn {
+                not_null: row.get(2),
+                table: row.get(3),
+                typmod: row.get(4),
+            };
+            ((row.get(0), row.get(1)), column)
+        })
+        .collect()
+}
+
+/// Get the type modifiers of the result columns, which the driver does not expose, from
+/// their table columns. Computed columns are not constrained, as with a modifier of -1.
+#[throws(postgres::Error)]
+fn column_typmods<C>(conn: &mut PgConn<C>, origins: &[Option<(Oid, i16)>]) -> Vec<i32>
+where
+    C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+    C::TlsConnect: Send,
+    C::Stream: Send,
+    <C::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    let columns = table_columns(conn, origins)?;
+    origins
+        .iter()
+        .map(|origin| {
+            origin
+                .and_then(|origin| columns.get(&origin))
+                .map_or(-1, |column| column.typmod)
+        })
+        .collect()
+}
+
+/// Decode the precision and scale of `NUMERIC(p, s)` from its type modifier, `None` for
+/// `NUMERIC` without a precision.
+fn numeric_precision_scale(typmod: i32) -> Option<(u8, i8)> {
+    // typmod is ((precision << 16) | scale) + VARHDRSZ, the scale being 11-bit signed
+    let typmod = typmod.checked_sub(4).filter(|t| *t >= 0)?;
+    let precision = (typmod >> 16) & 0xffff;
+    let scale = ((typmod & 0x7ff) ^ 1024) - 1024;
+    Some((u8::try_from(precision).ok()?, i8::try_from(scale).ok()?))
+}
+
+/// Decode the precision of `TIMESTAMP(p)` or `TIME(p)` from its type modifier, which is the precision
+/// itself or -1 for the default of 6.
+fn timestamp_precision(typmod: i32) -> u8 {
+    u8::try_from(typmod).unwrap_or(6)
+}
+
 pub struct PostgresSource<P, C>
 where
     C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
@@ -92,6 +227,8 @@ where
     names: Vec<String>,
     schema: Vec<PostgresTypeSystem>,
     pg_schema: Vec<postgres::types::Type>,
+    type_names: Vec<String>,
+    column_origins: Vec<Option<(Oid, i16)>>,
     _protocol: PhantomData<P>,
 }
 
@@ -114,6 +251,8 @@ where
             names: vec![],
             schema: vec![],
             pg_schema: vec![],
+            type_names: vec![],
+            column_origins: vec![],
             _protocol: PhantomData,
         }
     }
@@ -165,7 +304,16 @@ where
             .unzip();
 
         self.names = names;
-        self.schema = pg_types.iter().map(PostgresTypeSystem::from).collect();
+        self.type_names = pg_types.iter().map(|ty| ty.name().to_string()).collect();
+        self.column_origins = stmt
+            .columns()
+            .iter()
+            .map(|col| col.table_oid().zip(col.column_id()))
+            .collect();
+        self.schema = pg_types
+            .iter()
+            .map(|ty| resolve_type(&mut conn, ty))
+            .collect::<Result<Vec<_>, _>>()?;
         self.pg_schema = self
             .schema
             .iter()
@@ -187,6 +335,142 @@ where
         }
     }
 
+    #[throws(PostgresSourceError)]
+    fn decimal_precision_scale(&mut self) -> Vec<Option<(u8, i8)>> {
+        if !self
+            .schema
+            .iter()
+            .any(|ty| matches!(ty, PostgresTypeSystem::Numeric(_)))
+        {
+            return vec![];
+        }
+
+        let mut conn = self.pool.get()?;
+        column_typmods(&mut conn, &self.column_origins)?
+            .into_iter()
+            .zip(&self.schema)
+            .map(|(typmod, ty)| match ty {
+                PostgresTypeSystem::Numeric(_) => numeric_precision_scale(typmod),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[throws(PostgresSourceError)]
+    fn timestamp_precisions(&mut self) -> Vec<Option<u8>> {
+        let is_timestamp = |ty: &PostgresTypeSystem| {
+            matches!(
+                ty,
+                PostgresTypeSystem::Timestamp(_) | PostgresTypeSystem::TimestampTz(_)
+            )
+        };
+        if !self.schema.iter().any(is_timestamp) {
+            return vec![];
+        }
+
+        let mut conn = self.pool.get()?;
+        column_typmods(&mut conn, &self.column_origins)?
+            .into_iter()
+            .zip(&self.schema)
+            .map(|(typmod, ty)| is_timestamp(ty).then(|| timestamp_precision(typmod)))
+            .collect()
+    }
+
+    #[throws(PostgresSourceError)]
+    fn timezones(&mut self) -> Vec<Option<String>> {
+        if !self
+            .schema
+            .iter()
+            .any(|ty| matches!(ty, PostgresTypeSystem::TimestampTz(_)))
+        {
+            return vec![];
+        }
+
+        // timestamptz values are shown in the session time zone
+        let mut conn = self.pool.get()?;
+        let timezone: String = conn.query_one("SHOW TimeZone", &[])?.get(0);
+        self.schema
+            .iter()
+            .map(|ty| matches!(ty, PostgresTypeSystem::TimestampTz(_)).then(|| timezone.clone()))
+            .collect()
+    }
+
+    #[throws(PostgresSourceError)]
+    fn column_descriptors(&mut self) -> Vec<ColumnDescriptor> {
+        let mut conn = self.pool.get()?;
+        let table_columns = table_columns(&mut conn, &self.column_origins)?;
+        self.type_names
+            .iter()
+            .zip(&self.schema)
+            .enumerate()
+            .map(|(i, (type_name, ty))| {
+                // computed columns are not read from a table
+                let table_column = self
+                    .column_origins
+                    .get(i)
+                    .copied()
+                    .flatten()
+                    .and_then(|origin| table_columns.get(&origin));
+                let typmod = table_column.map_or(-1, |column| column.typmod);
+                let (precision, scale) = match ty {
+                    PostgresTypeSystem::Numeric(_) => match numeric_precision_scale(typmod) {
+                        Some((precision, scale)) => (Some(precision.into()), Some(scale.into())),
+                        None => (None, None),
+                    },
+                    PostgresTypeSystem::Time(_)
+                    | PostgresTypeSystem::Timestamp(_)
+                    | PostgresTypeSystem::TimestampTz(_) => {
+                        (Some(timestamp_precision(typmod).into()), None)
+                    }
+                    // the length plus VARHDRSZ, -1 if it is not limited
+                    PostgresTypeSystem::BpChar(_) | PostgresTypeSystem::VarChar(_) => {
+                        (u32::try_from(typmod - 4).ok(), None)
+                    }
+                    _ => (None, None),
+                };
+                ColumnDescriptor {
+                    type_name: Some(type_name.clone()),
+                    precision,
+                    scale,
+                    nullable: table_column.map(|column| !column.not_null),
+                    table: table_column.map(|column| column.table.clone()),
+                }
+            })
+            .collect()
+    }
+
+    #[throws(PostgresSourceError)]
+    fn json_samples(&mut self, limit: usize) -> Vec<Option<Vec<Value>>> {
+        let is_json = |ty: &PostgresTypeSystem| {
+            matches!(
+                ty,
+                PostgresTypeSystem::JSON(_) | PostgresTypeSystem::JSONB(_)
+            )
+        };
+        if !self.schema.iter().any(is_json) {
+            return vec![];
+        }
+
+        let mut conn = self.pool.get()?;
+        let query = format!(
+            "SELECT * FROM ({}) AS cx_json_samples LIMIT {}",
+            self.queries[0], limit
+        );
+        let rows = conn.query(query.as_str(), &[])?;
+        self.schema
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| {
+                is_json(ty).then(|| {
+                    rows.iter()
+                        .filter_map(|row| row.get::<_, Option<AsBuiltin<Value>>>(i))
+                        .map(|value| value.0)
+                        .collect()
+                })
+            })
+            .collect()
+    }
+
     fn names(&self) -> Vec<String> {
         self.names.clone()
     }
@@ -479,6 +763,8 @@ impl_produce!(
     Uuid,
     Value,
     Vec<String>,
+    PgInterval,
+    PgMoney,
 );
 
 impl<'r, 'a> Produce<'r, NaiveDateTime> for PostgresBinarySourcePartitionParser<'a> {
@@ -600,6 +886,30 @@ impl<'r, 'a> Produce<'r, Option<HashMap<String, Option<String>>>>
     }
 }
 
+impl<'r, 'a> Produce<'r, String> for PostgresBinarySourcePartitionParser<'a> {
+    type Error = PostgresSourceError;
+
+    #[throws(PostgresSourceError)]
+    fn produce(&'r mut self) -> String {
+        let (ridx, cidx) = self.next_loc()?;
+        let row = &self.rowbuf[ridx];
+        let val: PgText = row.try_get(cidx)?;
+        val.0
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<String>> for PostgresBinarySourcePartitionParser<'a> {
+    type Error = PostgresSourceError;
+
+    #[throws(PostgresSourceError)]
+    fn produce(&'r mut self) -> Option<String> {
+        let (ridx, cidx) = self.next_loc()?;
+        let row = &self.rowbuf[ridx];
+        let val: Option<PgText> = row.try_get(cidx)?;
+        val.map(|v| v.0)
+    }
+}
+
 pub struct PostgresCSVSourceParser<'a> {
     iter: StringRecordsIntoIter<CopyOutReader<'a>>,
     rowbuf: Vec<StringRecord>,
@@ -697,7 +1007,7 @@ macro_rules! impl_csv_produce {
     };
 }
 
-impl_csv_produce!(i8, i16, i32, i64, f32, f64, Uuid,);
+impl_csv_produce!(i8, i16, i32, i64, f32, f64, Uuid, PgInterval, PgMoney, String,);
 
 macro_rules! impl_csv_vec_produce {
     ($($t: ty,)+) => {
@@ -1161,8 +1471,8 @@ macro_rules! impl_produce {
                 fn produce(&'r mut self) -> $t {
                     let (ridx, cidx) = self.next_loc()?;
                     let row = &self.rowbuf[ridx];
-                    let val = row.try_get(cidx)?;
-                    val
+                    let val: AsBuiltin<$t> = row.try_get(cidx)?;
+                    val.0
                 }
             }
 
@@ -1173,8 +1483,11 @@ macro_rules! impl_produce {
                 fn produce(&'r mut self) -> Option<$t> {
                     let (ridx, cidx) = self.next_loc()?;
                     let row = &self.rowbuf[ridx];
-                    let val = row.try_get(cidx)?;
-                    val
+                    let val: Option<AsBuiltin<$t>> = row.try_get(cidx)?;
+                    match val {
+                        Some(v) => Some(v.0),
+                        None => None,
+                    }
                 }
             }
         )+
@@ -1204,6 +1517,8 @@ impl_produce!(
     Value,
     HashMap<String, Option<String>>,
     Vec<String>,
+    PgInterval,
+    PgMoney,
 );
 
 impl<'r, 'a> Produce<'r, DateTime<Utc>> for PostgresRawSourceParser<'a> {
@@ -1305,6 +1620,30 @@ impl<'r, 'a> Produce<'r, Option<NaiveDate>> for PostgresRawSourceParser<'a> {
     }
 }
 
+impl<'r, 'a> Produce<'r, String> for PostgresRawSourceParser<'a> {
+    type Error = PostgresSourceError;
+
+    #[throws(PostgresSourceError)]
+    fn produce(&'r mut self) -> String {
+        let (ridx, cidx) = self.next_loc()?;
+        let row = &self.rowbuf[ridx];
+        let val: PgText = row.try_get(cidx)?;
+        val.0
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<String>> for PostgresRawSourceParser<'a> {
+    type Error = PostgresSourceError;
+
+    #[throws(PostgresSourceError)]
+    fn produce(&'r mut self) -> Option<String> {
+        let (ridx, cidx) = self.next_loc()?;
+        let row = &self.rowbuf[ridx];
+        let val: Option<PgText> = row.try_get(cidx)?;
+        val.map(|v| v.0)
+    }
+}
+
 impl<C> SourcePartition for PostgresSourcePartition<SimpleProtocol, C>
 where
     C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
@@ -1343,7 +1682,14 @@ pub struct PostgresSimpleSourceParser {
     current_row: usize,
 }
 impl<'a> PostgresSimpleSourceParser {
-    pub fn new(rows: Vec<SimpleQueryMessage>, schema: &[PostgresTypeSystem]) -> Self {
+    pub fn new(mut rows: Vec<SimpleQueryMessage>, schema: &[PostgresTypeSystem]) -> Self {
+        // the row description comes before the rows
+        rows.retain(|msg| {
+            matches!(
+                msg,
+                SimpleQueryMessage::Row(_) | SimpleQueryMessage::CommandComplete(_)
+            )
+        });
         Self {
             rows,
             ncols: schema.len(),
@@ -1454,7 +1800,7 @@ macro_rules! impl_simple_produce {
     };
 }
 
-impl_simple_produce!(i8, i16, i32, i64, f32, f64, Uuid, bool,);
+impl_simple_produce!(i8, i16, i32, i64, f32, f64, Uuid, bool, PgInterval, PgMoney, String,);
 
 impl<'r> Produce<'r, Decimal> for PostgresSimpleSourceParser {
     type Error = PostgresSourceError;
@@ -0,0 +1,518 @@
+//! Values of Postgres types that have no counterpart in the `postgres` crate.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use postgres::types::{Date, Field, FromSql, Kind, Timestamp, Type};
+use rust_decimal::Decimal;
+use std::borrow::Cow;
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::{from_utf8, FromStr};
+use uuid::Uuid;
+
+/// A Postgres `interval`. Months and days are kept apart from the time part since their
+/// length in seconds depends on the date the interval is added to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PgInterval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl<'a> FromSql<'a> for PgInterval {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let microseconds = i64::from_be_bytes(read_bytes(&mut raw)?);
+        let days = i32::from_be_bytes(read_bytes(&mut raw)?);
+        let months = i32::from_be_bytes(read_bytes(&mut raw)?);
+        Ok(PgInterval {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INTERVAL)
+    }
+}
+
+/// Parse an interval in the default `postgres` interval style, e.g.
+/// `1 year 2 mons -3 days +04:05:06.5`.
+impl FromStr for PgInterval {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut interval = PgInterval::default();
+        let mut tokens = s.split_whitespace();
+        while let Some(token) = tokens.next() {
+            if token.contains(':') {
+                interval.microseconds = parse_time_part(token)?;
+                continue;
+            }
+            let n: i32 = token.parse()?;
+            match tokens.next() {
+                Some("year" | "years") => interval.months += n * 12,
+                Some("mon" | "mons") => interval.months += n,
+                Some("day" | "days") => interval.days += n,
+                unit => return Err(anyhow!("unknown unit {:?} in interval {}", unit, s)),
+            }
+        }
+        Ok(interval)
+    }
+}
+
+/// Format the interval the same way Postgres does in its default `postgres` interval style.
+impl fmt::Display for PgInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut is_zero = true;
+        // a positive part following a negative one gets an explicit sign
+        let mut is_before = false;
+        for (value, unit) in [
+            (self.months / 12, "year"),
+            (self.months % 12, "mon"),
+            (self.days, "day"),
+        ] {
+            if value == 0 {
+                continue;
+            }
+            write!(
+                f,
+                "{}{}{} {}{}",
+                if is_zero { "" } else { " " },
+                if is_before && value > 0 { "+" } else { "" },
+                value,
+                unit,
+                if value != 1 { "s" } else { "" }
+            )?;
+            is_before = value < 0;
+            is_zero = false;
+        }
+
+        if is_zero || self.microseconds != 0 {
+            let micros = self.microseconds.unsigned_abs();
+            let secs = micros / 1_000_000;
+            write!(
+                f,
+                "{}{}{:02}:{:02}:{:02}{}",
+                if is_zero { "" } else { " " },
+                if self.microseconds < 0 {
+                    "-"
+                } else if is_before {
+                    "+"
+                } else {
+                    ""
+                },
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60,
+                fraction(micros % 1_000_000)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A Postgres `money`, counted in the minor unit of the currency, e.g. cents.
+///
+/// Like the `money` type itself, this assumes a currency with two fractional digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PgMoney(pub i64);
+
+impl PgMoney {
+    pub fn to_decimal(self) -> Decimal {
+        Decimal::new(self.0, 2)
+    }
+}
+
+impl<'a> FromSql<'a> for PgMoney {
+    fn from_sql(_: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(PgMoney(i64::from_be_bytes(read_bytes(&mut raw)?)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::MONEY)
+    }
+}
+
+/// Parse the locale formatted output of `money`, e.g. `-$1,234.56`.
+impl FromStr for PgMoney {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (units, cents) = s.rsplit_once('.').unwrap_or((s, "00"));
+        let digits = |part: &str| {
+            part.chars()
+                .filter(char::is_ascii_digit)
+                .collect::<String>()
+        };
+        let (units, cents) = (digits(units), digits(cents));
+        if units.is_empty() && cents.is_empty() || cents.len() > 2 {
+            return Err(anyhow!("cannot parse money {}", s));
+        }
+
+        let value =
+            units.parse::<i64>().unwrap_or(0) * 100 + format!("{:0<2}", cents).parse::<i64>()?;
+        // negative amounts are either signed or in parentheses, depending on the locale
+        if s.contains('-') || s.contains('(') {
+            Ok(PgMoney(-value))
+        } else {
+            Ok(PgMoney(value))
+        }
+    }
+}
+
+impl fmt::Display for PgMoney {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_decimal())
+    }
+}
+
+/// The text representation of a value of a type the `postgres` crate cannot decode, i.e.
+/// network addresses, bit strings, `xml`, geometric types, ranges and composite types. The text is rendered
+/// from the binary format the same way Postgres would output it.
+pub struct PgText(pub String);
+
+impl<'a> FromSql<'a> for PgText {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(PgText(to_text(ty, raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        match ty.kind() {
+            Kind::Range(_) | Kind::Composite(_) => true,
+            _ => matches!(
+                *ty,
+                Type::INET
+                    | Type::CIDR
+                    | Type::MACADDR
+                    | Type::MACADDR8
+                    | Type::BIT
+                    | Type::VARBIT
+                    | Type::XML
+                    | Type::POINT
+                    | Type::LINE
+                    | Type::LSEG
+                    | Type::BOX
+                    | Type::PATH
+                    | Type::POLYGON
+                    | Type::CIRCLE
+            ),
+        }
+    }
+}
+
+/// The built-in type a value of `ty` is sent as: enums are sent as text, domains as their
+/// base type and arrays of those as arrays of that type.
+pub fn builtin_type(ty: &Type) -> Cow<'_, Type> {
+    match ty.kind() {
+        Kind::Enum(_) => Cow::Owned(Type::TEXT),
+        Kind::Domain(base) => Cow::Owned(builtin_type(base).into_owned()),
+        Kind::Array(elem) => match builtin_type(elem) {
+            Cow::Borrowed(_) => Cow::Borrowed(ty),
+            Cow::Owned(elem) => Cow::Owned(Type::new(
+                ty.name().to_string(),
+                ty.oid(),
+                Kind::Array(elem),
+                ty.schema().to_string(),
+            )),
+        },
+        _ => Cow::Borrowed(ty),
+    }
+}
+
+/// Decode a value as the built-in type it is sent as, see `builtin_type`.
+pub struct AsBuiltin<T>(pub T);
+
+impl<'a, T: FromSql<'a>> FromSql<'a> for AsBuiltin<T> {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(AsBuiltin(T::from_sql(&builtin_type(ty), raw)?))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        T::accepts(&builtin_type(ty))
+    }
+}
+
+fn read_bytes<const N: usize>(buf: &mut &[u8]) -> Result<[u8; N]> {
+    if buf.len() < N {
+        return Err(anyhow!("unexpected end of value"));
+    }
+    let (head, tail) = buf.split_at(N);
+    *buf = tail;
+    Ok(head.try_into()?)
+}
+
+/// Read a length prefixed value, `None` for NULL.
+fn read_value<'a>(buf: &mut &'a [u8]) -> Result<Option<&'a [u8]>> {
+    let len = i32::from_be_bytes(read_bytes(buf)?);
+    if len < 0 {
+        return Ok(None);
+    }
+    let len = len as usize;
+    if buf.len() < len {
+        return Err(anyhow!("unexpected end of value"));
+    }
+    let (head, tail) = buf.split_at(len);
+    *buf = tail;
+    Ok(Some(head))
+}
+
+/// Parse `[-+]HH:MM:SS[.ffffff]` into microseconds.
+fn parse_time_part(s: &str) -> Result<i64> {
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s.trim_start_matches('+')),
+    };
+    let mut parts = s.splitn(3, ':');
+    let hours: i64 = parts.next().unwrap_or("0").parse()?;
+    let minutes: i64 = parts.next().unwrap_or("0").parse()?;
+    let secs = parts.next().unwrap_or("0");
+    let (secs, frac) = secs.split_once('.').unwrap_or((secs, ""));
+    let secs: i64 = secs.parse()?;
+    let frac: i64 = match frac {
+        "" => 0,
+        frac => format!("{:0<6}", &frac[..frac.len().min(6)]).parse()?,
+    };
+    Ok(sign * (((hours * 60 + minutes) * 60 + secs) * 1_000_000 + frac))
+}
+
+/// Fractional seconds as Postgres prints them: without trailing zeros, empty if there are none.
+fn fraction(micros: u64) -> String {
+    if micros == 0 {
+        return String::new();
+    }
+    format!(".{:06}", micros).trim_end_matches('0').to_string()
+}
+
+fn format_time(t: &NaiveTime) -> String {
+    format!(
+        "{}{}",
+        t.format("%H:%M:%S"),
+        fraction(t.nanosecond() as u64 / 1000)
+    )
+}
+
+fn format_float(v: f64) -> String {
+    match v {
+        v if v.is_nan() => "NaN".to_string(),
+        v if v == f64::INFINITY => "Infinity".to_string(),
+        v if v == f64::NEG_INFINITY => "-Infinity".to_string(),
+        v => v.to_string(),
+    }
+}
+
+/// Render a value in binary format as text.
+fn to_text(ty: &Type, raw: &[u8]) -> Result<String> {
+    match ty.kind() {
+        Kind::Range(inner) => return range_to_text(inner, raw),
+        Kind::Composite(fields) => return composite_to_text(fields, raw),
+        Kind::Domain(inner) => return to_text(inner, raw),
+        Kind::Enum(_) => return Ok(from_utf8(raw)?.to_string()),
+        _ => {}
+    }
+
+    let from_sql = |e| anyhow!("cannot decode {}: {}", ty, e);
+    let text = match *ty {
+        Type::BOOL => match bool::from_sql(ty, raw).map_err(from_sql)? {
+            true => "t".to_string(),
+            false => "f".to_string(),
+        },
+        Type::INT2 => i16::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::INT4 => i32::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::INT8 => i64::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::FLOAT4 => format_float(f32::from_sql(ty, raw).map_err(from_sql)? as f64),
+        Type::FLOAT8 => format_float(f64::from_sql(ty, raw).map_err(from_sql)?),
+        Type::NUMERIC => Decimal::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::MONEY => PgMoney::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::TEXT
+        | Type::VARCHAR
+        | Type::BPCHAR
+        | Type::NAME
+        | Type::XML
+        | Type::JSON
+        | Type::UNKNOWN => from_utf8(raw)?.to_string(),
+        // jsonb is its text prefixed with a version byte
+        Type::JSONB => from_utf8(raw.get(1..).unwrap_or_default())?.to_string(),
+        Type::BYTEA => format!("\\x{}", hex::encode(raw)),
+        Type::UUID => Uuid::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::DATE => match Date::<NaiveDate>::from_sql(ty, raw).map_err(from_sql)? {
+            Date::PosInfinity => "infinity".to_string(),
+            Date::NegInfinity => "-infinity".to_string(),
+            Date::Value(d) => d.format("%Y-%m-%d").to_string(),
+        },
+        Type::TIME => format_time(&NaiveTime::from_sql(ty, raw).map_err(from_sql)?),
+        Type::TIMESTAMP => match Timestamp::<NaiveDateTime>::from_sql(ty, raw).map_err(from_sql)? {
+            Timestamp::PosInfinity => "infinity".to_string(),
+            Timestamp::NegInfinity => "-infinity".to_string(),
+            Timestamp::Value(t) => format!("{} {}", t.date(), format_time(&t.time())),
+        },
+        Type::TIMESTAMPTZ => {
+            match Timestamp::<DateTime<Utc>>::from_sql(ty, raw).map_err(from_sql)? {
+                Timestamp::PosInfinity => "infinity".to_string(),
+                Timestamp::NegInfinity => "-infinity".to_string(),
+                Timestamp::Value(t) => {
+                    let t = t.naive_utc();
+                    format!("{} {}+00", t.date(), format_time(&t.time()))
+                }
+            }
+        }
+        Type::INTERVAL => PgInterval::from_sql(ty, raw).map_err(from_sql)?.to_string(),
+        Type::INET | Type::CIDR => inet_to_text(raw, *ty == Type::CIDR)?,
+        Type::MACADDR | Type::MACADDR8 => raw
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<Vec<_>>()
+            .join(":"),
+        Type::BIT | Type::VARBIT => bits_to_text(raw)?,
+        Type::POINT
+        | Type::LINE
+        | Type::LSEG
+        | Type::BOX
+        | Type::PATH
+        | Type::POLYGON
+        | Type::CIRCLE => geometric_to_text(ty, raw)?,
+        _ => return Err(anyhow!("cannot convert type {} to text", ty)),
+    };
+    Ok(text)
+}
+
+/// The binary format of `inet` and `cidr` is the address family, the netmask bits, whether
+/// it is a cidr, the address length and the address. Like Postgres, only print the netmask
+/// of an `inet` if it does not cover the whole address.
+fn inet_to_text(mut raw: &[u8], is_cidr: bool) -> Result<String> {
+    let [family, bits, _, len] = read_bytes::<4>(&mut raw)?;
+    let (addr, max_bits) = match (family, len) {
+        (2, 4) => (Ipv4Addr::from(read_bytes::<4>(&mut raw)?).to_string(), 32),
+        (3, 16) => (Ipv6Addr::from(read_bytes::<16>(&mut raw)?).to_string(), 128),
+        _ => return Err(anyhow!("unknown address family {}", family)),
+    };
+    if is_cidr || bits != max_bits {
+        Ok(format!("{}/{}", addr, bits))
+    } else {
+        Ok(addr)
+    }
+}
+
+/// The binary format of a bit string is the number of bits followed by the bits, padded to bytes.
+fn bits_to_text(mut raw: &[u8]) -> Result<String> {
+    let len = i32::from_be_bytes(read_bytes(&mut raw)?) as usize;
+    if raw.len() * 8 < len {
+        return Err(anyhow!("unexpected end of bit string"));
+    }
+    Ok((0..len)
+        .map(|i| match raw[i / 8] >> (7 - i % 8) & 1 {
+            0 => '0',
+            _ => '1',
+        })
+        .collect())
+}
+
+fn read_float(buf: &mut &[u8]) -> Result<String> {
+    Ok(format_float(f64::from_be_bytes(read_bytes(buf)?)))
+}
+
+/// Read `n` points, each being its x and y coordinates.
+fn read_points(buf: &mut &[u8], n: usize) -> Result<String> {
+    let points = (0..n)
+        .map(|_| Ok(format!("({},{})", read_float(buf)?, read_float(buf)?)))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(points.join(","))
+}
+
+/// The binary format of the geometric types is their coordinates as `float8`s, preceded by
+/// the number of points for paths and polygons and whether it is closed for paths.
+fn geometric_to_text(ty: &Type, mut raw: &[u8]) -> Result<String> {
+    let raw = &mut raw;
+    let text = match *ty {
+        Type::POINT => read_points(raw, 1)?,
+        Type::LINE => format!(
+            "{{{},{},{}}}",
+            read_float(raw)?,
+            read_float(raw)?,
+            read_float(raw)?
+        ),
+        Type::LSEG => format!("[{}]", read_points(raw, 2)?),
+        Type::BOX => read_points(raw, 2)?,
+        Type::PATH => {
+            let [closed] = read_bytes::<1>(raw)?;
+            let n = i32::from_be_bytes(read_bytes(raw)?) as usize;
+            match closed {
+                0 => format!("[{}]", read_points(raw, n)?),
+                _ => format!("({})", read_points(raw, n)?),
+            }
+        }
+        Type::POLYGON => {
+            let n = i32::from_be_bytes(read_bytes(raw)?) as usize;
+            format!("({})", read_points(raw, n)?)
+        }
+        Type::CIRCLE => format!("<{},{}>", read_points(raw, 1)?, read_float(raw)?),
+        _ => return Err(anyhow!("{} is not a geometric type", ty)),
+    };
+    Ok(text)
+}
+
+/// Quote an element of a range or a composite type if it would be ambiguous otherwise.
+fn quote(text: String, special: &[char]) -> String {
+    let needs_quotes = text.is_empty()
+        || text
+            .chars()
+            .any(|c| c == '"' || c == '\\' || c.is_whitespace() || special.contains(&c));
+    if !needs_quotes {
+        return text;
+    }
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\"\""))
+}
+
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// The binary format of a range is a flags byte followed by the bounds that are not infinite.
+fn range_to_text(inner: &Type, mut raw: &[u8]) -> Result<String> {
+    let [flags] = read_bytes::<1>(&mut raw)?;
+    if flags & RANGE_EMPTY != 0 {
+        return Ok("empty".to_string());
+    }
+
+    let mut bound = |inf_flag: u8| -> Result<String> {
+        if flags & inf_flag != 0 {
+            return Ok(String::new());
+        }
+        let value = read_value(&mut raw)?.ok_or_else(|| anyhow!("range bound is NULL"))?;
+        Ok(quote(to_text(inner, value)?, &['(', ')', '[', ']', ',']))
+    };
+    let lower = bound(RANGE_LB_INF)?;
+    let upper = bound(RANGE_UB_INF)?;
+
+    Ok(format!(
+        "{}{},{}{}",
+        if flags & RANGE_LB_INC != 0 { '[' } else { '(' },
+        lower,
+        upper,
+        if flags & RANGE_UB_INC != 0 { ']' } else { ')' }
+    ))
+}
+
+/// The binary format of a composite value is the number of fields followed by the type oid
+/// and the length prefixed value of each field.
+fn composite_to_text(fields: &[Field], mut raw: &[u8]) -> Result<String> {
+    let n = i32::from_be_bytes(read_bytes(&mut raw)?) as usize;
+    if n != fields.len() {
+        return Err(anyhow!("expect {} fields, got {}", fields.len(), n));
+    }
+
+    let mut values = Vec::with_capacity(n);
+    for field in fields {
+        let _oid = read_bytes::<4>(&mut raw)?;
+        values.push(match read_value(&mut raw)? {
+            // NULL fields are left empty
+            None => String::new(),
+            Some(value) => quote(to_text(field.type_(), value)?, &['(', ')', ',']),
+        });
+    }
+    Ok(format!("({})", values.join(",")))
+}
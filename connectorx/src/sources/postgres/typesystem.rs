@@ -1,5 +1,6 @@
+use super::types::{builtin_type, PgInterval, PgMoney};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
-use postgres::types::Type;
+use postgres::types::{Kind, Type};
 use rust_decimal::Decimal;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -38,6 +39,17 @@ pub enum PostgresTypeSystem {
     Enum(bool),
     HSTORE(bool),
     Name(bool),
+    Interval(bool),
+    Money(bool),
+    Inet(bool),
+    Cidr(bool),
+    MacAddr(bool),
+    Bit(bool),
+    VarBit(bool),
+    Xml(bool),
+    Range(bool),
+    Composite(bool),
+    Geometric(bool),
 }
 
 impl_typesystem! {
@@ -67,13 +79,24 @@ impl_typesystem! {
         { UUID => Uuid }
         { JSON | JSONB => Value }
         { HSTORE => HashMap<String, Option<String>> }
+        { Interval => PgInterval }
+        { Money => PgMoney }
+        { Inet | Cidr | MacAddr | Bit | VarBit | Xml | Range | Composite | Geometric => String }
     }
 }
 
 impl<'a> From<&'a Type> for PostgresTypeSystem {
     fn from(ty: &'a Type) -> PostgresTypeSystem {
+        PostgresTypeSystem::from_type(ty).unwrap_or_else(|| unimplemented!("{}", ty.name()))
+    }
+}
+
+impl PostgresTypeSystem {
+    /// Map a type by its name or kind. Returns `None` for types that have to be resolved
+    /// through the `pg_type` catalog, such as domains and arrays of user-defined types.
+    pub fn from_type(ty: &Type) -> Option<PostgresTypeSystem> {
         use PostgresTypeSystem::*;
-        match ty.name() {
+        let ts = match ty.name() {
             "int2" => Int2(true),
             "int4" => Int4(true),
             "int8" => Int8(true),
@@ -103,11 +126,41 @@ impl<'a> From<&'a Type> for PostgresTypeSystem {
             "json" => JSON(true),
             "jsonb" => JSONB(true),
             "hstore" => HSTORE(true),
+            "interval" => Interval(true),
+            "money" => Money(true),
+            "inet" => Inet(true),
+            "cidr" => Cidr(true),
+            "macaddr" | "macaddr8" => MacAddr(true),
+            "bit" => Bit(true),
+            "varbit" => VarBit(true),
+            "xml" => Xml(true),
+            "point" | "line" | "lseg" | "box" | "path" | "polygon" | "circle" => Geometric(true),
             _ => match ty.kind() {
-                postgres::types::Kind::Enum(_) => Enum(true),
-                _ => unimplemented!("{}", ty.name()),
+                Kind::Enum(_) => Enum(true),
+                Kind::Range(_) => Range(true),
+                Kind::Composite(_) => Composite(true),
+                _ => return None,
             },
-        }
+        };
+        Some(ts)
+    }
+
+    /// The array type whose elements are of this type.
+    pub fn array_of(self) -> Option<PostgresTypeSystem> {
+        use PostgresTypeSystem::*;
+        let ts = match self {
+            Bool(_) => BoolArray(true),
+            Int2(_) => Int2Array(true),
+            Int4(_) => Int4Array(true),
+            Int8(_) => Int8Array(true),
+            Float4(_) => Float4Array(true),
+            Float8(_) => Float8Array(true),
+            Numeric(_) => NumericArray(true),
+            VarChar(_) => VarcharArray(true),
+            Text(_) | BpChar(_) | Name(_) | Enum(_) => TextArray(true),
+            _ => return None,
+        };
+        Some(ts)
     }
 }
 
@@ -120,7 +173,8 @@ impl<'a> From<PostgresTypePairs<'a>> for Type {
         match ty.1 {
             Enum(_) => Type::TEXT,
             HSTORE(_) => Type::TEXT, // hstore is not supported in binary protocol (since no corresponding inner TYPE)
-            _ => ty.0.clone(),
+            // domains and arrays of enums or domains
+            _ => builtin_type(ty.0).into_owned(),
         }
     }
 }
@@ -2,10 +2,12 @@ use std::{marker::PhantomData, sync::Arc};
 
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use fehler::{throw, throws};
-use prusto::{auth::Auth, Client, ClientBuilder, DataSet, Presto, Row};
+use prusto::{auth::Auth, Client, ClientBuilder, DataSet, Presto, PrestoTy, Row};
+use rust_decimal::Decimal;
 use serde_json::Value;
 use sqlparser::dialect::{Dialect, GenericDialect};
 use std::convert::TryFrom;
+use std::str::FromStr;
 use tokio::runtime::Runtime;
 
 use crate::{
@@ -17,6 +19,7 @@ use crate::{
 
 pub use self::{errors::TrinoSourceError, typesystem::TrinoTypeSystem};
 use urlencoding::decode;
+use uuid::Uuid;
 
 use super::{PartitionParser, Source, SourcePartition};
 
@@ -73,6 +76,7 @@ pub struct TrinoSource {
     queries: Vec<CXQuery<String>>,
     names: Vec<String>,
     schema: Vec<TrinoTypeSystem>,
+    decimal_precision_scale: Vec<Option<(u8, i8)>>,
 }
 
 impl TrinoSource {
@@ -111,6 +115,7 @@ impl TrinoSource {
             queries: vec![],
             names: vec![],
             schema: vec![],
+            decimal_precision_scale: vec![],
         }
     }
 }
@@ -155,6 +160,12 @@ where
 
         for (name, t) in schema {
             self.names.push(name.clone());
+            self.decimal_precision_scale.push(match &t {
+                PrestoTy::Decimal(precision, scale) => {
+                    u8::try_from(*precision).ok().zip(i8::try_from(*scale).ok())
+                }
+                _ => None,
+            });
             self.schema.push(TrinoTypeSystem::try_from(t.clone())?);
         }
     }
@@ -179,6 +190,11 @@ where
         self.schema.clone()
     }
 
+    #[throws(TrinoSourceError)]
+    fn decimal_precision_scale(&mut self) -> Vec<Option<(u8, i8)>> {
+        self.decimal_precision_scale.clone()
+    }
+
     #[throws(TrinoSourceError)]
     fn partition(self) -> Vec<Self::Partition> {
         let mut ret = vec![];
@@ -548,7 +564,38 @@ impl_produce_bool!(bool,);
 impl_produce_int!(i8, i16, i32, i64,);
 impl_produce_float!(f32, f64,);
 impl_produce_timestamp!(NaiveDateTime,);
-impl_produce_text!(String, char,);
+impl_produce_text!(String, char, Uuid,);
+
+impl<'r, 'a> Produce<'r, Decimal> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Decimal {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::String(x) => Decimal::from_str(x).map_err(|_| anyhow!("Trino cannot parse String at position: ({}, {}) {:?}", ridx, cidx, value))?,
+            _ => throw!(anyhow!("Trino cannot parse Decimal at position: ({}, {}) {:?}", ridx, cidx, value))
+        }
+    }
+}
+
+impl<'r, 'a> Produce<'r, Option<Decimal>> for TrinoSourcePartitionParser<'a> {
+    type Error = TrinoSourceError;
+
+    #[throws(TrinoSourceError)]
+    fn produce(&'r mut self) -> Option<Decimal> {
+        let (ridx, cidx) = self.next_loc()?;
+        let value = &self.rows[ridx].value()[cidx];
+
+        match value {
+            Value::Null => None,
+            Value::String(x) => Some(Decimal::from_str(x).map_err(|_| anyhow!("Trino cannot parse String at position: ({}, {}) {:?}", ridx, cidx, value))?),
+            _ => throw!(anyhow!("Trino cannot parse Decimal at position: ({}, {}) {:?}", ridx, cidx, value))
+        }
+    }
+}
 
 impl<'r, 'a> Produce<'r, NaiveTime> for TrinoSourcePartitionParser<'a> {
     type Error = TrinoSourceError;
@@ -2,7 +2,9 @@ use super::errors::TrinoSourceError;
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 use fehler::{throw, throws};
 use prusto::{PrestoFloat, PrestoInt, PrestoTy};
+use rust_decimal::Decimal;
 use std::convert::TryFrom;
+use uuid::Uuid;
 
 // TODO: implement Tuple, Row, Array and Map
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -17,8 +19,10 @@ pub enum TrinoTypeSystem {
     Tinyint(bool),
     Double(bool),
     Real(bool),
+    Decimal(bool),
     Varchar(bool),
     Char(bool),
+    Uuid(bool),
 }
 
 impl_typesystem! {
@@ -34,8 +38,10 @@ impl_typesystem! {
         { Tinyint => i8 }
         { Double => f64 }
         { Real => f32 }
+        { Decimal => Decimal }
         { Varchar => String }
         { Char => char }
+        { Uuid => Uuid }
     }
 }
 
@@ -62,9 +68,9 @@ impl TryFrom<PrestoTy> for TrinoTypeSystem {
             PrestoTy::Row(_) => Varchar(true),
             PrestoTy::Array(_) => Varchar(true),
             PrestoTy::Map(_, _) => Varchar(true),
-            PrestoTy::Decimal(_, _) => Double(true),
+            PrestoTy::Decimal(_, _) => Decimal(true),
             PrestoTy::IpAddress => Varchar(true),
-            PrestoTy::Uuid => Varchar(true),
+            PrestoTy::Uuid => Uuid(true),
             _ => throw!(TrinoSourceError::InferTypeFromNull),
         }
     }
@@ -96,9 +102,9 @@ impl TryFrom<(Option<&str>, PrestoTy)> for TrinoTypeSystem {
                     "row" => Varchar(true),
                     "array" => Varchar(true),
                     "map" => Varchar(true),
-                    "decimal" => Double(true),
+                    "decimal" => Decimal(true),
                     "ipaddress" => Varchar(true),
-                    "uuid" => Varchar(true),
+                    "uuid" => Uuid(true),
                     _ => TrinoTypeSystem::try_from(ty)?,
                 }
             }
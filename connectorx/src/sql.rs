@@ -5,11 +5,12 @@ use fehler::{throw, throws};
 use log::{debug, trace, warn};
 use sqlparser::ast::{
     BinaryOperator, Expr, Function, FunctionArg, FunctionArgExpr, Ident, ObjectName, Query, Select,
-    SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Value,
+    SelectItem, SetExpr, Statement, TableAlias, TableFactor, TableWithJoins, Top, Value,
     WildcardAdditionalOptions,
 };
-use sqlparser::dialect::Dialect;
+use sqlparser::dialect::{Dialect, MsSqlDialect};
 use sqlparser::parser::Parser;
+use sqlparser::tokenizer::Token;
 #[cfg(feature = "src_oracle")]
 use std::any::Any;
 
@@ -598,3 +599,80 @@ pub fn get_partition_range_query_sep<T: Dialect>(
     );
     (sql_min, sql_max)
 }
+
+/// `SELECT projection FROM table WHERE selection`, returning at most `limit` rows. `table` is
+/// parsed as a name of `dialect`, so it may be qualified by a schema and quoted.
+#[throws(ConnectorXError)]
+pub fn select_query(
+    table: &str,
+    projection: Vec<SelectItem>,
+    selection: Option<Expr>,
+    limit: Option<usize>,
+    dialect: &dyn Dialect,
+) -> String {
+    trace!("Incoming table: {}", table);
+
+    let mut parser = Parser::new(dialect).try_with_sql(table)?;
+    let name = parser.parse_object_name()?;
+    parser.expect_token(&Token::EOF)?;
+
+    let mut limit = limit.map(|n| Expr::Value(Value::Number(n.to_string(), false)));
+    // SQL Server has no LIMIT and Oracle only FETCH FIRST
+    let mut top = None;
+    if dialect.is::<MsSqlDialect>() {
+        top = limit.take().map(|n| Top {
+            with_ties: false,
+            percent: false,
+            quantity: Some(n),
+        });
+    }
+    #[allow(unused_mut)]
+    let mut fetch = None;
+    #[cfg(feature = "src_oracle")]
+    if dialect.is::<OracleDialect>() {
+        fetch = limit.take().map(|n| sqlparser::ast::Fetch {
+            with_ties: false,
+            percent: false,
+            quantity: Some(n),
+        });
+    }
+
+    let tsql = format!(
+        "{}",
+        Statement::Query(Box::new(Query {
+            with: None,
+            locks: vec![],
+            body: Box::new(SetExpr::Select(Box::new(Select {
+                distinct: None,
+                top,
+                projection,
+                from: vec![TableWithJoins {
+                    relation: TableFactor::Table {
+                        name,
+                        alias: None,
+                        args: None,
+                        with_hints: vec![],
+                        version: None,
+                    },
+                    joins: vec![],
+                }],
+                lateral_views: vec![],
+                selection,
+                group_by: vec![],
+                cluster_by: vec![],
+                distribute_by: vec![],
+                sort_by: vec![],
+                having: None,
+                into: None,
+                named_window: vec![],
+                qualify: None,
+            }))),
+            order_by: vec![],
+            limit,
+            offset: None,
+            fetch,
+        }))
+    );
+    debug!("Transformed select query: {}", tsql);
+    tsql
+}
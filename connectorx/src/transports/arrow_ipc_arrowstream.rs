@@ -0,0 +1,66 @@
+//! Transport from Arrow IPC Source to Arrow Destination.
+
+use crate::{
+    destinations::arrowstream::{
+        typesystem::ArrowTypeSystem, ArrowDestination, ArrowDestinationError,
+    },
+    impl_transport,
+    sources::arrow_ipc::{ArrowIPCSource, ArrowIPCSourceError, ArrowIPCTypeSystem},
+    typesystem::TypeConversion,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ArrowIPCArrowTransportError {
+    #[error(transparent)]
+    Source(#[from] ArrowIPCSourceError),
+
+    #[error(transparent)]
+    Destination(#[from] ArrowDestinationError),
+
+    #[error(transparent)]
+    ConnectorX(#[from] crate::errors::ConnectorXError),
+}
+
+/// Convert Arrow IPC data types to Arrow data types.
+pub struct ArrowIPCArrowTransport;
+
+impl_transport!(
+    name = ArrowIPCArrowTransport,
+    error = ArrowIPCArrowTransportError,
+    systems = ArrowIPCTypeSystem => ArrowTypeSystem,
+    route = ArrowIPCSource => ArrowDestination,
+    mappings = {
+        { Boolean[bool]              => Boolean[bool]             | conversion auto }
+        { Int8[i8]                   => Int32[i32]                | conversion auto }
+        { Int16[i16]                 => Int32[i32]                | conversion auto }
+        { Int32[i32]                 => Int32[i32]                | conversion auto }
+        { Int64[i64]                 => Int64[i64]                | conversion auto }
+        { UInt8[u8]                  => UInt32[u32]               | conversion auto }
+        { UInt16[u16]                => UInt32[u32]               | conversion auto }
+        { UInt32[u32]                => UInt32[u32]               | conversion auto }
+        { UInt64[u64]                => UInt64[u64]               | conversion auto }
+        { Float32[f32]               => Float32[f32]              | conversion auto }
+        { Float64[f64]               => Float64[f64]              | conversion auto }
+        { Decimal[Decimal]           => Float64[f64]              | conversion option }
+        { Utf8[String]               => LargeUtf8[String]         | conversion auto }
+        { Binary[Vec<u8>]            => LargeBinary[Vec<u8>]      | conversion auto }
+        { Date32[NaiveDate]          => Date32[NaiveDate]         | conversion auto }
+        { Date64[NaiveDateTime]      => Date64[NaiveDateTime]     | conversion auto }
+        { Time64[NaiveTime]          => Time64[NaiveTime]         | conversion auto }
+        { Timestamp[NaiveDateTime]   => Date64[NaiveDateTime]     | conversion none }
+        { TimestampTz[DateTime<Utc>] => DateTimeTz[DateTime<Utc>] | conversion auto }
+        { TimestampNano[NaiveDateTime]   => Date64[NaiveDateTime]     | conversion none }
+        { TimestampTzNano[DateTime<Utc>] => DateTimeTz[DateTime<Utc>] | conversion none }
+    }
+);
+
+impl TypeConversion<Decimal, f64> for ArrowIPCArrowTransport {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
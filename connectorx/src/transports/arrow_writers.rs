@@ -0,0 +1,106 @@
+//! Transports from any source with an Arrow transport to the destinations writing Arrow
+//! record batches out, e.g. to Parquet files.
+
+use crate::destinations::arrow::{ArrowDestination, ArrowPartitionWriter, ArrowTypeSystem};
+#[cfg(feature = "dst_arrow_ipc")]
+use crate::destinations::arrow_ipc::ArrowIPCDestination;
+#[cfg(feature = "dst_csv")]
+use crate::destinations::csv::CSVDestination;
+#[cfg(feature = "dst_parquet")]
+use crate::destinations::parquet::ParquetDestination;
+use crate::errors::Result;
+use crate::sources::{Source, SourcePartition};
+use crate::typesystem::Transport;
+#[cfg(feature = "dst_arrow_ipc")]
+use std::io::Write;
+use std::marker::PhantomData;
+
+/// Implement `Transport` for `$name` by delegating to the Arrow transport `TP` of the
+/// source, since the destination builds its columns as the Arrow destination does.
+macro_rules! impl_arrow_writer_transport {
+    ($name:ident<TP $(, $g:ident)*> => $dst:ty $(, where $($bounds:tt)+)?) => {
+        impl<TP $(, $g)*> Transport for $name<TP $(, $g)*>
+        where
+            TP: Transport<TSD = ArrowTypeSystem, D = ArrowDestination>,
+            $($($bounds)+)?
+        {
+            type TSS = TP::TSS;
+            type TSD = ArrowTypeSystem;
+            type S = TP::S;
+            type D = $dst;
+            type Error = TP::Error;
+
+            fn convert_typesystem(ts: Self::TSS) -> Result<ArrowTypeSystem> {
+                TP::convert_typesystem(ts)
+            }
+
+            fn convert_typesystem_to(
+                ts1: Self::TSS,
+                ts2: ArrowTypeSystem,
+            ) -> Result<ArrowTypeSystem> {
+                TP::convert_typesystem_to(ts1, ts2)
+            }
+
+            fn can_convert(ts1: Self::TSS, ts2: ArrowTypeSystem) -> bool {
+                TP::can_convert(ts1, ts2)
+            }
+
+            fn process<'s, 'd, 'r>(
+                ts1: Self::TSS,
+                ts2: ArrowTypeSystem,
+                src: &'r mut <<Self::S as Source>::Partition as SourcePartition>::Parser<'s>,
+                dst: &'r mut ArrowPartitionWriter,
+            ) -> std::result::Result<(), Self::Error>
+            where
+                Self: 'd,
+            {
+                TP::process(ts1, ts2, src, dst)
+            }
+
+            #[allow(clippy::type_complexity)]
+            fn processor<'s, 'd>(
+                ts1: Self::TSS,
+                ts2: ArrowTypeSystem,
+            ) -> Result<
+                fn(
+                    src: &mut <<Self::S as Source>::Partition as SourcePartition>::Parser<'s>,
+                    dst: &mut ArrowPartitionWriter,
+                ) -> std::result::Result<(), Self::Error>,
+            >
+            where
+                Self: 'd,
+            {
+                TP::processor(ts1, ts2)
+            }
+        }
+    };
+}
+
+/// Convert the data types of a source to Arrow data types written to Parquet files, with
+/// the rules of its Arrow transport `TP`, e.g.
+/// `ParquetTransport<PostgresArrowTransport<BinaryProtocol, NoTls>>`.
+#[cfg(feature = "dst_parquet")]
+pub struct ParquetTransport<TP>(PhantomData<TP>);
+
+#[cfg(feature = "dst_parquet")]
+impl_arrow_writer_transport!(ParquetTransport<TP> => ParquetDestination);
+
+/// Convert the data types of a source to Arrow data types written to an IPC file or stream
+/// of type `W`, with the rules of its Arrow transport `TP`, e.g.
+/// `ArrowIPCTransport<PostgresArrowTransport<BinaryProtocol, NoTls>, File>`.
+#[cfg(feature = "dst_arrow_ipc")]
+pub struct ArrowIPCTransport<TP, W>(PhantomData<(TP, W)>);
+
+#[cfg(feature = "dst_arrow_ipc")]
+impl_arrow_writer_transport!(
+    ArrowIPCTransport<TP, W> => ArrowIPCDestination<W>, where W: Write + Send + 'static
+);
+
+/// Convert the data types of a source to Arrow data types written to CSV files, with the
+/// rules of its Arrow transport `TP`, e.g.
+/// `CSVTransport<PostgresArrowTransport<BinaryProtocol, NoTls>>`.
+#[cfg(feature = "dst_csv")]
+pub struct CSVTransport<TP>(PhantomData<TP>);
+
+#[cfg(feature = "dst_csv")]
+impl_arrow_writer_transport!(CSVTransport<TP> => CSVDestination);
@@ -0,0 +1,100 @@
+//! Transport from ClickHouse Source to Arrow Destination.
+
+use crate::{
+    destinations::arrow::{typesystem::ArrowTypeSystem, ArrowDestination, ArrowDestinationError},
+    impl_transport,
+    sources::clickhouse::{
+        ArrowStreamProtocol, ClickHouseSource, ClickHouseSourceError, ClickHouseTypeSystem,
+        RowBinaryProtocol,
+    },
+    typesystem::TypeConversion,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ClickHouseArrowTransportError {
+    #[error(transparent)]
+    Source(#[from] ClickHouseSourceError),
+
+    #[error(transparent)]
+    Destination(#[from] ArrowDestinationError),
+
+    #[error(transparent)]
+    ConnectorX(#[from] crate::errors::ConnectorXError),
+}
+
+/// Convert ClickHouse data types to Arrow data types.
+pub struct ClickHouseArrowTransport<P>(PhantomData<P>);
+
+impl_transport!(
+    name = ClickHouseArrowTransport<RowBinaryProtocol>,
+    error = ClickHouseArrowTransportError,
+    systems = ClickHouseTypeSystem => ArrowTypeSystem,
+    route = ClickHouseSource<RowBinaryProtocol> => ArrowDestination,
+    mappings = {
+        { Bool[bool]               => Boolean[bool]            | conversion auto }
+        { Int8[i8]                 => Int64[i64]               | conversion auto }
+        { Int8[i8]                 => Int8[i8]                 | conversion auto }
+        { Int16[i16]               => Int64[i64]               | conversion auto }
+        { Int16[i16]               => Int16[i16]               | conversion auto }
+        { Int32[i32]               => Int64[i64]               | conversion auto }
+        { Int32[i32]               => Int32[i32]               | conversion auto }
+        { Int64[i64]               => Int64[i64]               | conversion auto }
+        { UInt8[u8]                => UInt64[u64]              | conversion auto }
+        { UInt8[u8]                => UInt8[u8]                | conversion auto }
+        { UInt16[u16]              => UInt64[u64]              | conversion auto }
+        { UInt16[u16]              => UInt16[u16]              | conversion auto }
+        { UInt32[u32]              => UInt64[u64]              | conversion auto }
+        { UInt32[u32]              => UInt32[u32]              | conversion auto }
+        { UInt64[u64]              => UInt64[u64]              | conversion auto }
+        { Float32[f32]             => Float32[f32]             | conversion auto }
+        { Float64[f64]             => Float64[f64]             | conversion auto }
+        { Decimal[Decimal]         => Float64[f64]             | conversion option }
+        { Decimal[Decimal]         => Decimal[Decimal]         | conversion auto }
+        { String[String]           => LargeUtf8[String]        | conversion auto }
+        { Date[NaiveDate]          => Date32[NaiveDate]        | conversion auto }
+        { DateTime[DateTime<Utc>]  => DateTimeTz[DateTime<Utc>] | conversion auto }
+    }
+);
+
+impl_transport!(
+    name = ClickHouseArrowTransport<ArrowStreamProtocol>,
+    error = ClickHouseArrowTransportError,
+    systems = ClickHouseTypeSystem => ArrowTypeSystem,
+    route = ClickHouseSource<ArrowStreamProtocol> => ArrowDestination,
+    mappings = {
+        { Bool[bool]               => Boolean[bool]            | conversion auto }
+        { Int8[i8]                 => Int64[i64]               | conversion auto }
+        { Int8[i8]                 => Int8[i8]                 | conversion auto }
+        { Int16[i16]               => Int64[i64]               | conversion auto }
+        { Int16[i16]               => Int16[i16]               | conversion auto }
+        { Int32[i32]               => Int64[i64]               | conversion auto }
+        { Int32[i32]               => Int32[i32]               | conversion auto }
+        { Int64[i64]               => Int64[i64]               | conversion auto }
+        { UInt8[u8]                => UInt64[u64]              | conversion auto }
+        { UInt8[u8]                => UInt8[u8]                | conversion auto }
+        { UInt16[u16]              => UInt64[u64]              | conversion auto }
+        { UInt16[u16]              => UInt16[u16]              | conversion auto }
+        { UInt32[u32]              => UInt64[u64]              | conversion auto }
+        { UInt32[u32]              => UInt32[u32]              | conversion auto }
+        { UInt64[u64]              => UInt64[u64]              | conversion auto }
+        { Float32[f32]             => Float32[f32]             | conversion auto }
+        { Float64[f64]             => Float64[f64]             | conversion auto }
+        { Decimal[Decimal]         => Float64[f64]             | conversion option }
+        { Decimal[Decimal]         => Decimal[Decimal]         | conversion auto }
+        { String[String]           => LargeUtf8[String]        | conversion auto }
+        { Date[NaiveDate]          => Date32[NaiveDate]        | conversion auto }
+        { DateTime[DateTime<Utc>]  => DateTimeTz[DateTime<Utc>] | conversion auto }
+    }
+);
+
+impl<P> TypeConversion<Decimal, f64> for ClickHouseArrowTransport<P> {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
@@ -3,7 +3,9 @@
 use crate::destinations::arrow::{ArrowDestination, ArrowDestinationError, ArrowTypeSystem};
 use crate::sources::csv::{CSVSource, CSVSourceError, CSVTypeSystem};
 use crate::typesystem::TypeConversion;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// Convert CSV data types to Arrow data types.
@@ -32,5 +34,16 @@ impl_transport!(
         { Bool[bool]              => Boolean[bool]             | conversion auto}
         { String[String]          => LargeUtf8[String]         | conversion auto}
         { DateTime[DateTime<Utc>] => DateTimeTz[DateTime<Utc>] | conversion auto}
+        { Date[NaiveDate]         => Date32[NaiveDate]         | conversion auto}
+        { Time[NaiveTime]         => Time64[NaiveTime]         | conversion auto}
+        { Decimal[Decimal]        => Float64[f64]              | conversion option}
+        { Decimal[Decimal]        => Decimal[Decimal]          | conversion auto }
     }
 );
+
+impl TypeConversion<Decimal, f64> for CSVArrowTransport {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
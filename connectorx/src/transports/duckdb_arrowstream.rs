@@ -0,0 +1,63 @@
+//! Transport from DuckDB Source to Arrow Destination.
+
+use crate::{
+    destinations::arrowstream::{
+        typesystem::ArrowTypeSystem, ArrowDestination, ArrowDestinationError,
+    },
+    impl_transport,
+    sources::duckdb::{DuckDBSource, DuckDBSourceError, DuckDBTypeSystem},
+    typesystem::TypeConversion,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DuckDBArrowTransportError {
+    #[error(transparent)]
+    Source(#[from] DuckDBSourceError),
+
+    #[error(transparent)]
+    Destination(#[from] ArrowDestinationError),
+
+    #[error(transparent)]
+    ConnectorX(#[from] crate::errors::ConnectorXError),
+}
+
+/// Convert DuckDB data types to Arrow data types.
+pub struct DuckDBArrowTransport;
+
+impl_transport!(
+    name = DuckDBArrowTransport,
+    error = DuckDBArrowTransportError,
+    systems = DuckDBTypeSystem => ArrowTypeSystem,
+    route = DuckDBSource => ArrowDestination,
+    mappings = {
+        { Bool[bool]                 => Boolean[bool]           | conversion auto }
+        { TinyInt[i8]                => Int64[i64]              | conversion auto }
+        { SmallInt[i16]              => Int64[i64]              | conversion auto }
+        { Integer[i32]               => Int64[i64]              | conversion auto }
+        { BigInt[i64]                => Int64[i64]              | conversion auto }
+        { UTinyInt[u8]               => UInt64[u64]             | conversion auto }
+        { USmallInt[u16]             => UInt64[u64]             | conversion auto }
+        { UInteger[u32]              => UInt64[u64]             | conversion auto }
+        { UBigInt[u64]               => UInt64[u64]             | conversion auto }
+        { Float[f32]                 => Float32[f32]            | conversion auto }
+        { Double[f64]                => Float64[f64]            | conversion auto }
+        { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Varchar[String]            => LargeUtf8[String]       | conversion auto }
+        { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion auto }
+        { Date[NaiveDate]            => Date32[NaiveDate]       | conversion auto }
+        { Time[NaiveTime]            => Time64[NaiveTime]       | conversion auto }
+        { Timestamp[NaiveDateTime]   => Date64[NaiveDateTime]   | conversion auto }
+        { TimestampTz[DateTime<Utc>] => DateTimeTz[DateTime<Utc>] | conversion auto }
+    }
+);
+
+impl TypeConversion<Decimal, f64> for DuckDBArrowTransport {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
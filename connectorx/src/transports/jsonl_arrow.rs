@@ -0,0 +1,34 @@
+//! Transport from JSON Lines Source to Arrow Destination.
+
+use crate::destinations::arrow::{ArrowDestination, ArrowDestinationError, ArrowTypeSystem};
+use crate::sources::jsonl::{JSONLSource, JSONLSourceError, JSONLTypeSystem};
+use crate::typesystem::TypeConversion;
+use thiserror::Error;
+
+/// Convert JSON Lines data types to Arrow data types.
+pub struct JSONLArrowTransport;
+
+#[derive(Error, Debug)]
+pub enum JSONLArrowTransportError {
+    #[error(transparent)]
+    Source(#[from] JSONLSourceError),
+
+    #[error(transparent)]
+    Destination(#[from] ArrowDestinationError),
+
+    #[error(transparent)]
+    ConnectorX(#[from] crate::errors::ConnectorXError),
+}
+
+impl_transport!(
+    name = JSONLArrowTransport,
+    error = JSONLArrowTransportError,
+    systems = JSONLTypeSystem => ArrowTypeSystem,
+    route = JSONLSource => ArrowDestination,
+    mappings = {
+        { Bool[bool]     => Boolean[bool]     | conversion auto}
+        { I64[i64]       => Int64[i64]        | conversion auto}
+        { F64[f64]       => Float64[f64]      | conversion auto}
+        { String[String] => LargeUtf8[String] | conversion auto}
+    }
+);
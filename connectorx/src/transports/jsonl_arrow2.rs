@@ -0,0 +1,34 @@
+//! Transport from JSON Lines Source to Arrow2 Destination.
+
+use crate::destinations::arrow2::{Arrow2Destination, Arrow2DestinationError, Arrow2TypeSystem};
+use crate::sources::jsonl::{JSONLSource, JSONLSourceError, JSONLTypeSystem};
+use crate::typesystem::TypeConversion;
+use thiserror::Error;
+
+/// Convert JSON Lines data types to Arrow2 data types.
+pub struct JSONLArrow2Transport;
+
+#[derive(Error, Debug)]
+pub enum JSONLArrow2TransportError {
+    #[error(transparent)]
+    Source(#[from] JSONLSourceError),
+
+    #[error(transparent)]
+    Destination(#[from] Arrow2DestinationError),
+
+    #[error(transparent)]
+    ConnectorX(#[from] crate::errors::ConnectorXError),
+}
+
+impl_transport!(
+    name = JSONLArrow2Transport,
+    error = JSONLArrow2TransportError,
+    systems = JSONLTypeSystem => Arrow2TypeSystem,
+    route = JSONLSource => Arrow2Destination,
+    mappings = {
+        { Bool[bool]     => Boolean[bool]     | conversion auto}
+        { I64[i64]       => Int64[i64]        | conversion auto}
+        { F64[f64]       => Float64[f64]      | conversion auto}
+        { String[String] => LargeUtf8[String] | conversion auto}
+    }
+);
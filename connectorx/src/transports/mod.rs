@@ -1,11 +1,25 @@
 //! This module contains transport definitions for the sources and destinations implemented in ConnectorX.
 
+#[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow"))]
+mod arrow_ipc_arrow;
+#[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow2"))]
+mod arrow_ipc_arrow2;
+#[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow"))]
+mod arrow_ipc_arrowstream;
+#[cfg(any(feature = "dst_parquet", feature = "dst_arrow_ipc", feature = "dst_csv"))]
+mod arrow_writers;
 #[cfg(all(feature = "src_bigquery", feature = "dst_arrow"))]
 mod bigquery_arrow;
 #[cfg(all(feature = "src_bigquery", feature = "dst_arrow2"))]
 mod bigquery_arrow2;
 #[cfg(all(feature = "src_bigquery", feature = "dst_arrow"))]
 mod bigquery_arrowstream;
+#[cfg(all(feature = "src_clickhouse", feature = "dst_arrow"))]
+mod clickhouse_arrow;
+#[cfg(all(feature = "src_clickhouse", feature = "dst_arrow2"))]
+mod clickhouse_arrow2;
+#[cfg(all(feature = "src_clickhouse", feature = "dst_arrow"))]
+mod clickhouse_arrowstream;
 #[cfg(all(feature = "src_csv", feature = "dst_arrow"))]
 mod csv_arrow;
 #[cfg(all(feature = "src_dummy", feature = "dst_arrow"))]
@@ -14,6 +28,16 @@ mod dummy_arrow;
 mod dummy_arrow2;
 #[cfg(all(feature = "src_dummy", feature = "dst_arrow"))]
 mod dummy_arrowstream;
+#[cfg(all(feature = "src_duckdb", feature = "dst_arrow"))]
+mod duckdb_arrow;
+#[cfg(all(feature = "src_duckdb", feature = "dst_arrow2"))]
+mod duckdb_arrow2;
+#[cfg(all(feature = "src_duckdb", feature = "dst_arrow"))]
+mod duckdb_arrowstream;
+#[cfg(all(feature = "src_jsonl", feature = "dst_arrow"))]
+mod jsonl_arrow;
+#[cfg(all(feature = "src_jsonl", feature = "dst_arrow2"))]
+mod jsonl_arrow2;
 #[cfg(all(feature = "src_mssql", feature = "dst_arrow"))]
 mod mssql_arrow;
 #[cfg(all(feature = "src_mssql", feature = "dst_arrow2"))]
@@ -32,6 +56,10 @@ mod oracle_arrow;
 mod oracle_arrow2;
 #[cfg(all(feature = "src_oracle", feature = "dst_arrow"))]
 mod oracle_arrowstream;
+#[cfg(all(feature = "src_parquet", feature = "dst_arrow"))]
+mod parquet_arrow;
+#[cfg(all(feature = "src_parquet", feature = "dst_arrow2"))]
+mod parquet_arrow2;
 #[cfg(all(feature = "src_postgres", feature = "dst_arrow"))]
 mod postgres_arrow;
 #[cfg(all(feature = "src_postgres", feature = "dst_arrow2"))]
@@ -50,6 +78,21 @@ mod trino_arrow;
 mod trino_arrow2;
 #[cfg(all(feature = "src_trino", feature = "dst_arrow"))]
 mod trino_arrowstream;
+#[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow"))]
+pub use arrow_ipc_arrow::{ArrowIPCArrowTransport, ArrowIPCArrowTransportError};
+#[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow2"))]
+pub use arrow_ipc_arrow2::{ArrowIPCArrow2Transport, ArrowIPCArrow2TransportError};
+#[cfg(all(feature = "src_arrow_ipc", feature = "dst_arrow"))]
+pub use arrow_ipc_arrowstream::{
+    ArrowIPCArrowTransport as ArrowIPCArrowStreamTransport,
+    ArrowIPCArrowTransportError as ArrowIPCArrowStreamTransportError,
+};
+#[cfg(feature = "dst_arrow_ipc")]
+pub use arrow_writers::ArrowIPCTransport;
+#[cfg(feature = "dst_csv")]
+pub use arrow_writers::CSVTransport;
+#[cfg(feature = "dst_parquet")]
+pub use arrow_writers::ParquetTransport;
 #[cfg(all(feature = "src_bigquery", feature = "dst_arrow"))]
 pub use bigquery_arrow::{BigQueryArrowTransport, BigQueryArrowTransportError};
 #[cfg(all(feature = "src_bigquery", feature = "dst_arrow2"))]
@@ -59,12 +102,34 @@ pub use bigquery_arrowstream::{
     BigQueryArrowTransport as BigQueryArrowStreamTransport,
     BigQueryArrowTransportError as BigQueryArrowStreamTransportError,
 };
+#[cfg(all(feature = "src_clickhouse", feature = "dst_arrow"))]
+pub use clickhouse_arrow::{ClickHouseArrowTransport, ClickHouseArrowTransportError};
+#[cfg(all(feature = "src_clickhouse", feature = "dst_arrow2"))]
+pub use clickhouse_arrow2::{ClickHouseArrow2Transport, ClickHouseArrow2TransportError};
+#[cfg(all(feature = "src_clickhouse", feature = "dst_arrow"))]
+pub use clickhouse_arrowstream::{
+    ClickHouseArrowTransport as ClickHouseArrowStreamTransport,
+    ClickHouseArrowTransportError as ClickHouseArrowStreamTransportError,
+};
 #[cfg(all(feature = "src_csv", feature = "dst_arrow"))]
 pub use csv_arrow::CSVArrowTransport;
 #[cfg(all(feature = "src_dummy", feature = "dst_arrow"))]
 pub use dummy_arrow::DummyArrowTransport;
 #[cfg(all(feature = "src_dummy", feature = "dst_arrow2"))]
-pub use dummy_arrow2::DummyArrow2Transport;
+pub use dummy_arrow2::{DummyArrow2Transport, DummyArrow2TransportError};
+#[cfg(all(feature = "src_duckdb", feature = "dst_arrow"))]
+pub use duckdb_arrow::{DuckDBArrowTransport, DuckDBArrowTransportError};
+#[cfg(all(feature = "src_duckdb", feature = "dst_arrow2"))]
+pub use duckdb_arrow2::{DuckDBArrow2Transport, DuckDBArrow2TransportError};
+#[cfg(all(feature = "src_duckdb", feature = "dst_arrow"))]
+pub use duckdb_arrowstream::{
+    DuckDBArrowTransport as DuckDBArrowStreamTransport,
+    DuckDBArrowTransportError as DuckDBArrowStreamTransportError,
+};
+#[cfg(all(feature = "src_jsonl", feature = "dst_arrow"))]
+pub use jsonl_arrow::{JSONLArrowTransport, JSONLArrowTransportError};
+#[cfg(all(feature = "src_jsonl", feature = "dst_arrow2"))]
+pub use jsonl_arrow2::{JSONLArrow2Transport, JSONLArrow2TransportError};
 #[cfg(all(feature = "src_mssql", feature = "dst_arrow"))]
 pub use mssql_arrow::{MsSQLArrowTransport, MsSQLArrowTransportError};
 #[cfg(all(feature = "src_mssql", feature = "dst_arrow2"))]
@@ -92,6 +157,10 @@ pub use oracle_arrowstream::{
     OracleArrowTransport as OracleArrowStreamTransport,
     OracleArrowTransportError as OracleArrowStreamTransportError,
 };
+#[cfg(all(feature = "src_parquet", feature = "dst_arrow"))]
+pub use parquet_arrow::{ParquetArrowTransport, ParquetArrowTransportError};
+#[cfg(all(feature = "src_parquet", feature = "dst_arrow2"))]
+pub use parquet_arrow2::{ParquetArrow2Transport, ParquetArrow2TransportError};
 #[cfg(all(feature = "src_postgres", feature = "dst_arrow"))]
 pub use postgres_arrow::{PostgresArrowTransport, PostgresArrowTransportError};
 #[cfg(all(feature = "src_postgres", feature = "dst_arrow2"))]
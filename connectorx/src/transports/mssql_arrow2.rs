@@ -43,22 +43,33 @@ impl_transport!(
         { Floatn[FloatN]                => Float64[f64]              | conversion option }
         { Bit[bool]                     => Boolean[bool]             | conversion auto  }
         { Nvarchar[&'r str]             => LargeUtf8[String]         | conversion owned }
+        { Nvarchar[&'r str]             => LargeBinary[Vec<u8>]      | conversion option }
         { Varchar[&'r str]              => LargeUtf8[String]         | conversion none }
+        { Varchar[&'r str]              => LargeBinary[Vec<u8>]      | conversion none }
         { Nchar[&'r str]                => LargeUtf8[String]         | conversion none }
+        { Nchar[&'r str]                => LargeBinary[Vec<u8>]      | conversion none }
         { Char[&'r str]                 => LargeUtf8[String]         | conversion none }
+        { Char[&'r str]                 => LargeBinary[Vec<u8>]      | conversion none }
         { Text[&'r str]                 => LargeUtf8[String]         | conversion none }
+        { Text[&'r str]                 => LargeBinary[Vec<u8>]      | conversion none }
         { Ntext[&'r str]                => LargeUtf8[String]         | conversion none }
+        { Ntext[&'r str]                => LargeBinary[Vec<u8>]      | conversion none }
         { Binary[&'r [u8]]              => LargeBinary[Vec<u8>]      | conversion owned }
         { Varbinary[&'r [u8]]           => LargeBinary[Vec<u8>]      | conversion none }
         { Image[&'r [u8]]               => LargeBinary[Vec<u8>]      | conversion none }
         { Numeric[Decimal]              => Float64[f64]              | conversion option }
+        { Numeric[Decimal]              => Decimal[Decimal]          | conversion auto }
+        { Numeric[Decimal]              => LargeUtf8[String]         | conversion option }
         { Decimal[Decimal]              => Float64[f64]              | conversion none }
+        { Decimal[Decimal]              => Decimal[Decimal]          | conversion none }
+        { Decimal[Decimal]              => LargeUtf8[String]         | conversion none }
         { Datetime[NaiveDateTime]       => Date64Micro[NaiveDateTimeWrapperMicro]     | conversion option }
         { Datetime2[NaiveDateTime]      => Date64Micro[NaiveDateTimeWrapperMicro]     | conversion none }
         { Smalldatetime[NaiveDateTime]  => Date64Micro[NaiveDateTimeWrapperMicro]     | conversion none }
         { Date[NaiveDate]               => Date32[NaiveDate]         | conversion auto }
         { Datetimeoffset[DateTime<Utc>] => DateTimeTzMicro[DateTimeWrapperMicro] | conversion option }
         { Uniqueidentifier[Uuid]        => LargeUtf8[String]         | conversion option }
+        { Uniqueidentifier[Uuid]        => Uuid[Uuid]                | conversion auto }
         { Time[NaiveTime]               => Time64Micro[NaiveTimeWrapperMicro]         | conversion option }
         { SmallMoney[f32]               => Float32[f32]              | conversion none }
         { Money[f64]                    => Float64[f64]              | conversion none }
@@ -107,3 +118,15 @@ impl TypeConversion<Decimal, f64> for MsSQLArrow2Transport {
             .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
     }
 }
+
+impl TypeConversion<Decimal, String> for MsSQLArrow2Transport {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<'r> TypeConversion<&'r str, Vec<u8>> for MsSQLArrow2Transport {
+    fn convert(val: &'r str) -> Vec<u8> {
+        val.as_bytes().to_vec()
+    }
+}
@@ -40,16 +40,24 @@ impl_transport!(
         { Floatn[FloatN]                => Float64[f64]              | conversion option }
         { Bit[bool]                     => Boolean[bool]             | conversion auto  }
         { Nvarchar[&'r str]             => LargeUtf8[String]         | conversion owned }
+        { Nvarchar[&'r str]             => LargeBinary[Vec<u8>]      | conversion option }
         { Varchar[&'r str]              => LargeUtf8[String]         | conversion none }
+        { Varchar[&'r str]              => LargeBinary[Vec<u8>]      | conversion none }
         { Nchar[&'r str]                => LargeUtf8[String]         | conversion none }
+        { Nchar[&'r str]                => LargeBinary[Vec<u8>]      | conversion none }
         { Char[&'r str]                 => LargeUtf8[String]         | conversion none }
+        { Char[&'r str]                 => LargeBinary[Vec<u8>]      | conversion none }
         { Text[&'r str]                 => LargeUtf8[String]         | conversion none }
+        { Text[&'r str]                 => LargeBinary[Vec<u8>]      | conversion none }
         { Ntext[&'r str]                => LargeUtf8[String]         | conversion none }
+        { Ntext[&'r str]                => LargeBinary[Vec<u8>]      | conversion none }
         { Binary[&'r [u8]]              => LargeBinary[Vec<u8>]      | conversion owned }
         { Varbinary[&'r [u8]]           => LargeBinary[Vec<u8>]      | conversion none }
         { Image[&'r [u8]]               => LargeBinary[Vec<u8>]      | conversion none }
         { Numeric[Decimal]              => Float64[f64]              | conversion option }
+        { Numeric[Decimal]              => LargeUtf8[String]         | conversion option }
         { Decimal[Decimal]              => Float64[f64]              | conversion none }
+        { Decimal[Decimal]              => LargeUtf8[String]         | conversion none }
         { Datetime[NaiveDateTime]       => Date64[NaiveDateTime]     | conversion auto }
         { Datetime2[NaiveDateTime]      => Date64[NaiveDateTime]     | conversion none }
         { Smalldatetime[NaiveDateTime]  => Date64[NaiveDateTime]     | conversion none }
@@ -86,3 +94,15 @@ impl TypeConversion<Decimal, f64> for MsSQLArrowTransport {
             .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
     }
 }
+
+impl TypeConversion<Decimal, String> for MsSQLArrowTransport {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<'r> TypeConversion<&'r str, Vec<u8>> for MsSQLArrowTransport {
+    fn convert(val: &'r str) -> Vec<u8> {
+        val.as_bytes().to_vec()
+    }
+}
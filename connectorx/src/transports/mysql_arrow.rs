@@ -40,26 +40,40 @@ impl_transport!(
     route = MySQLSource<BinaryProtocol> => ArrowDestination,
     mappings = {
         { Float[f32]                 => Float64[f64]            | conversion auto }
+        { Float[f32]                 => Float32[f32]            | conversion auto }
         { Double[f64]                => Float64[f64]            | conversion auto }
         { Tiny[i8]                   => Boolean[bool]           | conversion option }
+        { Tiny[i8]                   => Int8[i8]                | conversion auto }
         { Short[i16]                 => Int64[i64]              | conversion auto }
+        { Short[i16]                 => Int16[i16]              | conversion auto }
         { Int24[i32]                 => Int64[i64]              | conversion none }
+        { Int24[i32]                 => Int32[i32]              | conversion none }
         { Long[i32]                  => Int64[i64]              | conversion auto }
+        { Long[i32]                  => Int32[i32]              | conversion auto }
         { LongLong[i64]              => Int64[i64]              | conversion auto }
         { UTiny[u8]                  => Int64[i64]              | conversion auto }
+        { UTiny[u8]                  => UInt8[u8]               | conversion auto }
         { UShort[u16]                => Int64[i64]              | conversion auto }
+        { UShort[u16]                => UInt16[u16]             | conversion auto }
         { ULong[u32]                 => Int64[i64]              | conversion auto }
+        { ULong[u32]                 => UInt32[u32]             | conversion auto }
         { UInt24[u32]                => Int64[i64]              | conversion none }
+        { UInt24[u32]                => UInt32[u32]             | conversion none }
         { ULongLong[u64]             => Float64[f64]            | conversion auto }
         { Date[NaiveDate]            => Date32[NaiveDate]       | conversion auto }
         { Time[NaiveTime]            => Time64Micro[NaiveTimeWrapperMicro]       | conversion option }
         { Datetime[NaiveDateTime]    => Date64Micro[NaiveDateTimeWrapperMicro]   | conversion option }
         { Year[i16]                  => Int64[i64]              | conversion none}
+        { Year[i16]                  => Int16[i16]              | conversion none }
         { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro]   | conversion none }
         { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => Decimal[Decimal]        | conversion auto }
+        { Decimal[Decimal]           => LargeUtf8[String]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]       | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]    | conversion option }
         { Char[String]               => LargeUtf8[String]       | conversion none }
-        { Enum[String]               => LargeUtf8[String]       | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]    | conversion none }
+        { Enum[String]               => Dictionary[String]      | conversion none }
         { TinyBlob[Vec<u8>]          => LargeBinary[Vec<u8>]    | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion none }
         { MediumBlob[Vec<u8>]        => LargeBinary[Vec<u8>]    | conversion none }
@@ -76,26 +90,40 @@ impl_transport!(
     route = MySQLSource<TextProtocol> => ArrowDestination,
     mappings = {
         { Float[f32]                 => Float64[f64]            | conversion auto }
+        { Float[f32]                 => Float32[f32]            | conversion auto }
         { Double[f64]                => Float64[f64]            | conversion auto }
         { Tiny[i8]                   => Boolean[bool]           | conversion option }
+        { Tiny[i8]                   => Int8[i8]                | conversion auto }
         { Short[i16]                 => Int64[i64]              | conversion auto }
+        { Short[i16]                 => Int16[i16]              | conversion auto }
         { Int24[i32]                 => Int64[i64]              | conversion none }
+        { Int24[i32]                 => Int32[i32]              | conversion none }
         { Long[i32]                  => Int64[i64]              | conversion auto }
+        { Long[i32]                  => Int32[i32]              | conversion auto }
         { LongLong[i64]              => Int64[i64]              | conversion auto }
         { UTiny[u8]                  => Int64[i64]              | conversion auto }
+        { UTiny[u8]                  => UInt8[u8]               | conversion auto }
         { UShort[u16]                => Int64[i64]              | conversion auto }
+        { UShort[u16]                => UInt16[u16]             | conversion auto }
         { ULong[u32]                 => Int64[i64]              | conversion auto }
+        { ULong[u32]                 => UInt32[u32]             | conversion auto }
         { UInt24[u32]                => Int64[i64]              | conversion none }
+        { UInt24[u32]                => UInt32[u32]             | conversion none }
         { ULongLong[u64]             => Float64[f64]            | conversion auto }
         { Date[NaiveDate]            => Date32[NaiveDate]       | conversion auto }
         { Time[NaiveTime]            => Time64Micro[NaiveTimeWrapperMicro]       | conversion option }
         { Datetime[NaiveDateTime]    => Date64Micro[NaiveDateTimeWrapperMicro]   | conversion option }
         { Year[i16]                  => Int64[i64]              | conversion none}
+        { Year[i16]                  => Int16[i16]              | conversion none }
         { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro]   | conversion none }
         { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => Decimal[Decimal]        | conversion auto }
+        { Decimal[Decimal]           => LargeUtf8[String]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]       | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]    | conversion option }
         { Char[String]               => LargeUtf8[String]       | conversion none }
-        { Enum[String]               => LargeUtf8[String]       | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]    | conversion none }
+        { Enum[String]               => Dictionary[String]      | conversion none }
         { TinyBlob[Vec<u8>]          => LargeBinary[Vec<u8>]    | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion none }
         { MediumBlob[Vec<u8>]        => LargeBinary[Vec<u8>]    | conversion none }
@@ -124,6 +152,18 @@ impl<P> TypeConversion<Decimal, f64> for MySQLArrowTransport<P> {
     }
 }
 
+impl<P> TypeConversion<Decimal, String> for MySQLArrowTransport<P> {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<P> TypeConversion<String, Vec<u8>> for MySQLArrowTransport<P> {
+    fn convert(val: String) -> Vec<u8> {
+        val.into_bytes()
+    }
+}
+
 impl<P> TypeConversion<Value, String> for MySQLArrowTransport<P> {
     fn convert(val: Value) -> String {
         to_string(&val).unwrap()
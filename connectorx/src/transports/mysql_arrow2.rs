@@ -57,9 +57,13 @@ impl_transport!(
         { Year[i16]                  => Int64[i64]              | conversion none}
         { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro]   | conversion none }
         { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => Decimal[Decimal]        | conversion auto }
+        { Decimal[Decimal]           => LargeUtf8[String]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]       | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]    | conversion option }
         { Char[String]               => LargeUtf8[String]       | conversion none }
-        { Enum[String]               => LargeUtf8[String]       | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]    | conversion none }
+        { Enum[String]               => Dictionary[String]      | conversion none }
         { Json[Value]                => LargeUtf8[String]       | conversion option }
         { TinyBlob[Vec<u8>]          => LargeBinary[Vec<u8>]    | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion none }
@@ -93,9 +97,13 @@ impl_transport!(
         { Year[i16]                  => Int64[i64]              | conversion none}
         { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro]   | conversion none }
         { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => Decimal[Decimal]        | conversion auto }
+        { Decimal[Decimal]           => LargeUtf8[String]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]       | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]    | conversion option }
         { Char[String]               => LargeUtf8[String]       | conversion none }
-        { Enum[String]               => LargeUtf8[String]       | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]    | conversion none }
+        { Enum[String]               => Dictionary[String]      | conversion none }
         { Json[Value]                => LargeUtf8[String]       | conversion option }
         { TinyBlob[Vec<u8>]          => LargeBinary[Vec<u8>]    | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion none }
@@ -124,6 +132,18 @@ impl<P> TypeConversion<Decimal, f64> for MySQLArrow2Transport<P> {
     }
 }
 
+impl<P> TypeConversion<Decimal, String> for MySQLArrow2Transport<P> {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<P> TypeConversion<String, Vec<u8>> for MySQLArrow2Transport<P> {
+    fn convert(val: String) -> Vec<u8> {
+        val.into_bytes()
+    }
+}
+
 impl<P> TypeConversion<Value, String> for MySQLArrow2Transport<P> {
     fn convert(val: Value) -> String {
         to_string(&val).unwrap()
@@ -56,9 +56,12 @@ impl_transport!(
         { Year[i16]                  => Int64[i64]              | conversion none}
         { Timestamp[NaiveDateTime]   => Date64[NaiveDateTime]   | conversion none }
         { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => LargeUtf8[String]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]       | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]    | conversion option }
         { Char[String]               => LargeUtf8[String]       | conversion none }
-        { Enum[String]               => LargeUtf8[String]       | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]    | conversion none }
+        { Enum[String]               => Dictionary[String]      | conversion none }
         { TinyBlob[Vec<u8>]          => LargeBinary[Vec<u8>]    | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion none }
         { MediumBlob[Vec<u8>]        => LargeBinary[Vec<u8>]    | conversion none }
@@ -92,9 +95,12 @@ impl_transport!(
         { Year[i16]                  => Int64[i64]              | conversion none}
         { Timestamp[NaiveDateTime]   => Date64[NaiveDateTime]   | conversion none }
         { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => LargeUtf8[String]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]       | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]    | conversion option }
         { Char[String]               => LargeUtf8[String]       | conversion none }
-        { Enum[String]               => LargeUtf8[String]       | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]    | conversion none }
+        { Enum[String]               => Dictionary[String]      | conversion none }
         { TinyBlob[Vec<u8>]          => LargeBinary[Vec<u8>]    | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion none }
         { MediumBlob[Vec<u8>]        => LargeBinary[Vec<u8>]    | conversion none }
@@ -111,6 +117,18 @@ impl<P> TypeConversion<Decimal, f64> for MySQLArrowTransport<P> {
     }
 }
 
+impl<P> TypeConversion<Decimal, String> for MySQLArrowTransport<P> {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<P> TypeConversion<String, Vec<u8>> for MySQLArrowTransport<P> {
+    fn convert(val: String) -> Vec<u8> {
+        val.into_bytes()
+    }
+}
+
 impl<P> TypeConversion<Value, String> for MySQLArrowTransport<P> {
     fn convert(val: Value) -> String {
         to_string(&val).unwrap()
@@ -8,6 +8,8 @@ use crate::{
     typesystem::TypeConversion,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -31,16 +33,24 @@ impl_transport!(
     route = OracleSource => ArrowDestination,
     mappings = {
         { NumFloat[f64]              => Float64[f64]               | conversion auto }
+        { NumDecimal[Decimal]        => Float64[f64]               | conversion option }
+        { NumDecimal[Decimal]        => Decimal[Decimal]           | conversion auto }
+        { NumDecimal[Decimal]        => LargeUtf8[String]          | conversion option }
         { Float[f64]                 => Float64[f64]               | conversion none }
         { BinaryFloat[f64]           => Float64[f64]               | conversion none }
         { BinaryDouble[f64]          => Float64[f64]               | conversion none }
         { NumInt[i64]                => Int64[i64]                 | conversion auto }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]       | conversion auto }
         { Clob[String]               => LargeUtf8[String]          | conversion none }
+        { Clob[String]               => LargeBinary[Vec<u8>]       | conversion option }
         { VarChar[String]            => LargeUtf8[String]          | conversion auto }
+        { VarChar[String]            => LargeBinary[Vec<u8>]       | conversion none }
         { Char[String]               => LargeUtf8[String]          | conversion none }
+        { Char[String]               => LargeBinary[Vec<u8>]       | conversion none }
         { NVarChar[String]           => LargeUtf8[String]          | conversion none }
+        { NVarChar[String]           => LargeBinary[Vec<u8>]       | conversion none }
         { NChar[String]              => LargeUtf8[String]          | conversion none }
+        { NChar[String]              => LargeBinary[Vec<u8>]       | conversion none }
         { Date[NaiveDateTime]        => Date64Micro[NaiveDateTimeWrapperMicro]  | conversion option }
         { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro]  | conversion none }
         { TimestampNano[NaiveDateTime]   => Date64[NaiveDateTime]      | conversion auto }
@@ -60,3 +70,22 @@ impl TypeConversion<DateTime<Utc>, DateTimeWrapperMicro> for OracleArrowTranspor
         DateTimeWrapperMicro(val)
     }
 }
+
+impl TypeConversion<Decimal, f64> for OracleArrowTransport {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
+
+impl TypeConversion<Decimal, String> for OracleArrowTransport {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl TypeConversion<String, Vec<u8>> for OracleArrowTransport {
+    fn convert(val: String) -> Vec<u8> {
+        val.into_bytes()
+    }
+}
@@ -0,0 +1,71 @@
+//! Transport from Parquet Source to Arrow Destination.
+
+use crate::{
+    destinations::arrow::{
+        typesystem::{ArrowTypeSystem, DateTimeWrapperMicro, NaiveDateTimeWrapperMicro},
+        ArrowDestination, ArrowDestinationError,
+    },
+    impl_transport,
+    sources::parquet::{ParquetSource, ParquetSourceError, ParquetTypeSystem},
+    typesystem::TypeConversion,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use num_traits::ToPrimitive;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ParquetArrowTransportError {
+    #[error(transparent)]
+    Source(#[from] ParquetSourceError),
+
+    #[error(transparent)]
+    Destination(#[from] ArrowDestinationError),
+
+    #[error(transparent)]
+    ConnectorX(#[from] crate::errors::ConnectorXError),
+}
+
+/// Convert Parquet data types to Arrow data types.
+pub struct ParquetArrowTransport;
+
+impl_transport!(
+    name = ParquetArrowTransport,
+    error = ParquetArrowTransportError,
+    systems = ParquetTypeSystem => ArrowTypeSystem,
+    route = ParquetSource => ArrowDestination,
+    mappings = {
+        { Boolean[bool]              => Boolean[bool]                          | conversion auto }
+        { Int32[i32]                 => Int32[i32]                             | conversion auto }
+        { Int64[i64]                 => Int64[i64]                             | conversion auto }
+        { UInt64[u64]                => UInt64[u64]                            | conversion auto }
+        { Float[f32]                 => Float32[f32]                           | conversion auto }
+        { Double[f64]                => Float64[f64]                           | conversion auto }
+        { Decimal[Decimal]           => Float64[f64]                           | conversion option }
+        { Decimal[Decimal]           => Decimal[Decimal]                       | conversion auto }
+        { Utf8[String]               => LargeUtf8[String]                      | conversion auto }
+        { Binary[Vec<u8>]            => LargeBinary[Vec<u8>]                   | conversion auto }
+        { Date[NaiveDate]            => Date32[NaiveDate]                      | conversion auto }
+        { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro] | conversion option }
+        { TimestampTz[DateTime<Utc>] => DateTimeTzMicro[DateTimeWrapperMicro]  | conversion option }
+    }
+);
+
+impl TypeConversion<Decimal, f64> for ParquetArrowTransport {
+    fn convert(val: Decimal) -> f64 {
+        val.to_f64()
+            .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", val))
+    }
+}
+
+impl TypeConversion<NaiveDateTime, NaiveDateTimeWrapperMicro> for ParquetArrowTransport {
+    fn convert(val: NaiveDateTime) -> NaiveDateTimeWrapperMicro {
+        NaiveDateTimeWrapperMicro(val)
+    }
+}
+
+impl TypeConversion<DateTime<Utc>, DateTimeWrapperMicro> for ParquetArrowTransport {
+    fn convert(val: DateTime<Utc>) -> DateTimeWrapperMicro {
+        DateTimeWrapperMicro(val)
+    }
+}
@@ -2,13 +2,14 @@
 
 use crate::destinations::arrow::{
     typesystem::{
-        ArrowTypeSystem, DateTimeWrapperMicro, NaiveDateTimeWrapperMicro, NaiveTimeWrapperMicro,
+        ArrowTypeSystem, DateTimeWrapperMicro, IntervalMonthDayNano, NaiveDateTimeWrapperMicro,
+        NaiveTimeWrapperMicro,
     },
     ArrowDestination, ArrowDestinationError,
 };
 use crate::sources::postgres::{
-    BinaryProtocol, CSVProtocol, CursorProtocol, PostgresSource, PostgresSourceError,
-    PostgresTypeSystem, SimpleProtocol,
+    BinaryProtocol, CSVProtocol, CursorProtocol, PgInterval, PgMoney, PostgresSource,
+    PostgresSourceError, PostgresTypeSystem, SimpleProtocol,
 };
 use crate::typesystem::TypeConversion;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
@@ -16,7 +17,8 @@ use num_traits::ToPrimitive;
 use postgres::NoTls;
 use postgres_openssl::MakeTlsConnector;
 use rust_decimal::Decimal;
-use serde_json::Value;
+use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::marker::PhantomData;
 use thiserror::Error;
 use uuid::Uuid;
@@ -45,25 +47,60 @@ macro_rules! impl_postgres_transport {
             route = PostgresSource<$proto, $tls> => ArrowDestination,
             mappings = {
                 { Float4[f32]                => Float64[f64]              | conversion auto }
+                { Float4[f32]                => Float32[f32]              | conversion auto }
                 { Float8[f64]                => Float64[f64]              | conversion auto }
                 { Numeric[Decimal]           => Float64[f64]              | conversion option }
+                { Numeric[Decimal]           => Decimal[Decimal]          | conversion auto }
+                { Numeric[Decimal]           => LargeUtf8[String]         | conversion option }
                 { Int2[i16]                  => Int64[i64]                | conversion auto }
+                { Int2[i16]                  => Int16[i16]                | conversion auto }
                 { Int4[i32]                  => Int64[i64]                | conversion auto }
+                { Int4[i32]                  => Int32[i32]                | conversion auto }
                 { Int8[i64]                  => Int64[i64]                | conversion auto }
                 { Bool[bool]                 => Boolean[bool]             | conversion auto  }
                 { Text[&'r str]              => LargeUtf8[String]         | conversion owned }
+                { Text[&'r str]              => LargeBinary[Vec<u8>]      | conversion option }
                 { BpChar[&'r str]            => LargeUtf8[String]         | conversion none }
+                { BpChar[&'r str]            => LargeBinary[Vec<u8>]      | conversion none }
                 { VarChar[&'r str]           => LargeUtf8[String]         | conversion none }
+                { VarChar[&'r str]           => LargeBinary[Vec<u8>]      | conversion none }
                 { Name[&'r str]              => LargeUtf8[String]         | conversion none }
+                { Enum[&'r str]              => Dictionary[String]        | conversion none }
                 { Timestamp[NaiveDateTime]   => Date64Micro[NaiveDateTimeWrapperMicro] | conversion option }
                 { Date[NaiveDate]            => Date32[NaiveDate]         | conversion auto }
                 { Time[NaiveTime]            => Time64Micro[NaiveTimeWrapperMicro]     | conversion option }
                 { TimestampTz[DateTime<Utc>] => DateTimeTzMicro[DateTimeWrapperMicro]  | conversion option }
                 { UUID[Uuid]                 => LargeUtf8[String]         | conversion option }
+                { UUID[Uuid]                 => Uuid[Uuid]                | conversion auto }
                 { Char[&'r str]              => LargeUtf8[String]         | conversion none }
+                { Char[&'r str]              => LargeBinary[Vec<u8>]      | conversion none }
                 { ByteA[Vec<u8>]             => LargeBinary[Vec<u8>]      | conversion auto }
                 { JSON[Value]                => LargeUtf8[String]         | conversion option }
                 { JSONB[Value]               => LargeUtf8[String]         | conversion none }
+                { JSON[Value]                => Json[Value]               | conversion auto }
+                { JSONB[Value]               => Json[Value]               | conversion none }
+                { HSTORE[HashMap<String, Option<String>>] => LargeUtf8[String] | conversion option }
+                { HSTORE[HashMap<String, Option<String>>] => Map[HashMap<String, Option<String>>] | conversion auto }
+                { BoolArray[Vec<bool>]       => BoolArray[Vec<bool>]      | conversion auto_vec }
+                { Int2Array[Vec<i16>]        => Int64Array[Vec<i64>]      | conversion auto_vec }
+                { Int4Array[Vec<i32>]        => Int64Array[Vec<i64>]      | conversion auto_vec }
+                { Int8Array[Vec<i64>]        => Int64Array[Vec<i64>]      | conversion auto }
+                { Float4Array[Vec<f32>]      => Float64Array[Vec<f64>]    | conversion auto_vec }
+                { Float8Array[Vec<f64>]      => Float64Array[Vec<f64>]    | conversion auto }
+                { NumericArray[Vec<Decimal>] => Float64Array[Vec<f64>]    | conversion option }
+                { VarcharArray[Vec<String>]  => Utf8Array[Vec<String>]    | conversion none }
+                { TextArray[Vec<String>]     => Utf8Array[Vec<String>]    | conversion auto }
+                { Interval[PgInterval]       => Interval[IntervalMonthDayNano] | conversion option }
+                { Money[PgMoney]             => Float64[f64]              | conversion option }
+                { Inet[String]               => LargeUtf8[String]         | conversion auto }
+                { Cidr[String]               => LargeUtf8[String]         | conversion none }
+                { MacAddr[String]            => LargeUtf8[String]         | conversion none }
+                { Bit[String]                => LargeUtf8[String]         | conversion none }
+                { VarBit[String]             => LargeUtf8[String]         | conversion none }
+                { Xml[String]                => LargeUtf8[String]         | conversion none }
+                { Range[String]              => LargeUtf8[String]         | conversion none }
+                { Composite[String]          => LargeUtf8[String]         | conversion none }
+                { Geometric[String]          => LargeUtf8[String]         | conversion none }
             }
         );
     }
@@ -111,8 +148,57 @@ impl<P, C> TypeConversion<Decimal, f64> for PostgresArrowTransport<P, C> {
     }
 }
 
+impl<P, C> TypeConversion<Decimal, String> for PostgresArrowTransport<P, C> {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<'r, P, C> TypeConversion<&'r str, Vec<u8>> for PostgresArrowTransport<P, C> {
+    fn convert(val: &'r str) -> Vec<u8> {
+        val.as_bytes().to_vec()
+    }
+}
+
+impl<P, C> TypeConversion<Vec<Decimal>, Vec<f64>> for PostgresArrowTransport<P, C> {
+    fn convert(val: Vec<Decimal>) -> Vec<f64> {
+        val.into_iter()
+            .map(|v| {
+                v.to_f64()
+                    .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", v))
+            })
+            .collect()
+    }
+}
+
 impl<P, C> TypeConversion<Value, String> for PostgresArrowTransport<P, C> {
     fn convert(val: Value) -> String {
         val.to_string()
     }
 }
+
+impl<P, C> TypeConversion<HashMap<String, Option<String>>, String>
+    for PostgresArrowTransport<P, C>
+{
+    fn convert(val: HashMap<String, Option<String>>) -> String {
+        json!(val).to_string()
+    }
+}
+
+impl<P, C> TypeConversion<PgMoney, f64> for PostgresArrowTransport<P, C> {
+    fn convert(val: PgMoney) -> f64 {
+        val.to_decimal()
+            .to_f64()
+            .unwrap_or_else(|| panic!("cannot convert money {:?} to float64", val))
+    }
+}
+
+impl<P, C> TypeConversion<PgInterval, IntervalMonthDayNano> for PostgresArrowTransport<P, C> {
+    fn convert(val: PgInterval) -> IntervalMonthDayNano {
+        IntervalMonthDayNano {
+            months: val.months,
+            days: val.days,
+            nanoseconds: val.microseconds * 1000,
+        }
+    }
+}
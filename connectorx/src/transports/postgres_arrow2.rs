@@ -7,10 +7,11 @@ use crate::destinations::arrow2::{
     Arrow2Destination, Arrow2DestinationError,
 };
 use crate::sources::postgres::{
-    BinaryProtocol, CSVProtocol, CursorProtocol, PostgresSource, PostgresSourceError,
-    PostgresTypeSystem, SimpleProtocol,
+    BinaryProtocol, CSVProtocol, CursorProtocol, PgInterval, PgMoney, PostgresSource,
+    PostgresSourceError, PostgresTypeSystem, SimpleProtocol,
 };
 use crate::typesystem::TypeConversion;
+use arrow2::types::months_days_ns;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use num_traits::ToPrimitive;
 use postgres::NoTls;
@@ -47,21 +48,28 @@ macro_rules! impl_postgres_transport {
                 { Float4[f32]                       => Float32[f32]                | conversion auto }
                 { Float8[f64]                       => Float64[f64]                | conversion auto }
                 { Numeric[Decimal]                  => Float64[f64]                | conversion option }
+                { Numeric[Decimal]                  => Decimal[Decimal]            | conversion auto }
+                { Numeric[Decimal]                  => LargeUtf8[String]           | conversion option }
                 { Int2[i16]                         => Int32[i32]                  | conversion auto }
                 { Int4[i32]                         => Int32[i32]                  | conversion auto }
                 { Int8[i64]                         => Int64[i64]                  | conversion auto }
                 { Bool[bool]                        => Boolean[bool]               | conversion auto  }
                 { Text[&'r str]                     => LargeUtf8[String]           | conversion owned }
+                { Text[&'r str]                     => LargeBinary[Vec<u8>]        | conversion option }
                 { BpChar[&'r str]                   => LargeUtf8[String]           | conversion none }
+                { BpChar[&'r str]                   => LargeBinary[Vec<u8>]        | conversion none }
                 { VarChar[&'r str]                  => LargeUtf8[String]           | conversion none }
-                { Enum[&'r str]                     => LargeUtf8[String]           | conversion none }
+                { VarChar[&'r str]                  => LargeBinary[Vec<u8>]        | conversion none }
+                { Enum[&'r str]                     => Dictionary[String]          | conversion none }
                 { Name[&'r str]                     => LargeUtf8[String]           | conversion none }
                 { Timestamp[NaiveDateTime]          => Date64Micro[NaiveDateTimeWrapperMicro] | conversion option }
                 { Date[NaiveDate]                   => Date32[NaiveDate]           | conversion auto }
                 { Time[NaiveTime]                   => Time64Micro[NaiveTimeWrapperMicro]     | conversion option }
                 { TimestampTz[DateTime<Utc>]        => DateTimeTzMicro[DateTimeWrapperMicro]  | conversion option }
                 { UUID[Uuid]                        => LargeUtf8[String]           | conversion option }
+                { UUID[Uuid]                        => Uuid[Uuid]                  | conversion auto }
                 { Char[&'r str]                     => LargeUtf8[String]           | conversion none }
+                { Char[&'r str]                     => LargeBinary[Vec<u8>]        | conversion none }
                 { ByteA[Vec<u8>]                    => LargeBinary[Vec<u8>]        | conversion auto }
                 { JSON[Value]                       => LargeUtf8[String]           | conversion option }
                 { JSONB[Value]                      => LargeUtf8[String]           | conversion none }
@@ -74,6 +82,17 @@ macro_rules! impl_postgres_transport {
                 { NumericArray[Vec<Decimal>]        => Float64Array[Vec<f64>]      | conversion option }
                 { VarcharArray[Vec<String>]        => Utf8Array[Vec<String>]      | conversion none }
                 { TextArray[Vec<String>]        => Utf8Array[Vec<String>]      | conversion auto }
+                { Interval[PgInterval]              => Interval[months_days_ns]    | conversion option }
+                { Money[PgMoney]                    => Float64[f64]                | conversion option }
+                { Inet[String]                      => LargeUtf8[String]           | conversion auto }
+                { Cidr[String]                      => LargeUtf8[String]           | conversion none }
+                { MacAddr[String]                   => LargeUtf8[String]           | conversion none }
+                { Bit[String]                       => LargeUtf8[String]           | conversion none }
+                { VarBit[String]                    => LargeUtf8[String]           | conversion none }
+                { Xml[String]                       => LargeUtf8[String]           | conversion none }
+                { Range[String]                     => LargeUtf8[String]           | conversion none }
+                { Composite[String]                 => LargeUtf8[String]           | conversion none }
+                { Geometric[String]                 => LargeUtf8[String]           | conversion none }
 
             }
         );
@@ -122,6 +141,18 @@ impl<P, C> TypeConversion<Decimal, f64> for PostgresArrow2Transport<P, C> {
     }
 }
 
+impl<P, C> TypeConversion<Decimal, String> for PostgresArrow2Transport<P, C> {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<'r, P, C> TypeConversion<&'r str, Vec<u8>> for PostgresArrow2Transport<P, C> {
+    fn convert(val: &'r str) -> Vec<u8> {
+        val.as_bytes().to_vec()
+    }
+}
+
 impl<P, C> TypeConversion<Vec<Decimal>, Vec<f64>> for PostgresArrow2Transport<P, C> {
     fn convert(val: Vec<Decimal>) -> Vec<f64> {
         val.into_iter()
@@ -138,3 +169,17 @@ impl<P, C> TypeConversion<Value, String> for PostgresArrow2Transport<P, C> {
         val.to_string()
     }
 }
+
+impl<P, C> TypeConversion<PgMoney, f64> for PostgresArrow2Transport<P, C> {
+    fn convert(val: PgMoney) -> f64 {
+        val.to_decimal()
+            .to_f64()
+            .unwrap_or_else(|| panic!("cannot convert money {:?} to float64", val))
+    }
+}
+
+impl<P, C> TypeConversion<PgInterval, months_days_ns> for PostgresArrow2Transport<P, C> {
+    fn convert(val: PgInterval) -> months_days_ns {
+        months_days_ns::new(val.months, val.days, val.microseconds * 1000)
+    }
+}
@@ -4,8 +4,8 @@ use crate::destinations::arrowstream::{
     typesystem::ArrowTypeSystem, ArrowDestination, ArrowDestinationError,
 };
 use crate::sources::postgres::{
-    BinaryProtocol, CSVProtocol, CursorProtocol, PostgresSource, PostgresSourceError,
-    PostgresTypeSystem, SimpleProtocol,
+    BinaryProtocol, CSVProtocol, CursorProtocol, PgInterval, PgMoney, PostgresSource,
+    PostgresSourceError, PostgresTypeSystem, SimpleProtocol,
 };
 use crate::typesystem::TypeConversion;
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
@@ -44,23 +44,49 @@ macro_rules! impl_postgres_transport {
                 { Float4[f32]                => Float64[f64]              | conversion auto }
                 { Float8[f64]                => Float64[f64]              | conversion auto }
                 { Numeric[Decimal]           => Float64[f64]              | conversion option }
+                { Numeric[Decimal]           => LargeUtf8[String]         | conversion option }
                 { Int2[i16]                  => Int64[i64]                | conversion auto }
                 { Int4[i32]                  => Int64[i64]                | conversion auto }
                 { Int8[i64]                  => Int64[i64]                | conversion auto }
                 { Bool[bool]                 => Boolean[bool]             | conversion auto  }
                 { Text[&'r str]              => LargeUtf8[String]         | conversion owned }
+                { Text[&'r str]              => LargeBinary[Vec<u8>]      | conversion option }
                 { BpChar[&'r str]            => LargeUtf8[String]         | conversion none }
+                { BpChar[&'r str]            => LargeBinary[Vec<u8>]      | conversion none }
                 { VarChar[&'r str]           => LargeUtf8[String]         | conversion none }
+                { VarChar[&'r str]           => LargeBinary[Vec<u8>]      | conversion none }
                 { Name[&'r str]              => LargeUtf8[String]         | conversion none }
+                { Enum[&'r str]              => Dictionary[String]        | conversion none }
                 { Timestamp[NaiveDateTime]   => Date64[NaiveDateTime]     | conversion auto }
                 { Date[NaiveDate]            => Date32[NaiveDate]         | conversion auto }
                 { Time[NaiveTime]            => Time64[NaiveTime]         | conversion auto }
                 { TimestampTz[DateTime<Utc>] => DateTimeTz[DateTime<Utc>] | conversion auto }
                 { UUID[Uuid]                 => LargeUtf8[String]         | conversion option }
                 { Char[&'r str]              => LargeUtf8[String]         | conversion none }
+                { Char[&'r str]              => LargeBinary[Vec<u8>]      | conversion none }
                 { ByteA[Vec<u8>]             => LargeBinary[Vec<u8>]      | conversion auto }
                 { JSON[Value]                => LargeUtf8[String]         | conversion option }
                 { JSONB[Value]               => LargeUtf8[String]         | conversion none }
+                { BoolArray[Vec<bool>]       => BoolArray[Vec<bool>]      | conversion auto_vec }
+                { Int2Array[Vec<i16>]        => Int64Array[Vec<i64>]      | conversion auto_vec }
+                { Int4Array[Vec<i32>]        => Int64Array[Vec<i64>]      | conversion auto_vec }
+                { Int8Array[Vec<i64>]        => Int64Array[Vec<i64>]      | conversion auto }
+                { Float4Array[Vec<f32>]      => Float64Array[Vec<f64>]    | conversion auto_vec }
+                { Float8Array[Vec<f64>]      => Float64Array[Vec<f64>]    | conversion auto }
+                { NumericArray[Vec<Decimal>] => Float64Array[Vec<f64>]    | conversion option }
+                { VarcharArray[Vec<String>]  => Utf8Array[Vec<String>]    | conversion none }
+                { TextArray[Vec<String>]     => Utf8Array[Vec<String>]    | conversion auto }
+                { Interval[PgInterval]       => LargeUtf8[String]         | conversion option }
+                { Money[PgMoney]             => Float64[f64]              | conversion option }
+                { Inet[String]               => LargeUtf8[String]         | conversion auto }
+                { Cidr[String]               => LargeUtf8[String]         | conversion none }
+                { MacAddr[String]            => LargeUtf8[String]         | conversion none }
+                { Bit[String]                => LargeUtf8[String]         | conversion none }
+                { VarBit[String]             => LargeUtf8[String]         | conversion none }
+                { Xml[String]                => LargeUtf8[String]         | conversion none }
+                { Range[String]              => LargeUtf8[String]         | conversion none }
+                { Composite[String]          => LargeUtf8[String]         | conversion none }
+                { Geometric[String]          => LargeUtf8[String]         | conversion none }
             }
         );
     }
@@ -88,8 +114,45 @@ impl<P, C> TypeConversion<Decimal, f64> for PostgresArrowTransport<P, C> {
     }
 }
 
+impl<P, C> TypeConversion<Decimal, String> for PostgresArrowTransport<P, C> {
+    fn convert(val: Decimal) -> String {
+        val.to_string()
+    }
+}
+
+impl<'r, P, C> TypeConversion<&'r str, Vec<u8>> for PostgresArrowTransport<P, C> {
+    fn convert(val: &'r str) -> Vec<u8> {
+        val.as_bytes().to_vec()
+    }
+}
+
+impl<P, C> TypeConversion<Vec<Decimal>, Vec<f64>> for PostgresArrowTransport<P, C> {
+    fn convert(val: Vec<Decimal>) -> Vec<f64> {
+        val.into_iter()
+            .map(|v| {
+                v.to_f64()
+                    .unwrap_or_else(|| panic!("cannot convert decimal {:?} to float64", v))
+            })
+            .collect()
+    }
+}
+
 impl<P, C> TypeConversion<Value, String> for PostgresArrowTransport<P, C> {
     fn convert(val: Value) -> String {
         val.to_string()
     }
 }
+
+impl<P, C> TypeConversion<PgMoney, f64> for PostgresArrowTransport<P, C> {
+    fn convert(val: PgMoney) -> f64 {
+        val.to_decimal()
+            .to_f64()
+            .unwrap_or_else(|| panic!("cannot convert money {:?} to float64", val))
+    }
+}
+
+impl<P, C> TypeConversion<PgInterval, String> for PostgresArrowTransport<P, C> {
+    fn convert(val: PgInterval) -> String {
+        val.to_string()
+    }
+}
@@ -33,7 +33,9 @@ impl_transport!(
         { Bool[bool]                 => Boolean[bool]           | conversion auto }
         { Int8[i64]                  => Int64[i64]              | conversion auto }
         { Int4[i32]                  => Int64[i64]              | conversion auto }
+        { Int4[i32]                  => Int32[i32]              | conversion auto }
         { Int2[i16]                  => Int64[i64]              | conversion auto }
+        { Int2[i16]                  => Int16[i16]              | conversion auto }
         { Real[f64]                  => Float64[f64]            | conversion auto }
         { Text[Box<str>]             => LargeUtf8[String]       | conversion option }
         { Blob[Vec<u8>]              => LargeBinary[Vec<u8>]    | conversion auto }
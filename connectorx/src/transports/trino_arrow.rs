@@ -11,6 +11,7 @@ use num_traits::ToPrimitive;
 use rust_decimal::Decimal;
 use serde_json::{to_string, Value};
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Error, Debug)]
 pub enum TrinoArrowTransportError {
@@ -39,12 +40,20 @@ impl_transport!(
         { Boolean[bool]              => Boolean[bool]           | conversion auto }
         { Bigint[i32]                => Int64[i64]              | conversion auto }
         { Integer[i32]               => Int64[i64]              | conversion none }
+        { Integer[i32]               => Int32[i32]              | conversion auto }
         { Smallint[i16]              => Int64[i64]              | conversion auto }
+        { Smallint[i16]              => Int16[i16]              | conversion auto }
         { Tinyint[i8]                => Int64[i64]              | conversion auto }
+        { Tinyint[i8]                => Int8[i8]                | conversion auto }
         { Double[f64]                => Float64[f64]            | conversion auto }
         { Real[f32]                  => Float64[f64]            | conversion auto }
+        { Real[f32]                  => Float32[f32]            | conversion auto }
+        { Decimal[Decimal]           => Float64[f64]            | conversion option }
+        { Decimal[Decimal]           => Decimal[Decimal]        | conversion auto }
         { Varchar[String]            => LargeUtf8[String]       | conversion auto }
         { Char[String]               => LargeUtf8[String]       | conversion none }
+        { Uuid[Uuid]                 => LargeUtf8[String]       | conversion option }
+        { Uuid[Uuid]                 => Uuid[Uuid]              | conversion auto }
     }
 );
 
@@ -60,3 +69,9 @@ impl TypeConversion<Value, String> for TrinoArrowTransport {
         to_string(&val).unwrap()
     }
 }
+
+impl TypeConversion<Uuid, String> for TrinoArrowTransport {
+    fn convert(val: Uuid) -> String {
+        val.to_string()
+    }
+}
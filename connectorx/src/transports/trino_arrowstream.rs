@@ -13,6 +13,7 @@ use num_traits::ToPrimitive;
 use rust_decimal::Decimal;
 use serde_json::{to_string, Value};
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Error, Debug)]
 pub enum TrinoArrowTransportError {
@@ -45,8 +46,10 @@ impl_transport!(
         { Tinyint[i8]                => Int64[i64]              | conversion auto }
         { Double[f64]                => Float64[f64]            | conversion auto }
         { Real[f32]                  => Float64[f64]            | conversion auto }
+        { Decimal[Decimal]           => Float64[f64]            | conversion option }
         { Varchar[String]            => LargeUtf8[String]       | conversion auto }
         { Char[String]               => LargeUtf8[String]       | conversion none }
+        { Uuid[Uuid]                 => LargeUtf8[String]       | conversion option }
     }
 );
 
@@ -62,3 +65,9 @@ impl TypeConversion<Value, String> for TrinoArrowTransport {
         to_string(&val).unwrap()
     }
 }
+
+impl TypeConversion<Uuid, String> for TrinoArrowTransport {
+    fn convert(val: Uuid) -> String {
+        val.to_string()
+    }
+}
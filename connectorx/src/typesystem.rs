@@ -12,7 +12,7 @@ use crate::sources::{PartitionParser, Produce, Source, SourcePartition};
 /// `TypeSystem` describes all the types a source or destination support
 /// using enum variants.
 /// The variant can be used to type check with a static type `T` through the `check` method.
-pub trait TypeSystem: Copy + Clone + Send + Sync {
+pub trait TypeSystem: Copy + Clone + Send + Sync + std::fmt::Debug {
     /// Check whether T is the same type as defined by self.
     fn check<T: TypeAssoc<Self>>(self) -> CXResult<()> {
         T::check(self)
@@ -80,6 +80,24 @@ pub trait Transport {
     /// type system TSD.
     fn convert_typesystem(ts: Self::TSS) -> CXResult<Self::TSD>;
 
+    /// convert_typesystem_to converts the source type TSS to the destination type
+    /// `ts2` requested by the user instead of the default one, keeping the nullability
+    /// of `ts1`. It fails if there is no rule between the two types, which is always the
+    /// case for transports implemented without [`impl_transport!`] by default.
+    fn convert_typesystem_to(ts1: Self::TSS, ts2: Self::TSD) -> CXResult<Self::TSD> {
+        Err(ConnectorXError::NoConversionRule(
+            format!("{:?}", ts1),
+            format!("{:?}", ts2),
+        ))
+    }
+
+    /// can_convert tells whether there is a rule to convert the source type TSS
+    /// to the destination type TSD. Transports implemented without
+    /// [`impl_transport!`] convert nothing besides `convert_typesystem` by default.
+    fn can_convert(_ts1: Self::TSS, _ts2: Self::TSD) -> bool {
+        false
+    }
+
     /// convert_type convert the type T1 associated with the source type system
     /// TSS to a type T2 which is associated with the destination type system TSD.
     fn convert_type<T1, T2>(val: T1) -> T2
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+use rust_decimal::Decimal;
 use std::ops::{Deref, DerefMut};
 
 pub struct DummyBox<T>(pub T);
@@ -15,3 +17,25 @@ impl<T> DerefMut for DummyBox<T> {
         &mut self.0
     }
 }
+
+/// Represent `value` as an integer of `scale` decimal digits after the point. Returns `None`
+/// if it needs more than `precision` digits, or more than `scale` digits after the point.
+#[cfg(any(feature = "dst_arrow", feature = "dst_arrow2"))]
+pub fn decimal_to_i128(value: Decimal, precision: u8, scale: i8) -> Option<i128> {
+    let mantissa = value.mantissa();
+    let shift = scale as i32 - value.scale() as i32;
+    let scaled = if shift >= 0 {
+        mantissa.checked_mul(10i128.checked_pow(shift as u32)?)?
+    } else {
+        // only trailing zeros may be dropped
+        let divisor = 10i128.checked_pow(-shift as u32)?;
+        if mantissa % divisor != 0 {
+            return None;
+        }
+        mantissa / divisor
+    };
+    if scaled.abs() >= 10i128.checked_pow(precision as u32)? {
+        return None;
+    }
+    Some(scaled)
+}
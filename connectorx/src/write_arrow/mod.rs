@@ -0,0 +1,322 @@
+//! Write Arrow record batches into a table of a database, the reverse of [`get_arrow`](crate::get_arrow::get_arrow).
+
+#[cfg(feature = "src_mssql")]
+mod mssql;
+#[cfg(feature = "src_mysql")]
+mod mysql;
+#[cfg(feature = "src_postgres")]
+mod postgres;
+#[cfg(feature = "src_sqlite")]
+mod sqlite;
+
+#[cfg(any(feature = "src_postgres", feature = "src_mssql"))]
+use crate::constants::{ARROW_EXTENSION_NAME_KEY, UUID_EXTENSION_NAME};
+use crate::errors::{ConnectorXError, ConnectorXOutError, Result};
+use crate::source_router::{SourceConn, SourceType};
+use anyhow::anyhow;
+use arrow::{
+    array::{Array, ArrayRef, AsArray},
+    compute::cast,
+    datatypes::*,
+    record_batch::RecordBatch,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use fehler::{throw, throws};
+use rust_decimal::Decimal;
+
+/// What to do with the table before writing the record batches into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteMode {
+    /// Create the table from the schema of the record batches, failing if it exists.
+    Create,
+    /// Append the rows to the existing table.
+    Append,
+    /// Delete the rows of the existing table first.
+    Truncate,
+}
+
+/// Options of [`write_arrow`].
+#[derive(Debug, Clone)]
+pub struct WriteOptions {
+    pub mode: WriteMode,
+    /// Number of connections copying the record batches into Postgres in parallel, the
+    /// batches being spread over them in turn. Values above 1 are not atomic: the table is
+    /// created or truncated first and each connection copies in its own transaction, so the
+    /// rows of the connections that succeeded are kept if another one fails. The other
+    /// databases always write in a single transaction.
+    pub partition_num: usize,
+}
+
+impl Default for WriteOptions {
+    fn default() -> Self {
+        Self {
+            mode: WriteMode::Append,
+            partition_num: 1,
+        }
+    }
+}
+
+/// Write `batches` into `table` of the database of `source_conn`, returning the number of
+/// rows written. The columns are matched by name and the values converted to the types of
+/// the table. `table` is used as is in the statements, so it may be qualified by a schema
+/// and must be quoted if needed.
+///
+/// The rows are written in a single transaction, so nothing is written if it fails, unless
+/// `options.partition_num` is above 1 (see [`WriteOptions`]). MySQL commits `CREATE TABLE`
+/// on its own and drops the table it created instead.
+#[allow(unreachable_code, unreachable_patterns, unused_variables)]
+#[throws(ConnectorXOutError)]
+pub fn write_arrow(
+    source_conn: &SourceConn,
+    table: &str,
+    batches: &[RecordBatch],
+    options: WriteOptions,
+) -> u64 {
+    if let Some(rb) = batches.iter().find(|rb| rb.schema() != batches[0].schema()) {
+        throw!(anyhow!(
+            "record batches have different schemas: {:?} and {:?}",
+            batches[0].schema(),
+            rb.schema()
+        ));
+    }
+
+    match source_conn.ty {
+        #[cfg(feature = "src_postgres")]
+        SourceType::Postgres => postgres::write_postgres(source_conn, table, batches, &options)?,
+        #[cfg(feature = "src_mysql")]
+        SourceType::MySQL => mysql::write_mysql(source_conn, table, batches, &options)?,
+        #[cfg(feature = "src_sqlite")]
+        SourceType::SQLite => sqlite::write_sqlite(source_conn, table, batches, &options)?,
+        #[cfg(feature = "src_mssql")]
+        SourceType::MsSQL => mssql::write_mssql(source_conn, table, batches, &options)?,
+        _ => throw!(ConnectorXOutError::SourceNotSupport(format!(
+            "{:?}",
+            source_conn.ty
+        ))),
+    }
+}
+
+/// Quote the identifier `name` with `quote`, doubling the quotes it contains.
+fn quote_identifier(name: &str, quote: char) -> String {
+    let escaped = name.replace(quote, &format!("{}{}", quote, quote));
+    format!("{}{}{}", quote, escaped, quote)
+}
+
+/// The quoted names of the columns of `schema`, separated by commas.
+fn column_names(schema: &Schema, quote: impl Fn(&str) -> String) -> String {
+    schema
+        .fields()
+        .iter()
+        .map(|f| quote(f.name()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The `CREATE TABLE` statement of a table with the columns of `schema`, where
+/// `column_type` gives the type of a column in the database if it can be written.
+#[throws(ConnectorXError)]
+fn create_table(
+    table: &str,
+    schema: &Schema,
+    quote: impl Fn(&str) -> String,
+    column_type: impl Fn(&Field) -> Option<String>,
+) -> String {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|f| match column_type(f) {
+            Some(ty) => Ok(format!(
+                "{} {}{}",
+                quote(f.name()),
+                ty,
+                if f.is_nullable() { "" } else { " NOT NULL" }
+            )),
+            None => Err(ConnectorXError::UnsupportedWriteType(
+                f.name().to_string(),
+                format!("{:?}", f.data_type()),
+            )),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    format!("CREATE TABLE {} ({})", table, columns.join(", "))
+}
+
+/// Whether `field` holds UUIDs, as the `FixedSizeBinary(16)` columns written by the Arrow
+/// destination do.
+#[cfg(any(feature = "src_postgres", feature = "src_mssql"))]
+fn is_uuid(field: &Field) -> bool {
+    matches!(field.data_type(), DataType::FixedSizeBinary(16))
+        && field
+            .metadata()
+            .get(ARROW_EXTENSION_NAME_KEY)
+            .map(String::as_str)
+            == Some(UUID_EXTENSION_NAME)
+}
+
+/// A value of a record batch, before the conversion to the type of its column in the table.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Value<'a> {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Text(&'a str),
+    Bytes(&'a [u8]),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+    DateTimeTz(DateTime<Utc>),
+    Time(NaiveTime),
+    Decimal(Decimal),
+}
+
+type ValueReader<'a> = Box<dyn Fn(usize) -> Result<Value<'a>> + 'a>;
+
+/// The columns of `rb`, with the dictionaries unpacked.
+#[throws(ConnectorXError)]
+fn columns(rb: &RecordBatch) -> Vec<ArrayRef> {
+    rb.columns()
+        .iter()
+        .map(|array| match array.data_type() {
+            DataType::Dictionary(_, value_type) => {
+                cast(array, value_type).map_err(|e| anyhow!("cannot unpack dictionary: {}", e))
+            }
+            _ => Ok(array.clone()),
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?
+}
+
+/// Read the values of the `columns` of `rb`.
+fn value_readers<'a>(rb: &RecordBatch, columns: &'a [ArrayRef]) -> Result<Vec<ValueReader<'a>>> {
+    rb.schema()
+        .fields()
+        .iter()
+        .zip(columns)
+        .map(|(f, c)| value_reader(f.name(), c))
+        .collect()
+}
+
+/// Read the values of the column `name`.
+fn value_reader<'a>(name: &str, array: &'a ArrayRef) -> Result<ValueReader<'a>> {
+    macro_rules! read {
+        ($array:expr, |$a:ident, $i:ident| $value:expr) => {{
+            let $a = $array;
+            Box::new(move |$i: usize| Ok(if $a.is_null($i) { Value::Null } else { $value }))
+                as ValueReader<'_>
+        }};
+    }
+    let temporal = |v: Option<Value<'a>>| v.ok_or_else(|| anyhow!("timestamp out of range"));
+
+    Ok(match array.data_type() {
+        DataType::Boolean => read!(array.as_boolean(), |a, i| Value::Bool(a.value(i))),
+        DataType::Int8 => read!(array.as_primitive::<Int8Type>(), |a, i| Value::Int(
+            a.value(i) as i64
+        )),
+        DataType::Int16 => read!(array.as_primitive::<Int16Type>(), |a, i| Value::Int(
+            a.value(i) as i64
+        )),
+        DataType::Int32 => read!(array.as_primitive::<Int32Type>(), |a, i| Value::Int(
+            a.value(i) as i64
+        )),
+        DataType::Int64 => read!(array.as_primitive::<Int64Type>(), |a, i| Value::Int(
+            a.value(i)
+        )),
+        DataType::UInt8 => read!(array.as_primitive::<UInt8Type>(), |a, i| Value::UInt(
+            a.value(i) as u64
+        )),
+        DataType::UInt16 => read!(array.as_primitive::<UInt16Type>(), |a, i| Value::UInt(
+            a.value(i) as u64
+        )),
+        DataType::UInt32 => read!(array.as_primitive::<UInt32Type>(), |a, i| Value::UInt(
+            a.value(i) as u64
+        )),
+        DataType::UInt64 => read!(array.as_primitive::<UInt64Type>(), |a, i| Value::UInt(
+            a.value(i)
+        )),
+        DataType::Float32 => read!(array.as_primitive::<Float32Type>(), |a, i| Value::Float(
+            a.value(i) as f64
+        )),
+        DataType::Float64 => read!(array.as_primitive::<Float64Type>(), |a, i| Value::Float(
+            a.value(i)
+        )),
+        DataType::Utf8 => read!(array.as_string::<i32>(), |a, i| Value::Text(a.value(i))),
+        DataType::LargeUtf8 => read!(array.as_string::<i64>(), |a, i| Value::Text(a.value(i))),
+        DataType::Binary => read!(array.as_binary::<i32>(), |a, i| Value::Bytes(a.value(i))),
+        DataType::LargeBinary => {
+            read!(array.as_binary::<i64>(), |a, i| Value::Bytes(a.value(i)))
+        }
+        DataType::FixedSizeBinary(_) => {
+            read!(array.as_fixed_size_binary(), |a, i| Value::Bytes(
+                a.value(i)
+            ))
+        }
+        DataType::Date32 => read!(array.as_primitive::<Date32Type>(), |a, i| temporal(
+            a.value_as_date(i).map(Value::Date)
+        )?),
+        DataType::Date64 => read!(array.as_primitive::<Date64Type>(), |a, i| temporal(
+            a.value_as_date(i).map(Value::Date)
+        )?),
+        DataType::Time32(TimeUnit::Second) => {
+            read!(array.as_primitive::<Time32SecondType>(), |a, i| temporal(
+                a.value_as_time(i).map(Value::Time)
+            )?)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            read!(array.as_primitive::<Time32MillisecondType>(), |a, i| {
+                temporal(a.value_as_time(i).map(Value::Time))?
+            })
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            read!(array.as_primitive::<Time64MicrosecondType>(), |a, i| {
+                temporal(a.value_as_time(i).map(Value::Time))?
+            })
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            read!(array.as_primitive::<Time64NanosecondType>(), |a, i| {
+                temporal(a.value_as_time(i).map(Value::Time))?
+            })
+        }
+        DataType::Timestamp(unit, tz) => {
+            // the values are instants in UTC if the column has a time zone
+            let to_value: fn(NaiveDateTime) -> Value<'a> = if tz.is_some() {
+                |dt| Value::DateTimeTz(DateTime::from_naive_utc_and_offset(dt, Utc))
+            } else {
+                Value::DateTime
+            };
+            match unit {
+                TimeUnit::Second => {
+                    read!(array.as_primitive::<TimestampSecondType>(), |a, i| {
+                        temporal(a.value_as_datetime(i).map(to_value))?
+                    })
+                }
+                TimeUnit::Millisecond => {
+                    read!(array.as_primitive::<TimestampMillisecondType>(), |a, i| {
+                        temporal(a.value_as_datetime(i).map(to_value))?
+                    })
+                }
+                TimeUnit::Microsecond => {
+                    read!(array.as_primitive::<TimestampMicrosecondType>(), |a, i| {
+                        temporal(a.value_as_datetime(i).map(to_value))?
+                    })
+                }
+                TimeUnit::Nanosecond => {
+                    read!(array.as_primitive::<TimestampNanosecondType>(), |a, i| {
+                        temporal(a.value_as_datetime(i).map(to_value))?
+                    })
+                }
+            }
+        }
+        DataType::Decimal128(_, scale) if *scale >= 0 => {
+            let scale = *scale as u32;
+            read!(array.as_primitive::<Decimal128Type>(), |a, i| {
+                Value::Decimal(
+                    Decimal::try_from_i128_with_scale(a.value(i), scale)
+                        .map_err(|e| anyhow!("cannot write decimal {}: {}", a.value(i), e))?,
+                )
+            })
+        }
+        dt => throw!(ConnectorXError::UnsupportedWriteType(
+            name.to_string(),
+            format!("{:?}", dt)
+        )),
+    })
+}
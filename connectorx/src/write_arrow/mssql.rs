@@ -0,0 +1,190 @@
+//! Write record batches into SQL Server with multi-row inserts.
+//!
+//! The version of `tiberius` in use has no bulk load, so the rows are inserted with
+//! parameterized `INSERT ... VALUES` statements of up to 1000 rows.
+
+use super::{
+    column_names, columns, create_table, is_uuid, value_readers, Value, WriteMode, WriteOptions,
+};
+use crate::source_router::SourceConn;
+use crate::sources::mssql::{mssql_config, MsSQLSourceError};
+use anyhow::anyhow;
+use arrow::{
+    datatypes::{DataType, Field},
+    record_batch::RecordBatch,
+};
+use bb8::Pool;
+use bb8_tiberius::{rt::Client, ConnectionManager};
+use fehler::throws;
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+use tiberius::Query;
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+/// A request has at most 2100 parameters.
+const MAX_PARAMS: usize = 2000;
+/// A table value constructor has at most 1000 rows.
+const MAX_ROWS_PER_INSERT: usize = 1000;
+
+/// The rows are written in a single transaction, which also holds `CREATE TABLE` and
+/// `TRUNCATE TABLE` in SQL Server.
+#[throws(MsSQLSourceError)]
+pub(super) fn write_mssql(
+    source_conn: &SourceConn,
+    table: &str,
+    batches: &[RecordBatch],
+    options: &WriteOptions,
+) -> u64 {
+    let rt = Runtime::new().map_err(|e| anyhow!("cannot create runtime: {}", e))?;
+    let manager = ConnectionManager::new(mssql_config(&source_conn.conn)?);
+    let pool = rt.block_on(Pool::builder().max_size(1).build(manager))?;
+
+    rt.block_on(async {
+        let mut conn = pool.get().await?;
+        conn.execute("BEGIN TRANSACTION", &[]).await?;
+        match write(&mut conn, table, batches, options.mode).await {
+            Ok(n) => {
+                conn.execute("COMMIT", &[]).await?;
+                Ok(n)
+            }
+            Err(e) => {
+                // keep the error of the load if the rollback fails as well
+                conn.execute("IF @@TRANCOUNT > 0 ROLLBACK TRANSACTION", &[])
+                    .await
+                    .ok();
+                Err(e)
+            }
+        }
+    })?
+}
+
+/// Create or truncate `table` as set by `mode` and insert `batches` into it.
+async fn write(
+    conn: &mut Client,
+    table: &str,
+    batches: &[RecordBatch],
+    mode: WriteMode,
+) -> Result<u64, MsSQLSourceError> {
+    match mode {
+        WriteMode::Create => {
+            let rb = batches
+                .first()
+                .ok_or_else(|| anyhow!("no record batch to create table {} from", table))?;
+            let create = create_table(table, &rb.schema(), quote, column_type)?;
+            conn.execute(create, &[]).await?;
+        }
+        WriteMode::Truncate => {
+            conn.execute(format!("TRUNCATE TABLE {}", table), &[])
+                .await?;
+        }
+        WriteMode::Append => {}
+    }
+
+    let schema = match batches.first() {
+        Some(rb) => rb.schema(),
+        None => return Ok(0),
+    };
+    let ncols = schema.fields().len();
+    let insert = format!(
+        "INSERT INTO {} ({}) VALUES ",
+        table,
+        column_names(&schema, quote)
+    );
+    let uuids: Vec<bool> = schema.fields().iter().map(|f| is_uuid(f)).collect();
+    let rows_per_insert = (MAX_PARAMS / ncols.max(1)).clamp(1, MAX_ROWS_PER_INSERT);
+
+    let mut n = 0;
+    for rb in batches {
+        let columns = columns(rb)?;
+        let readers = value_readers(rb, &columns)?;
+        let mut start = 0;
+        while start < rb.num_rows() {
+            let end = (start + rows_per_insert).min(rb.num_rows());
+            // the nulls are written as literals since their type is unknown
+            let mut values = Vec::with_capacity((end - start) * ncols);
+            let mut rows = Vec::with_capacity(end - start);
+            for i in start..end {
+                let mut row = Vec::with_capacity(ncols);
+                for (reader, uuid) in readers.iter().zip(&uuids) {
+                    match reader(i)? {
+                        Value::Null => row.push("NULL".to_string()),
+                        value => {
+                            values.push((value, *uuid));
+                            row.push(format!("@P{}", values.len()));
+                        }
+                    }
+                }
+                rows.push(format!("({})", row.join(", ")));
+            }
+            let mut query = Query::new(format!("{}{}", insert, rows.join(", ")));
+            for (value, uuid) in values {
+                bind(&mut query, value, uuid)?;
+            }
+            n += query.execute(&mut *conn).await?.total();
+            start = end;
+        }
+    }
+    Ok(n)
+}
+
+fn quote(name: &str) -> String {
+    format!("[{}]", name.replace(']', "]]"))
+}
+
+/// The type of the column of `field`, the inverse of the SQL Server Arrow transport.
+fn column_type(field: &Field) -> Option<String> {
+    let ty = match field.data_type() {
+        DataType::Boolean => "BIT",
+        DataType::UInt8 => "TINYINT",
+        DataType::Int8 | DataType::Int16 => "SMALLINT",
+        DataType::Int32 | DataType::UInt16 => "INT",
+        DataType::Int64 | DataType::UInt32 => "BIGINT",
+        DataType::UInt64 => "DECIMAL(20, 0)",
+        DataType::Float32 => "REAL",
+        DataType::Float64 => "FLOAT(53)",
+        DataType::Utf8 | DataType::LargeUtf8 => "NVARCHAR(MAX)",
+        DataType::Dictionary(_, value_type)
+            if matches!(**value_type, DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            "NVARCHAR(MAX)"
+        }
+        _ if is_uuid(field) => "UNIQUEIDENTIFIER",
+        DataType::Binary | DataType::LargeBinary => "VARBINARY(MAX)",
+        DataType::FixedSizeBinary(n) => return Some(format!("BINARY({})", n)),
+        DataType::Date32 | DataType::Date64 => "DATE",
+        DataType::Time32(_) | DataType::Time64(_) => "TIME",
+        DataType::Timestamp(_, None) => "DATETIME2",
+        DataType::Timestamp(_, Some(_)) => "DATETIMEOFFSET",
+        DataType::Decimal128(precision, scale) if *precision <= 38 && *scale >= 0 => {
+            return Some(format!("DECIMAL({}, {})", precision, scale))
+        }
+        _ => return None,
+    };
+    Some(ty.to_string())
+}
+
+/// Bind `value`, converted to a UUID if `uuid` is set.
+#[throws(MsSQLSourceError)]
+fn bind<'a>(query: &mut Query<'a>, value: Value<'a>, uuid: bool) {
+    match value {
+        Value::Null => query.bind(Option::<&str>::None),
+        Value::Bool(v) => query.bind(v),
+        Value::Int(v) => query.bind(v),
+        Value::UInt(v) => match i64::try_from(v) {
+            Ok(v) => query.bind(v),
+            Err(_) => query.bind(Decimal::from(v)),
+        },
+        Value::Float(v) => query.bind(v),
+        Value::Text(v) => query.bind(v),
+        Value::Bytes(v) if uuid => {
+            query.bind(Uuid::from_slice(v).map_err(|e| anyhow!("invalid uuid: {}", e))?)
+        }
+        Value::Bytes(v) => query.bind(v),
+        Value::Date(v) => query.bind(v),
+        Value::DateTime(v) => query.bind(v),
+        Value::DateTimeTz(v) => query.bind(v),
+        Value::Time(v) => query.bind(v),
+        Value::Decimal(v) => query.bind(v),
+    }
+}
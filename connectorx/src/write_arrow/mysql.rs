@@ -0,0 +1,152 @@
+//! Write record batches into MySQL with multi-row inserts.
+
+use super::{
+    column_names, columns, create_table, quote_identifier, value_readers, Value, WriteMode,
+    WriteOptions,
+};
+use crate::source_router::SourceConn;
+use crate::sources::mysql::MySQLSourceError;
+use anyhow::anyhow;
+use arrow::{
+    datatypes::{DataType, Field},
+    record_batch::RecordBatch,
+};
+use fehler::{throw, throws};
+use r2d2_mysql::mysql::{
+    prelude::Queryable, Conn, Opts, OptsBuilder, Params, TxOpts, Value as MySQLValue,
+};
+
+/// Placeholders of a statement are numbered with 16 bits.
+const MAX_PARAMS: usize = 65535;
+const MAX_ROWS_PER_INSERT: usize = 1000;
+
+/// The rows are written in a single transaction. MySQL commits `CREATE TABLE` on its own,
+/// so the table is dropped again if the rows cannot be written.
+#[throws(MySQLSourceError)]
+pub(super) fn write_mysql(
+    source_conn: &SourceConn,
+    table: &str,
+    batches: &[RecordBatch],
+    options: &WriteOptions,
+) -> u64 {
+    let mut conn = Conn::new(OptsBuilder::from_opts(Opts::from_url(
+        &source_conn.conn[..],
+    )?))?;
+    if options.mode == WriteMode::Create {
+        let rb = batches
+            .first()
+            .ok_or_else(|| anyhow!("no record batch to create table {} from", table))?;
+        conn.query_drop(create_table(table, &rb.schema(), quote, column_type)?)?;
+    }
+
+    match insert(&mut conn, table, batches, options.mode) {
+        Ok(n) => n,
+        Err(e) => {
+            if options.mode == WriteMode::Create {
+                conn.query_drop(format!("DROP TABLE {}", table))?;
+            }
+            throw!(e)
+        }
+    }
+}
+
+/// Insert `batches` into `table` in a transaction, after deleting its rows in the
+/// `Truncate` mode. The transaction is rolled back on drop if it fails.
+#[throws(MySQLSourceError)]
+fn insert(conn: &mut Conn, table: &str, batches: &[RecordBatch], mode: WriteMode) -> u64 {
+    let mut tx = conn.start_transaction(TxOpts::default())?;
+    if mode == WriteMode::Truncate {
+        // unlike TRUNCATE TABLE, which is committed on its own
+        tx.query_drop(format!("DELETE FROM {}", table))?;
+    }
+
+    let mut n = 0;
+    if let Some(rb) = batches.first() {
+        let schema = rb.schema();
+        let ncols = schema.fields().len();
+        let insert = format!(
+            "INSERT INTO {} ({}) VALUES ",
+            table,
+            column_names(&schema, quote)
+        );
+        let row = format!("({})", vec!["?"; ncols].join(", "));
+        let rows_per_insert = (MAX_PARAMS / ncols.max(1)).clamp(1, MAX_ROWS_PER_INSERT);
+
+        for rb in batches {
+            let columns = columns(rb)?;
+            let readers = value_readers(rb, &columns)?;
+            let mut start = 0;
+            while start < rb.num_rows() {
+                let end = (start + rows_per_insert).min(rb.num_rows());
+                let mut params = Vec::with_capacity((end - start) * ncols);
+                for i in start..end {
+                    for reader in &readers {
+                        params.push(mysql_value(reader(i)?));
+                    }
+                }
+                let stmt = format!("{}{}", insert, vec![row.as_str(); end - start].join(", "));
+                tx.exec_drop(stmt, Params::Positional(params))?;
+                n += tx.affected_rows();
+                start = end;
+            }
+        }
+    }
+    tx.commit()?;
+    n
+}
+
+fn quote(name: &str) -> String {
+    quote_identifier(name, '`')
+}
+
+/// The type of the column of `field`, the inverse of the MySQL Arrow transport.
+fn column_type(field: &Field) -> Option<String> {
+    let ty = match field.data_type() {
+        DataType::Boolean => "BOOLEAN",
+        DataType::Int8 => "TINYINT",
+        DataType::Int16 => "SMALLINT",
+        DataType::Int32 => "INT",
+        DataType::Int64 => "BIGINT",
+        DataType::UInt8 => "TINYINT UNSIGNED",
+        DataType::UInt16 => "SMALLINT UNSIGNED",
+        DataType::UInt32 => "INT UNSIGNED",
+        DataType::UInt64 => "BIGINT UNSIGNED",
+        DataType::Float32 => "FLOAT",
+        DataType::Float64 => "DOUBLE",
+        DataType::Utf8 | DataType::LargeUtf8 => "LONGTEXT",
+        DataType::Dictionary(_, value_type)
+            if matches!(**value_type, DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            "LONGTEXT"
+        }
+        DataType::Binary | DataType::LargeBinary => "LONGBLOB",
+        DataType::FixedSizeBinary(n) => return Some(format!("BINARY({})", n)),
+        DataType::Date32 | DataType::Date64 => "DATE",
+        DataType::Time32(_) | DataType::Time64(_) => "TIME(6)",
+        // the timestamps with a time zone are written in UTC
+        DataType::Timestamp(_, _) => "DATETIME(6)",
+        DataType::Decimal128(precision, scale) if *scale >= 0 => {
+            return Some(format!("DECIMAL({}, {})", precision, scale))
+        }
+        _ => return None,
+    };
+    Some(ty.to_string())
+}
+
+/// The values are converted to the types of the columns by the server.
+fn mysql_value(value: Value) -> MySQLValue {
+    match value {
+        Value::Null => MySQLValue::NULL,
+        Value::Bool(v) => MySQLValue::Int(v as i64),
+        Value::Int(v) => MySQLValue::Int(v),
+        Value::UInt(v) => MySQLValue::UInt(v),
+        Value::Float(v) => MySQLValue::Double(v),
+        Value::Text(v) => MySQLValue::Bytes(v.as_bytes().to_vec()),
+        Value::Bytes(v) => MySQLValue::Bytes(v.to_vec()),
+        Value::Date(v) => MySQLValue::from(v),
+        Value::DateTime(v) => MySQLValue::from(v),
+        Value::DateTimeTz(v) => MySQLValue::from(v.naive_utc()),
+        Value::Time(v) => MySQLValue::from(v),
+        Value::Decimal(v) => MySQLValue::Bytes(v.to_string().into_bytes()),
+    }
+}
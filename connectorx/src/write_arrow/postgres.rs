@@ -0,0 +1,288 @@
+//! Write record batches into Postgres with `COPY ... FROM STDIN (FORMAT binary)`.
+
+use super::{
+    column_names, columns, create_table, is_uuid, quote_identifier, value_readers, Value,
+    WriteMode, WriteOptions,
+};
+use crate::source_router::SourceConn;
+use crate::sources::postgres::{rewrite_tls_args, PostgresSourceError};
+use anyhow::anyhow;
+use arrow::{
+    datatypes::{DataType, Field},
+    record_batch::RecordBatch,
+};
+use bytes::BytesMut;
+use fehler::throws;
+use postgres::{
+    binary_copy::BinaryCopyInWriter,
+    tls::{MakeTlsConnect, TlsConnect},
+    types::{to_sql_checked, IsNull, ToSql, Type},
+    Config, GenericClient, NoTls, Socket,
+};
+use r2d2::Pool;
+use r2d2_postgres::PostgresConnectionManager;
+use rayon::prelude::*;
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+use std::error::Error;
+use uuid::Uuid;
+
+/// The table is created or truncated and the batches are copied in a single transaction,
+/// or over `options.partition_num` connections in parallel.
+#[throws(PostgresSourceError)]
+pub(super) fn write_postgres(
+    source_conn: &SourceConn,
+    table: &str,
+    batches: &[RecordBatch],
+    options: &WriteOptions,
+) -> u64 {
+    let (config, tls) = rewrite_tls_args(&source_conn.conn)?;
+    match tls {
+        Some(tls_conn) => write(config, tls_conn, table, batches, options)?,
+        None => write(config, NoTls, table, batches, options)?,
+    }
+}
+
+#[throws(PostgresSourceError)]
+fn write<C>(
+    config: Config,
+    tls: C,
+    table: &str,
+    batches: &[RecordBatch],
+    options: &WriteOptions,
+) -> u64
+where
+    C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+    C::TlsConnect: Send,
+    C::Stream: Send,
+    <C::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    if options.partition_num > 1 {
+        return write_parallel(config, tls, table, batches, options)?;
+    }
+
+    let mut client = config.connect(tls)?;
+    let mut tx = client.transaction()?;
+    match options.mode {
+        WriteMode::Create => {
+            let rb = batches
+                .first()
+                .ok_or_else(|| anyhow!("no record batch to create table {} from", table))?;
+            tx.batch_execute(&create_table(table, &rb.schema(), quote, column_type)?)?;
+        }
+        WriteMode::Truncate => tx.batch_execute(&format!("TRUNCATE TABLE {}", table))?,
+        WriteMode::Append => {}
+    }
+
+    let mut n = 0;
+    if let Some(rb) = batches.first() {
+        let schema = rb.schema();
+        let names = column_names(&schema, quote);
+        let types = column_types(&mut tx, &names, table)?;
+
+        let copy = format!("COPY {} ({}) FROM STDIN (FORMAT binary)", table, names);
+        let mut writer = BinaryCopyInWriter::new(tx.copy_in(copy.as_str())?, &types);
+        for rb in batches {
+            write_batch(&mut writer, rb)?;
+        }
+        n = writer.finish()?;
+    }
+    tx.commit()?;
+    n
+}
+
+/// Copy the batches over `options.partition_num` connections, each in its own transaction.
+#[throws(PostgresSourceError)]
+fn write_parallel<C>(
+    config: Config,
+    tls: C,
+    table: &str,
+    batches: &[RecordBatch],
+    options: &WriteOptions,
+) -> u64
+where
+    C: MakeTlsConnect<Socket> + Clone + 'static + Sync + Send,
+    C::TlsConnect: Send,
+    C::Stream: Send,
+    <C::TlsConnect as TlsConnect<Socket>>::Future: Send,
+{
+    let partitions = partitions(batches, options.partition_num);
+    let manager = PostgresConnectionManager::new(config, tls);
+    let pool = Pool::builder()
+        .max_size(partitions.len() as u32)
+        .build(manager)?;
+
+    let mut conn = pool.get()?;
+    match options.mode {
+        WriteMode::Create => {
+            let rb = batches
+                .first()
+                .ok_or_else(|| anyhow!("no record batch to create table {} from", table))?;
+            conn.batch_execute(&create_table(table, &rb.schema(), quote, column_type)?)?;
+        }
+        WriteMode::Truncate => conn.batch_execute(&format!("TRUNCATE TABLE {}", table))?,
+        WriteMode::Append => {}
+    }
+
+    let schema = match batches.first() {
+        Some(rb) => rb.schema(),
+        None => return 0,
+    };
+    let names = column_names(&schema, quote);
+    let types = column_types(&mut *conn, &names, table)?;
+    drop(conn);
+
+    let copy = format!("COPY {} ({}) FROM STDIN (FORMAT binary)", table, names);
+    partitions
+        .into_par_iter()
+        .map(|batches| -> Result<u64, PostgresSourceError> {
+            let mut conn = pool.get()?;
+            let mut writer = BinaryCopyInWriter::new(conn.copy_in(copy.as_str())?, &types);
+            for rb in batches {
+                write_batch(&mut writer, rb)?;
+            }
+            Ok(writer.finish()?)
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .sum()
+}
+
+/// Split `batches` in `n` partitions, taking the batches in turn.
+fn partitions(batches: &[RecordBatch], n: usize) -> Vec<Vec<&RecordBatch>> {
+    let n = n.max(1).min(batches.len().max(1));
+    let mut partitions = vec![vec![]; n];
+    for (i, rb) in batches.iter().enumerate() {
+        partitions[i % n].push(rb);
+    }
+    partitions
+}
+
+/// The types of the columns `names` in the table, which the values are converted to.
+#[throws(PostgresSourceError)]
+fn column_types(client: &mut impl GenericClient, names: &str, table: &str) -> Vec<Type> {
+    client
+        .prepare(&format!("SELECT {} FROM {} LIMIT 0", names, table))?
+        .columns()
+        .iter()
+        .map(|c| c.type_().clone())
+        .collect()
+}
+
+fn quote(name: &str) -> String {
+    quote_identifier(name, '"')
+}
+
+#[throws(PostgresSourceError)]
+fn write_batch(writer: &mut BinaryCopyInWriter, rb: &RecordBatch) {
+    let columns = columns(rb)?;
+    let readers = value_readers(rb, &columns)?;
+    let mut row = Vec::with_capacity(readers.len());
+    for i in 0..rb.num_rows() {
+        row.clear();
+        for reader in &readers {
+            row.push(reader(i)?);
+        }
+        writer.write_raw(row.iter())?;
+    }
+}
+
+/// The type of the column of `field`, the inverse of the Postgres Arrow transport.
+fn column_type(field: &Field) -> Option<String> {
+    let ty = match field.data_type() {
+        DataType::Boolean => "BOOLEAN",
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => "SMALLINT",
+        DataType::Int32 | DataType::UInt16 => "INTEGER",
+        DataType::Int64 | DataType::UInt32 => "BIGINT",
+        DataType::UInt64 => "NUMERIC(20, 0)",
+        DataType::Float32 => "REAL",
+        DataType::Float64 => "DOUBLE PRECISION",
+        DataType::Utf8 | DataType::LargeUtf8 => "TEXT",
+        DataType::Dictionary(_, value_type)
+            if matches!(**value_type, DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            "TEXT"
+        }
+        _ if is_uuid(field) => "UUID",
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => "BYTEA",
+        DataType::Date32 | DataType::Date64 => "DATE",
+        DataType::Time32(_) | DataType::Time64(_) => "TIME",
+        DataType::Timestamp(_, None) => "TIMESTAMP",
+        DataType::Timestamp(_, Some(_)) => "TIMESTAMPTZ",
+        DataType::Decimal128(precision, scale) if *scale >= 0 => {
+            return Some(format!("NUMERIC({}, {})", precision, scale))
+        }
+        _ => return None,
+    };
+    Some(ty.to_string())
+}
+
+impl ToSql for Value<'_> {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match *self {
+            Value::Null => Ok(IsNull::Yes),
+            Value::Bool(v) => v.to_sql_checked(ty, out),
+            Value::Int(v) => match *ty {
+                Type::INT2 => i16::try_from(v)?.to_sql_checked(ty, out),
+                Type::INT4 => i32::try_from(v)?.to_sql_checked(ty, out),
+                Type::FLOAT4 => (v as f32).to_sql_checked(ty, out),
+                Type::FLOAT8 => (v as f64).to_sql_checked(ty, out),
+                Type::NUMERIC => Decimal::from(v).to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::UInt(v) => match *ty {
+                Type::NUMERIC => Decimal::from(v).to_sql_checked(ty, out),
+                _ => Value::Int(i64::try_from(v)?).to_sql(ty, out),
+            },
+            Value::Float(v) => match *ty {
+                Type::FLOAT4 => (v as f32).to_sql_checked(ty, out),
+                Type::NUMERIC => Decimal::try_from(v)?.to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::Text(v) => match *ty {
+                Type::JSON | Type::JSONB => {
+                    serde_json::from_str::<serde_json::Value>(v)?.to_sql_checked(ty, out)
+                }
+                Type::UUID => Uuid::parse_str(v)?.to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::Bytes(v) => match *ty {
+                Type::UUID => Uuid::from_slice(v)?.to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::Date(v) => match *ty {
+                Type::TIMESTAMP => v.and_hms_opt(0, 0, 0).to_sql_checked(ty, out),
+                Type::TIMESTAMPTZ => v
+                    .and_hms_opt(0, 0, 0)
+                    .map(|dt| dt.and_utc())
+                    .to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::DateTime(v) => match *ty {
+                Type::TIMESTAMPTZ => v.and_utc().to_sql_checked(ty, out),
+                Type::DATE => v.date().to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::DateTimeTz(v) => match *ty {
+                Type::TIMESTAMP => v.naive_utc().to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+            Value::Time(v) => v.to_sql_checked(ty, out),
+            Value::Decimal(v) => match *ty {
+                Type::FLOAT4 => f32::try_from(v)?.to_sql_checked(ty, out),
+                Type::FLOAT8 => f64::try_from(v)?.to_sql_checked(ty, out),
+                _ => v.to_sql_checked(ty, out),
+            },
+        }
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
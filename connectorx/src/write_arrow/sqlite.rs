@@ -0,0 +1,125 @@
+//! Write record batches into SQLite with prepared inserts in a single transaction.
+
+use super::{
+    column_names, columns, create_table, quote_identifier, value_readers, Value, WriteMode,
+    WriteOptions,
+};
+use crate::source_router::SourceConn;
+use crate::sources::sqlite::SQLiteSourceError;
+use anyhow::anyhow;
+use arrow::{
+    datatypes::{DataType, Field},
+    record_batch::RecordBatch,
+};
+use fehler::throws;
+use rusqlite::{
+    params_from_iter,
+    types::{ToSqlOutput, ValueRef},
+    Connection, ToSql,
+};
+use std::convert::TryFrom;
+use urlencoding::decode;
+
+/// The table is created or truncated and the rows are inserted in a single transaction.
+#[throws(SQLiteSourceError)]
+pub(super) fn write_sqlite(
+    source_conn: &SourceConn,
+    table: &str,
+    batches: &[RecordBatch],
+    options: &WriteOptions,
+) -> u64 {
+    // remove the first "sqlite://" manually since url.path is not correct for windows
+    let path = decode(&source_conn.conn.as_str()[9..])?.into_owned();
+    let mut conn = Connection::open(path)?;
+    let tx = conn.transaction()?;
+    match options.mode {
+        WriteMode::Create => {
+            let rb = batches
+                .first()
+                .ok_or_else(|| anyhow!("no record batch to create table {} from", table))?;
+            tx.execute_batch(&create_table(table, &rb.schema(), quote, column_type)?)?;
+        }
+        WriteMode::Truncate => tx.execute_batch(&format!("DELETE FROM {}", table))?,
+        WriteMode::Append => {}
+    }
+
+    let mut n = 0;
+    if let Some(rb) = batches.first() {
+        let schema = rb.schema();
+        let insert = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            table,
+            column_names(&schema, quote),
+            vec!["?"; schema.fields().len()].join(", ")
+        );
+        let mut stmt = tx.prepare(&insert)?;
+        for rb in batches {
+            let columns = columns(rb)?;
+            let readers = value_readers(rb, &columns)?;
+            let mut row = Vec::with_capacity(readers.len());
+            for i in 0..rb.num_rows() {
+                row.clear();
+                for reader in &readers {
+                    row.push(reader(i)?);
+                }
+                n += stmt.execute(params_from_iter(row.iter()))? as u64;
+            }
+        }
+    }
+    tx.commit()?;
+    n
+}
+
+fn quote(name: &str) -> String {
+    quote_identifier(name, '"')
+}
+
+/// The declared type of the column of `field`, the inverse of the SQLite Arrow transport.
+fn column_type(field: &Field) -> Option<String> {
+    let ty = match field.data_type() {
+        DataType::Boolean => "BOOLEAN",
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => "INT2",
+        DataType::Int32 | DataType::UInt16 => "INT4",
+        DataType::Int64 | DataType::UInt32 | DataType::UInt64 => "INTEGER",
+        DataType::Float32 | DataType::Float64 => "REAL",
+        DataType::Utf8 | DataType::LargeUtf8 => "TEXT",
+        DataType::Dictionary(_, value_type)
+            if matches!(**value_type, DataType::Utf8 | DataType::LargeUtf8) =>
+        {
+            "TEXT"
+        }
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => "BLOB",
+        DataType::Date32 | DataType::Date64 => "DATE",
+        DataType::Time32(_) | DataType::Time64(_) => "TIME",
+        // the timestamps with a time zone are written in UTC
+        DataType::Timestamp(_, _) => "TIMESTAMP",
+        DataType::Decimal128(precision, scale) if *scale >= 0 => {
+            return Some(format!("DECIMAL({}, {})", precision, scale))
+        }
+        _ => return None,
+    };
+    Some(ty.to_string())
+}
+
+impl ToSql for Value<'_> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(match self {
+            Value::Null => ToSqlOutput::Borrowed(ValueRef::Null),
+            Value::Bool(v) => ToSqlOutput::from(*v),
+            Value::Int(v) => ToSqlOutput::from(*v),
+            Value::UInt(v) => ToSqlOutput::from(
+                i64::try_from(*v)
+                    .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?,
+            ),
+            Value::Float(v) => ToSqlOutput::from(*v),
+            Value::Text(v) => ToSqlOutput::from(*v),
+            Value::Bytes(v) => ToSqlOutput::from(*v),
+            Value::Date(v) => v.to_sql()?,
+            Value::DateTime(v) => v.to_sql()?,
+            Value::DateTimeTz(v) => ToSqlOutput::from(v.naive_utc().format("%F %T%.f").to_string()),
+            Value::Time(v) => v.to_sql()?,
+            // stored with the affinity of the column, i.e. as a number in a DECIMAL column
+            Value::Decimal(v) => ToSqlOutput::from(v.to_string()),
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use arrow::{
+    array::{
+        Array, ArrayRef, Float64Array, Int32Array, Int8Array, StringArray, TimestampNanosecondArray,
+    },
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    ipc::writer::{FileWriter, StreamWriter},
+    record_batch::RecordBatch,
+};
+use chrono::NaiveDate;
+use connectorx::{
+    destinations::{arrow::ArrowDestination, arrow2::Arrow2Destination},
+    prelude::*,
+    sources::arrow_ipc::ArrowIPCSource,
+    sql::CXQuery,
+    transports::{ArrowIPCArrow2Transport, ArrowIPCArrowTransport},
+};
+use std::convert::TryFrom;
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+fn batches() -> Vec<RecordBatch> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("test_int", DataType::Int8, false),
+        Field::new("test_str", DataType::Utf8, true),
+        Field::new("test_float", DataType::Float64, true),
+        Field::new(
+            "test_ts",
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("+00:00".into())),
+            true,
+        ),
+    ]));
+    let ts = NaiveDate::from_ymd_opt(2021, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_nanos_opt()
+        .unwrap();
+
+    let batch = |ints: Vec<i8>, strs: Vec<Option<&str>>, floats: Vec<Option<f64>>| {
+        let tss: Vec<Option<i64>> = ints
+            .iter()
+            .map(|&i| Some(ts + i as i64 * 1_000_000_000 + 123))
+            .collect();
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Int8Array::from(ints)),
+            Arc::new(StringArray::from(strs)),
+            Arc::new(Float64Array::from(floats)),
+            Arc::new(TimestampNanosecondArray::from(tss).with_timezone("+00:00")),
+        ];
+        RecordBatch::try_new(schema.clone(), columns).unwrap()
+    };
+
+    vec![
+        batch(
+            vec![1, 2],
+            vec![Some("a"), Some("b")],
+            vec![Some(1.1), Some(2.2)],
+        ),
+        batch(vec![3, 4], vec![Some("c"), None], vec![None, Some(4.4)]),
+    ]
+}
+
+fn setup_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("connectorx_{}.arrow", name));
+    let batches = batches();
+    let mut writer =
+        FileWriter::try_new(File::create(&path).unwrap(), &batches[0].schema()).unwrap();
+    for batch in &batches {
+        writer.write(batch).unwrap();
+    }
+    writer.finish().unwrap();
+    path
+}
+
+fn setup_stream(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("connectorx_{}.arrows", name));
+    let batches = batches();
+    let mut writer =
+        StreamWriter::try_new(File::create(&path).unwrap(), &batches[0].schema()).unwrap();
+    for batch in &batches {
+        writer.write(batch).unwrap();
+    }
+    writer.finish().unwrap();
+    path
+}
+
+#[test]
+fn test_arrow_ipc_split_batches() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_file("test_arrow_ipc_split_batches");
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    let source = ArrowIPCSource::new(true);
+    let mut destination = ArrowDestination::new();
+    let dispatcher =
+        Dispatcher::<_, _, ArrowIPCArrowTransport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let result = destination.arrow().unwrap();
+    assert_eq!(2, result.len());
+
+    for rb in result {
+        let ints = rb.column(0).as_any().downcast_ref::<Int32Array>().unwrap();
+        let strs = rb.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        let floats = rb
+            .column(2)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        // nanosecond timestamps are not truncated
+        let tss = rb
+            .column(3)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        match ints.value(0) {
+            1 => {
+                assert!(ints.eq(&Int32Array::from(vec![1, 2])));
+                assert!(strs.eq(&StringArray::from(vec!["a", "b"])));
+                assert!(floats.eq(&Float64Array::from(vec![1.1, 2.2])));
+            }
+            3 => {
+                assert!(ints.eq(&Int32Array::from(vec![3, 4])));
+                assert!(strs.eq(&StringArray::from(vec![Some("c"), None])));
+                assert!(floats.eq(&Float64Array::from(vec![None, Some(4.4)])));
+            }
+            _ => unreachable!(),
+        }
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2021, 1, 1)
+                .unwrap()
+                .and_hms_nano_opt(0, 0, ints.value(1) as u32, 123),
+            tss.value_as_datetime(1)
+        );
+    }
+}
+
+#[test]
+fn test_arrow_ipc_stream_arrow2() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_stream("test_arrow_ipc_stream_arrow2");
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    // the stream format cannot be split, the file becomes a single partition
+    let source = ArrowIPCSource::new(true);
+    let mut destination = Arrow2Destination::new();
+    let dispatcher =
+        Dispatcher::<_, _, ArrowIPCArrow2Transport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let df = destination.polars().unwrap();
+    assert_eq!(4, df.height());
+    assert_eq!(4, df.width());
+    assert_eq!(1, df.column("test_str").unwrap().null_count());
+    assert_eq!(1, df.column("test_float").unwrap().null_count());
+}
+
+#[test]
+fn test_arrow_ipc_get_arrow() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let file = setup_file("test_arrow_ipc_get_arrow");
+    let stream = setup_stream("test_arrow_ipc_get_arrow");
+    let queries = [
+        CXQuery::naked(file.to_str().unwrap().to_string()),
+        CXQuery::naked(stream.to_str().unwrap().to_string()),
+    ];
+
+    let source_conn = SourceConn::try_from("feather://?split_batches=true").unwrap();
+    let destination = get_arrow(&source_conn, None, &queries).unwrap();
+    // the file is split by record batch, the stream is not
+    let result = destination.arrow().unwrap();
+    assert_eq!(3, result.len());
+    assert_eq!(8, result.iter().map(|rb| rb.num_rows()).sum::<usize>());
+
+    let source_conn = SourceConn::try_from("arrow://").unwrap();
+    let mut iter = new_arrow2_batch_iter(&source_conn, None, &queries, 1024).unwrap();
+    iter.prepare();
+    let mut nrows = 0;
+    while let Some(chunk) = iter.next_batch().unwrap() {
+        nrows += chunk.len();
+    }
+    assert_eq!(8, nrows);
+}
@@ -0,0 +1,191 @@
+use arrow::{
+    array::{Float64Array, Int64Array, StringArray},
+    compute::concat_batches,
+    ipc::reader::{FileReader, StreamReader},
+    record_batch::RecordBatch,
+};
+use connectorx::{
+    destinations::arrow_ipc::{ArrowIPCDestination, IPCFormat},
+    prelude::*,
+    sources::{
+        dummy::{DummySource, DummyTypeSystem},
+        postgres::{rewrite_tls_args, BinaryProtocol, PostgresSource},
+    },
+    sql::CXQuery,
+    transports::{ArrowIPCTransport, DummyArrowTransport, PostgresArrowTransport},
+};
+use postgres::NoTls;
+use std::env;
+use std::fs::File;
+use std::io::Cursor;
+use url::Url;
+
+fn concat(batches: Vec<RecordBatch>) -> RecordBatch {
+    concat_batches(&batches[0].schema(), &batches).unwrap()
+}
+
+#[test]
+fn test_arrow_ipc_stream() {
+    let schema = [
+        DummyTypeSystem::I64(false),
+        DummyTypeSystem::F64(true),
+        DummyTypeSystem::String(true),
+    ];
+    let queries = [CXQuery::naked("4,3"), CXQuery::naked("7,3")];
+    let mut destination = ArrowIPCDestination::new(vec![], IPCFormat::Stream);
+    let dispatcher = Dispatcher::<_, _, ArrowIPCTransport<DummyArrowTransport, Vec<u8>>>::new(
+        DummySource::new(&["a", "b", "c"], &schema),
+        &mut destination,
+        &queries,
+        None,
+    );
+    dispatcher.run().expect("run dispatcher");
+    let output = destination.finish().unwrap();
+
+    let reader = StreamReader::try_new(Cursor::new(output), None).unwrap();
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(2, batches.len());
+    for rb in batches {
+        let n = rb.num_rows() as i64;
+        assert!(n == 4 || n == 7);
+        assert!(rb
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .eq(&Int64Array::from((0..n).collect::<Vec<_>>())));
+        assert!(rb
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap()
+            .eq(&Float64Array::from(
+                (0..n).map(|i| i as f64).collect::<Vec<_>>()
+            )));
+        assert!(rb
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap()
+            .eq(&StringArray::from(
+                (0..n).map(|i| i.to_string()).collect::<Vec<_>>()
+            )));
+    }
+}
+
+#[test]
+fn test_arrow_ipc_empty_stream() {
+    let mut destination = ArrowIPCDestination::new(vec![], IPCFormat::Stream);
+    destination
+        .allocate(
+            0,
+            &["a"],
+            &[ArrowTypeSystem::Int64(false)],
+            DataOrder::RowMajor,
+        )
+        .unwrap();
+    let output = destination.finish().unwrap();
+
+    let reader = StreamReader::try_new(Cursor::new(output), None).unwrap();
+    assert_eq!("a", reader.schema().field(0).name());
+    assert_eq!(0, reader.count());
+}
+
+#[test]
+fn test_postgres_arrow_ipc_file() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [
+        CXQuery::naked("select * from test_table where test_int < 2"),
+        CXQuery::naked("select * from test_table where test_int >= 2"),
+    ];
+    let url = Url::parse(dburl.as_str()).unwrap();
+    let (config, _tls) = rewrite_tls_args(&url).unwrap();
+    let builder = PostgresSource::<BinaryProtocol, NoTls>::new(config, NoTls, 2).unwrap();
+
+    let path = env::temp_dir().join("connectorx_dst_postgres.arrow");
+    let mut destination = ArrowIPCDestination::create(&path).unwrap();
+    let dispatcher = Dispatcher::<
+        _,
+        _,
+        ArrowIPCTransport<PostgresArrowTransport<BinaryProtocol, NoTls>, File>,
+    >::new(builder, &mut destination, &queries, None);
+    dispatcher.run().expect("run dispatcher");
+    destination.finish().unwrap();
+
+    let reader = FileReader::try_new(File::open(&path).unwrap(), None).unwrap();
+    assert_eq!(2, reader.num_batches());
+    let rb = concat(reader.collect::<Result<Vec<_>, _>>().unwrap());
+    let names: Vec<_> = rb
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect();
+    assert_eq!(
+        vec![
+            "test_int",
+            "test_nullint",
+            "test_str",
+            "test_float",
+            "test_bool"
+        ],
+        names
+    );
+    assert_eq!(6, rb.num_rows());
+    assert_eq!(1, rb.column(2).null_count());
+}
+
+#[test]
+fn test_postgres_arrow_ipc_file_enum() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    // the partitions see different enum values, so their dictionaries differ
+    let queries = [
+        CXQuery::naked("select test_int16, test_enum from test_types where test_int16 < 2"),
+        CXQuery::naked("select test_int16, test_enum from test_types where test_int16 >= 2"),
+    ];
+    let url = Url::parse(dburl.as_str()).unwrap();
+    let (config, _tls) = rewrite_tls_args(&url).unwrap();
+    let builder = PostgresSource::<BinaryProtocol, NoTls>::new(config, NoTls, 2).unwrap();
+
+    let mut destination = ArrowIPCDestination::new(vec![], IPCFormat::File);
+    let dispatcher = Dispatcher::<
+        _,
+        _,
+        ArrowIPCTransport<PostgresArrowTransport<BinaryProtocol, NoTls>, Vec<u8>>,
+    >::new(builder, &mut destination, &queries, None);
+    dispatcher.run().expect("run dispatcher");
+    let output = destination.finish().unwrap();
+
+    let reader = FileReader::try_new(Cursor::new(output), None).unwrap();
+    assert_eq!(2, reader.num_batches());
+    let rb = concat(reader.collect::<Result<Vec<_>, _>>().unwrap());
+    let mut rows: Vec<_> = rb
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .iter()
+        .zip(
+            rb.column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap()
+                .iter(),
+        )
+        .map(|(i, s)| (i.unwrap(), s.map(str::to_string)))
+        .collect();
+    rows.sort();
+    assert_eq!(
+        vec![
+            (0, Some("happy".to_string())),
+            (1, Some("very happy".to_string())),
+            (2, Some("ecstatic".to_string())),
+            (3, None),
+        ],
+        rows
+    );
+}
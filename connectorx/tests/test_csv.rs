@@ -1,9 +1,16 @@
-use arrow::array::Int64Array;
+use arrow::array::{
+    Array, Date32Array, Decimal128Array, Float64Array, Int64Array, StringArray,
+    Time64NanosecondArray,
+};
+use chrono::{NaiveDate, NaiveTime};
 use connectorx::prelude::*;
 use connectorx::{
-    destinations::arrow::{ArrowDestination, ArrowTypeSystem},
+    destinations::{
+        arrow::{ArrowDestination, ArrowTypeSystem},
+        ArrowOptions,
+    },
     sources::{
-        csv::{CSVSource, CSVTypeSystem},
+        csv::{CSVOptions, CSVSource, CSVTypeSystem},
         PartitionParser,
     },
     sql::CXQuery,
@@ -168,3 +175,211 @@ fn test_csv_infer_schema() {
 
     assert_eq!(expected_schema, writer.schema());
 }
+
+#[test]
+fn test_csv_options() {
+    let files = [CXQuery::naked("./tests/data/dialect_0.csv")];
+    let source = CSVSource::with_options(
+        &[],
+        CSVOptions {
+            delimiter: b';',
+            has_header: false,
+            null_values: vec!["NA".into()],
+            infer_sample_size: None,
+            ..Default::default()
+        },
+    );
+
+    let mut writer = ArrowDestination::new_with_options(ArrowOptions {
+        decimal128: true,
+        ..Default::default()
+    });
+    let dispatcher = Dispatcher::<_, _, CSVArrowTransport>::new(source, &mut writer, &files, None);
+
+    dispatcher.run().expect("run dispatcher");
+
+    // the float in the last record is only seen with a full scan
+    let expected_schema = vec![
+        ArrowTypeSystem::Float64(false),
+        ArrowTypeSystem::Date32(true),
+        ArrowTypeSystem::Time64(false),
+        ArrowTypeSystem::Decimal(true),
+        ArrowTypeSystem::LargeUtf8(true),
+    ];
+    assert_eq!(expected_schema, writer.schema());
+
+    let result = writer.arrow().unwrap();
+    assert_eq!(1, result.len());
+    let rb = &result[0];
+    assert_eq!("column_0", rb.schema().field(0).name());
+
+    assert!(rb
+        .column(0)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap()
+        .eq(&Float64Array::from(vec![1.0, 2.0, 3.0, 4.5])));
+
+    let dates = rb.column(1).as_any().downcast_ref::<Date32Array>().unwrap();
+    assert_eq!(NaiveDate::from_ymd_opt(2021, 1, 2), dates.value_as_date(0));
+    assert!(dates.is_null(2));
+
+    let times = rb
+        .column(2)
+        .as_any()
+        .downcast_ref::<Time64NanosecondArray>()
+        .unwrap();
+    assert_eq!(
+        NaiveTime::from_hms_milli_opt(11, 0, 0, 500),
+        times.value_as_time(1)
+    );
+
+    // mixed with the other numbers, the long decimal keeps all its digits
+    let decimals = rb
+        .column(3)
+        .as_any()
+        .downcast_ref::<Decimal128Array>()
+        .unwrap();
+    assert_eq!("12345678901234567.2500000000", decimals.value_as_string(0));
+    assert_eq!("0.5000000000", decimals.value_as_string(1));
+    assert!(decimals.is_null(2));
+    assert_eq!("1.7500000000", decimals.value_as_string(3));
+
+    assert!(rb
+        .column(4)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec![
+            None,
+            Some("b"),
+            Some("c"),
+            Some("d")
+        ])));
+}
+
+#[test]
+fn test_csv_byte_range_partitions() {
+    let path = std::env::temp_dir().join("connectorx_test_csv_byte_range_partitions.csv");
+    let mut content = String::from("id,name\n");
+    let mut expected = vec![];
+    for i in 0..100 {
+        // quoted fields with line breaks must not be taken as record boundaries
+        let name = if i % 7 == 0 {
+            format!("line\n{}", i)
+        } else {
+            format!("name{}", i)
+        };
+        content.push_str(&format!("{},\"{}\"\n", i, name));
+        expected.push((i as i64, name));
+    }
+    std::fs::write(&path, content).unwrap();
+
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+    let source = CSVSource::new_with_partitions(
+        &[CSVTypeSystem::I64(false), CSVTypeSystem::String(false)],
+        CSVOptions::default(),
+        4,
+    );
+
+    let mut writer = ArrowDestination::new();
+    let dispatcher = Dispatcher::<_, _, CSVArrowTransport>::new(source, &mut writer, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let result = writer.arrow().unwrap();
+    assert_eq!(4, result.len());
+
+    let mut rows = vec![];
+    for rb in result {
+        let ids = rb.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        let names = rb.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        for i in 0..rb.num_rows() {
+            rows.push((ids.value(i), names.value(i).to_string()));
+        }
+    }
+    rows.sort();
+    assert_eq!(expected, rows);
+}
+
+#[test]
+fn test_csv_byte_range_quoted_records() {
+    let path = std::env::temp_dir().join("connectorx_test_csv_byte_range_quoted_records.csv");
+    // the quoted field spans the middle of the file and its lines look like records
+    let note: String = (0..50).map(|i| format!("{},fake\n", i)).collect();
+    let content = format!("id,note\n1,first\n2,\"{}\"\n3,last\n", note);
+    std::fs::write(&path, content).unwrap();
+
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+    let source = CSVSource::new_with_partitions(
+        &[CSVTypeSystem::I64(false), CSVTypeSystem::String(false)],
+        CSVOptions::default(),
+        2,
+    );
+
+    let mut writer = ArrowDestination::new();
+    let dispatcher = Dispatcher::<_, _, CSVArrowTransport>::new(source, &mut writer, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let mut rows = vec![];
+    for rb in writer.arrow().unwrap() {
+        let ids = rb.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        let notes = rb.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        for i in 0..rb.num_rows() {
+            rows.push((ids.value(i), notes.value(i).to_string()));
+        }
+    }
+    rows.sort();
+    assert_eq!(
+        vec![(1, "first".to_string()), (2, note), (3, "last".to_string())],
+        rows
+    );
+}
+
+#[test]
+fn test_csv_byte_range_resync() {
+    let path = std::env::temp_dir().join("connectorx_test_csv_byte_range_resync.csv");
+    let mut content = String::new();
+    let mut expected = vec![];
+    for i in 0..200 {
+        // the split offsets land in quoted fields holding quotes, delimiters and line breaks
+        let name = match i % 3 {
+            0 => format!("say \"hi\",\r\n{}", i),
+            1 => format!("\"{}\"", i),
+            _ => format!("name{}", i),
+        };
+        content.push_str(&format!("{},\"{}\"\r\n", i, name.replace('"', "\"\"")));
+        expected.push((i as i64, name));
+    }
+    std::fs::write(&path, content).unwrap();
+
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+    let options = CSVOptions {
+        has_header: false,
+        ..Default::default()
+    };
+    for partitions in [2, 7, 50] {
+        let source = CSVSource::new_with_partitions(
+            &[CSVTypeSystem::I64(false), CSVTypeSystem::String(false)],
+            options.clone(),
+            partitions,
+        );
+
+        let mut writer = ArrowDestination::new();
+        let dispatcher =
+            Dispatcher::<_, _, CSVArrowTransport>::new(source, &mut writer, &files, None);
+        dispatcher.run().expect("run dispatcher");
+
+        let result = writer.arrow().unwrap();
+        assert_eq!(partitions, result.len());
+        let mut rows = vec![];
+        for rb in result {
+            let ids = rb.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+            let names = rb.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+            for i in 0..rb.num_rows() {
+                rows.push((ids.value(i), names.value(i).to_string()));
+            }
+        }
+        rows.sort();
+        assert_eq!(expected, rows);
+    }
+}
@@ -0,0 +1,117 @@
+use connectorx::{
+    destinations::csv::{CSVDestination, CSVWriteOptions, QuoteStyle},
+    prelude::*,
+    sources::{
+        dummy::{DummySource, DummyTypeSystem},
+        postgres::{rewrite_tls_args, BinaryProtocol, PostgresSource},
+    },
+    sql::CXQuery,
+    transports::{CSVTransport, DummyArrowTransport, PostgresArrowTransport},
+};
+use postgres::NoTls;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use url::Url;
+
+fn temp_path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("connectorx_dst_{}", name));
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn test_csv_file_per_partition() {
+    let schema = [
+        DummyTypeSystem::I64(false),
+        DummyTypeSystem::F64(true),
+        DummyTypeSystem::String(true),
+    ];
+    let queries = [CXQuery::naked("2,3"), CXQuery::naked("3,3")];
+    let path = temp_path("partitions_csv");
+    let options = CSVWriteOptions {
+        file_per_partition: true,
+        ..Default::default()
+    };
+    let mut destination = CSVDestination::new_with_options(&path, options);
+    let dispatcher = Dispatcher::<_, _, CSVTransport<DummyArrowTransport>>::new(
+        DummySource::new(&["a", "b", "c"], &schema),
+        &mut destination,
+        &queries,
+        None,
+    );
+    dispatcher.run().expect("run dispatcher");
+    let files = destination.finish().unwrap();
+    assert_eq!(
+        vec![path.join("part-0.csv"), path.join("part-1.csv")],
+        files
+    );
+
+    assert_eq!(
+        "a,b,c\n0,0.0,0\n1,1.0,1\n",
+        fs::read_to_string(&files[0]).unwrap()
+    );
+    assert_eq!(
+        "a,b,c\n0,0.0,0\n1,1.0,1\n2,2.0,2\n",
+        fs::read_to_string(&files[1]).unwrap()
+    );
+}
+
+#[test]
+fn test_postgres_csv_dialect() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [
+        CXQuery::naked(
+            "select test_int, test_nullint, test_str, '2021-03-04'::date as test_date \
+             from test_table where test_int < 2",
+        ),
+        CXQuery::naked(
+            "select test_int, test_nullint, test_str, '2021-03-04'::date as test_date \
+             from test_table where test_int >= 2",
+        ),
+    ];
+    let url = Url::parse(dburl.as_str()).unwrap();
+    let (config, _tls) = rewrite_tls_args(&url).unwrap();
+    let builder = PostgresSource::<BinaryProtocol, NoTls>::new(config, NoTls, 2).unwrap();
+
+    let path = temp_path("postgres.csv");
+    let options = CSVWriteOptions {
+        delimiter: b'|',
+        quote_style: QuoteStyle::NonNumeric,
+        null_value: "NULL".to_string(),
+        date_format: Some("%d/%m/%Y".to_string()),
+        ..Default::default()
+    };
+    let mut destination = CSVDestination::new_with_options(&path, options);
+    let dispatcher =
+        Dispatcher::<_, _, CSVTransport<PostgresArrowTransport<BinaryProtocol, NoTls>>>::new(
+            builder,
+            &mut destination,
+            &queries,
+            None,
+        );
+    dispatcher.run().expect("run dispatcher");
+    destination.finish().unwrap();
+
+    let content = fs::read_to_string(&path).unwrap();
+    let mut lines: Vec<_> = content.lines().collect();
+    assert_eq!(
+        "\"test_int\"|\"test_nullint\"|\"test_str\"|\"test_date\"",
+        lines.remove(0)
+    );
+    lines.sort_unstable();
+    assert_eq!(
+        vec![
+            "0|5|\"a\"|\"04/03/2021\"",
+            "1314|2|\"NULL\"|\"04/03/2021\"",
+            "1|3|\"str1\"|\"04/03/2021\"",
+            "2|\"NULL\"|\"str2\"|\"04/03/2021\"",
+            "3|7|\"b\"|\"04/03/2021\"",
+            "4|9|\"c\"|\"04/03/2021\"",
+        ],
+        lines
+    );
+}
@@ -0,0 +1,140 @@
+use arrow::{
+    array::{Float64Array, Int64Array, StringArray},
+    record_batch::RecordBatch,
+};
+use connectorx::{
+    destinations::arrow::ArrowDestination,
+    get_arrow::get_arrow,
+    partition::{get_col_range, partition, PartitionQuery},
+    prelude::*,
+    source_router::SourceConn,
+    sources::duckdb::DuckDBSource,
+    sql::CXQuery,
+    transports::DuckDBArrowTransport,
+};
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+fn setup_db(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("connectorx_{}.duckdb", name));
+    let _ = std::fs::remove_file(&path);
+
+    let conn = duckdb::Connection::open(&path).unwrap();
+    conn.execute_batch(
+        "CREATE TABLE test_table(test_int BIGINT, test_float DOUBLE, test_str VARCHAR);
+         INSERT INTO test_table VALUES
+            (1, 1.1, 'a'),
+            (2, 2.2, 'b'),
+            (3, NULL, 'c'),
+            (4, 4.4, NULL);",
+    )
+    .unwrap();
+    path
+}
+
+#[test]
+fn test_duckdb() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_db("test_duckdb");
+
+    let queries = [
+        CXQuery::naked("select * from test_table where test_int <= 2 order by test_int"),
+        CXQuery::naked("select * from test_table where test_int > 2 order by test_int"),
+    ];
+
+    let builder = DuckDBSource::new(path.to_str().unwrap(), 2).unwrap();
+    let mut destination = ArrowDestination::new();
+    let dispatcher = Dispatcher::<_, _, DuckDBArrowTransport>::new(
+        builder,
+        &mut destination,
+        &queries,
+        Some(String::from("select * from test_table")),
+    );
+    dispatcher.run().unwrap();
+
+    let result = destination.arrow().unwrap();
+    verify_arrow_results(result);
+}
+
+#[test]
+fn test_duckdb_partition() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_db("test_duckdb_partition");
+    let source_conn =
+        SourceConn::try_from(format!("duckdb://{}", path.to_str().unwrap()).as_str()).unwrap();
+
+    let query = "select * from test_table";
+    assert_eq!(
+        (1, 4),
+        get_col_range(&source_conn, query, "test_int").unwrap()
+    );
+
+    let queries = partition(
+        &PartitionQuery::new(query, "test_int", None, None, 2),
+        &source_conn,
+    )
+    .unwrap();
+    assert_eq!(2, queries.len());
+
+    let destination = get_arrow(&source_conn, None, &queries).unwrap();
+    let result = destination.arrow().unwrap();
+    verify_arrow_results(result);
+}
+
+pub fn verify_arrow_results(result: Vec<RecordBatch>) {
+    assert!(result.len() == 2);
+
+    for r in result {
+        match r
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .value(0)
+        {
+            1 => {
+                assert!(r
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap()
+                    .eq(&Int64Array::from(vec![1, 2])));
+                assert!(r
+                    .column(1)
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .unwrap()
+                    .eq(&Float64Array::from(vec![1.1, 2.2])));
+                assert!(r
+                    .column(2)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap()
+                    .eq(&StringArray::from(vec!["a", "b"])));
+            }
+            3 => {
+                assert!(r
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int64Array>()
+                    .unwrap()
+                    .eq(&Int64Array::from(vec![3, 4])));
+                assert!(r
+                    .column(1)
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .unwrap()
+                    .eq(&Float64Array::from(vec![None, Some(4.4)])));
+                assert!(r
+                    .column(2)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap()
+                    .eq(&StringArray::from(vec![Some("c"), None])));
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use arrow::util::pretty::pretty_format_batches;
+use connectorx::{fed_provider::ConnectorXTableProvider, source_router::SourceConn};
+use datafusion::datasource::TableProvider;
+use datafusion::physical_plan::displayable;
+use datafusion::prelude::SessionContext;
+use std::convert::TryFrom;
+use std::env;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+fn context(partition: Option<(&str, usize)>) -> SessionContext {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let mut provider = ConnectorXTableProvider::try_new(conn, "test_table").unwrap();
+    if let Some((column, num)) = partition {
+        provider = provider.with_partition(column, num);
+    }
+    let ctx = SessionContext::new();
+    ctx.register_table("test_table", Arc::new(provider))
+        .unwrap();
+    ctx
+}
+
+/// The pretty printed result and physical plan of `sql`.
+fn run(ctx: &SessionContext, sql: &str) -> (String, String) {
+    let rt = Runtime::new().unwrap();
+    rt.block_on(async {
+        let df = ctx.sql(sql).await.unwrap();
+        let plan = df.clone().create_physical_plan().await.unwrap();
+        let plan = displayable(plan.as_ref()).indent(true).to_string();
+        let rbs = df.collect().await.unwrap();
+        (pretty_format_batches(&rbs).unwrap().to_string(), plan)
+    })
+}
+
+#[test]
+fn test_provider_pushdown() {
+    let ctx = context(None);
+    let (result, plan) = run(
+        &ctx,
+        "SELECT test_int, test_str FROM test_table WHERE test_nullint > 4 ORDER BY test_int",
+    );
+    assert_eq!(
+        result,
+        [
+            "+----------+----------+",
+            "| test_int | test_str |",
+            "+----------+----------+",
+            "| 0        | a        |",
+            "| 3        | b        |",
+            "| 4        | c        |",
+            "+----------+----------+",
+        ]
+        .join("\n")
+    );
+    assert!(plan.contains(
+        r#"ConnectorXExec: partitions=1, query=SELECT "test_int", "test_nullint", "test_str" FROM test_table WHERE "test_nullint" > 4"#
+    ));
+    // the filter is evaluated by DataFusion as well
+    assert!(plan.contains("FilterExec: test_nullint@1 > 4"));
+}
+
+#[test]
+fn test_provider_limit() {
+    let ctx = context(Some(("test_int", 2)));
+    let (result, plan) = run(&ctx, "SELECT test_int FROM test_table LIMIT 2");
+    assert_eq!(result.lines().count(), 6);
+    // a limited scan is not partitioned
+    assert!(plan.contains(
+        r#"ConnectorXExec: partitions=1, query=SELECT "test_int" FROM test_table LIMIT 2"#
+    ));
+
+    // DataFusion does not push the limit past a filter
+    let (result, plan) = run(
+        &ctx,
+        "SELECT test_int FROM test_table WHERE test_str IN ('str1', 'str2') LIMIT 1",
+    );
+    assert_eq!(result.lines().count(), 5);
+    assert!(plan.contains(
+        r#"FROM test_table WHERE ("test_str" = 'str1') OR ("test_str" = 'str2')) AS CXTMPTAB_PART"#
+    ));
+}
+
+#[test]
+fn test_provider_partition() {
+    let ctx = context(Some(("test_int", 2)));
+    let (result, plan) = run(
+        &ctx,
+        "SELECT test_int FROM test_table WHERE test_nullint IS NOT NULL ORDER BY test_int",
+    );
+    assert_eq!(
+        result,
+        [
+            "+----------+",
+            "| test_int |",
+            "+----------+",
+            "| 0        |",
+            "| 1        |",
+            "| 3        |",
+            "| 4        |",
+            "| 1314     |",
+            "+----------+",
+        ]
+        .join("\n")
+    );
+    assert!(plan.contains("ConnectorXExec: partitions=2"));
+    assert!(plan.contains(
+        r#"FROM test_table WHERE "test_nullint" IS NOT NULL) AS CXTMPTAB_PART WHERE 0 <= CXTMPTAB_PART.test_int AND CXTMPTAB_PART.test_int < 657"#
+    ));
+
+    // the partition column is read even if it is not projected
+    let (result, plan) = run(
+        &ctx,
+        "SELECT test_str FROM test_table WHERE test_float > 3 ORDER BY test_str",
+    );
+    assert_eq!(
+        result,
+        [
+            "+----------+",
+            "| test_str |",
+            "+----------+",
+            "| a        |",
+            "| c        |",
+            "+----------+",
+        ]
+        .join("\n")
+    );
+    assert!(plan.contains(r#"SELECT "test_str", "test_float", test_int FROM test_table"#));
+}
+
+#[test]
+fn test_provider_count() {
+    let ctx = context(Some(("test_int", 3)));
+    let (result, _) = run(&ctx, "SELECT COUNT(*) AS n FROM test_table");
+    assert_eq!(
+        result,
+        ["+---+", "| n |", "+---+", "| 6 |", "+---+"].join("\n")
+    );
+}
+
+#[test]
+fn test_provider_table_name() {
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let provider =
+        ConnectorXTableProvider::try_new(conn.clone(), r#"public."test_table""#).unwrap();
+    assert_eq!(provider.schema().fields().len(), 5);
+    assert!(ConnectorXTableProvider::try_new(conn.clone(), "test_missing").is_err());
+    assert!(ConnectorXTableProvider::try_new(conn, "test_table; DROP TABLE test_table").is_err());
+}
@@ -0,0 +1,138 @@
+use arrow::array::{BooleanArray, Float64Array, Int64Array, StringArray};
+use connectorx::{
+    destinations::arrow::{ArrowDestination, ArrowTypeSystem},
+    prelude::*,
+    sources::jsonl::JSONLSource,
+    sql::CXQuery,
+    transports::JSONLArrowTransport,
+};
+use std::path::PathBuf;
+
+fn setup_file(name: &str, content: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("connectorx_{}.jsonl", name));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_jsonl() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_file(
+        "test_jsonl",
+        r#"{"id": 1, "score": 1, "ok": true, "user": {"name": "a", "address": {"city": "x"}}, "tags": ["t1"]}
+{"id": 2, "score": 2.5, "ok": null, "user": {"name": "b"}, "tags": []}
+
+{"id": 3, "score": null, "ok": false, "user": {"name": null, "address": {"city": "z"}}, "tags": "t3", "extra": 1}
+"#,
+    );
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    let source = JSONLSource::new(None);
+    let mut destination = ArrowDestination::new();
+    let dispatcher =
+        Dispatcher::<_, _, JSONLArrowTransport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let schema = destination.schema().to_vec();
+    let result = destination.arrow().unwrap();
+    assert_eq!(1, result.len());
+    let rb = &result[0];
+    // field order inside an object is up to serde_json, look the columns up by name
+    let column = |name: &str| {
+        let idx = rb.schema().index_of(name).unwrap();
+        (schema[idx], rb.column(idx).clone())
+    };
+    assert_eq!(7, rb.num_columns());
+
+    let (ty, col) = column("id");
+    assert_eq!(ArrowTypeSystem::Int64(false), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .eq(&Int64Array::from(vec![1, 2, 3])));
+
+    // integers and floats are merged into floats
+    let (ty, col) = column("score");
+    assert_eq!(ArrowTypeSystem::Float64(true), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap()
+        .eq(&Float64Array::from(vec![Some(1.0), Some(2.5), None])));
+
+    let (ty, col) = column("ok");
+    assert_eq!(ArrowTypeSystem::Boolean(true), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .unwrap()
+        .eq(&BooleanArray::from(vec![Some(true), None, Some(false)])));
+
+    let (ty, col) = column("user.name");
+    assert_eq!(ArrowTypeSystem::LargeUtf8(true), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec![Some("a"), Some("b"), None])));
+
+    // missing nested fields are null
+    let (ty, col) = column("user.address.city");
+    assert_eq!(ArrowTypeSystem::LargeUtf8(true), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec![Some("x"), None, Some("z")])));
+
+    // arrays are kept as JSON text
+    let (ty, col) = column("tags");
+    assert_eq!(ArrowTypeSystem::LargeUtf8(false), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec![r#"["t1"]"#, "[]", "t3"])));
+
+    let (ty, col) = column("extra");
+    assert_eq!(ArrowTypeSystem::Int64(true), ty);
+    assert!(col
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .eq(&Int64Array::from(vec![None, None, Some(1)])));
+}
+
+#[test]
+fn test_jsonl_byte_range_partitions() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let content: String = (0..100)
+        .map(|i| format!("{{\"id\": {}, \"name\": \"name{}\"}}\n", i, i))
+        .collect();
+    let path = setup_file("test_jsonl_byte_range_partitions", &content);
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    let source = JSONLSource::new_with_partitions(Some(10), 3);
+    let mut destination = ArrowDestination::new();
+    let dispatcher =
+        Dispatcher::<_, _, JSONLArrowTransport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let result = destination.arrow().unwrap();
+    assert_eq!(3, result.len());
+
+    let mut rows = vec![];
+    for rb in result {
+        let ids = rb.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        let names = rb.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        for i in 0..rb.num_rows() {
+            rows.push((ids.value(i), names.value(i).to_string()));
+        }
+    }
+    rows.sort();
+    let expected: Vec<_> = (0..100).map(|i| (i, format!("name{}", i))).collect();
+    assert_eq!(expected, rows);
+}
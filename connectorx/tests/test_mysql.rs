@@ -1,14 +1,16 @@
 use arrow::{
     array::{Float64Array, Int64Array, StringArray},
+    datatypes::DataType,
     record_batch::RecordBatch,
 };
 use connectorx::{
-    destinations::arrow::ArrowDestination,
+    destinations::{arrow::ArrowDestination, ArrowOptions},
     prelude::*,
     sources::mysql::{BinaryProtocol, MySQLSource, TextProtocol},
     sql::CXQuery,
     transports::MySQLArrowTransport,
 };
+use std::convert::TryFrom;
 use std::env;
 
 #[test]
@@ -61,6 +63,43 @@ fn test_mysql_text() {
     verify_arrow_results(result);
 }
 
+#[test]
+fn test_mysql_keep_width() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("MYSQL_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_tiny, test_short, test_long, test_float from test_types",
+    )];
+    let options = ArrowOptions {
+        keep_width: true,
+        ..Default::default()
+    };
+
+    for protocol in ["binary", "text"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let schema = rbs[0].schema();
+        let types: Vec<_> = schema.fields().iter().map(|f| f.data_type()).collect();
+        // TINYINT is read as a boolean, only integer columns keep their width
+        assert_eq!(
+            vec![
+                &DataType::Boolean,
+                &DataType::Int16,
+                &DataType::Int32,
+                &DataType::Float32
+            ],
+            types,
+            "{} protocol",
+            protocol
+        );
+    }
+}
+
 pub fn verify_arrow_results(result: Vec<RecordBatch>) {
     assert!(result.len() == 2);
 
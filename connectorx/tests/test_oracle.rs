@@ -1,6 +1,9 @@
+use arrow::array::Decimal128Array;
+use connectorx::destinations::{arrow::ArrowDestination, ArrowOptions};
 use connectorx::prelude::*;
 use connectorx::sources::oracle::OracleSource;
 use connectorx::sql::CXQuery;
+use connectorx::transports::OracleArrowTransport;
 use std::env;
 
 #[test]
@@ -79,3 +82,35 @@ fn test_types() {
         rows
     );
 }
+
+#[test]
+#[ignore]
+fn test_oracle_decimal128() {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let dburl = env::var("ORACLE_URL").unwrap();
+
+    let queries = [CXQuery::naked(
+        "select test_num_float from admin.test_types where test_num_int = 1",
+    )];
+    let source = OracleSource::new(&dburl, 1).unwrap();
+    let mut destination = ArrowDestination::new_with_options(ArrowOptions {
+        decimal128: true,
+        ..Default::default()
+    });
+    let dispatcher =
+        Dispatcher::<_, _, OracleArrowTransport>::new(source, &mut destination, &queries, None);
+    dispatcher.run().unwrap();
+
+    // NUMBER(10,1) keeps its precision and scale
+    let result = destination.arrow().unwrap();
+    assert_eq!(
+        &Decimal128Array::from(vec![Some(23)])
+            .with_precision_and_scale(10, 1)
+            .unwrap(),
+        result[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap()
+    );
+}
@@ -0,0 +1,259 @@
+use arrow::{
+    array::{Date32Array, Float64Array, Int64Array, StringArray},
+    record_batch::RecordBatch,
+};
+use chrono::NaiveDate;
+use connectorx::{
+    destinations::arrow::ArrowDestination, prelude::*, sources::parquet::ParquetSource,
+    sql::CXQuery, transports::ParquetArrowTransport,
+};
+use parquet::{
+    data_type::{ByteArray, ByteArrayType, DoubleType, Int32Type, Int64Type},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::parser::parse_message_type,
+};
+use std::fs::File;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Write a file of 4 rows split in 2 row groups.
+fn setup_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("connectorx_{}.parquet", name));
+    let schema = Arc::new(
+        parse_message_type(
+            "message schema {
+                REQUIRED INT64 test_int;
+                OPTIONAL DOUBLE test_float;
+                OPTIONAL BYTE_ARRAY test_str (UTF8);
+                REQUIRED INT32 test_date (DATE);
+            }",
+        )
+        .unwrap(),
+    );
+    let props = Arc::new(WriterProperties::builder().build());
+    let mut writer =
+        SerializedFileWriter::new(File::create(&path).unwrap(), schema, props).unwrap();
+
+    let groups: [(&[i64], &[f64], &[i16], &[&str], &[i16], &[i32]); 2] = [
+        (&[1, 2], &[1.1, 2.2], &[1, 1], &["a", "b"], &[1, 1], &[0, 1]),
+        (&[3, 4], &[4.4], &[0, 1], &["c"], &[1, 0], &[2, 3]),
+    ];
+    for (ints, floats, float_defs, strs, str_defs, dates) in groups {
+        let mut rg = writer.next_row_group().unwrap();
+
+        let mut col = rg.next_column().unwrap().unwrap();
+        col.typed::<Int64Type>()
+            .write_batch(ints, None, None)
+            .unwrap();
+        col.close().unwrap();
+
+        let mut col = rg.next_column().unwrap().unwrap();
+        col.typed::<DoubleType>()
+            .write_batch(floats, Some(float_defs), None)
+            .unwrap();
+        col.close().unwrap();
+
+        let strs: Vec<ByteArray> = strs.iter().map(|&s| s.into()).collect();
+        let mut col = rg.next_column().unwrap().unwrap();
+        col.typed::<ByteArrayType>()
+            .write_batch(&strs, Some(str_defs), None)
+            .unwrap();
+        col.close().unwrap();
+
+        let mut col = rg.next_column().unwrap().unwrap();
+        col.typed::<Int32Type>()
+            .write_batch(dates, None, None)
+            .unwrap();
+        col.close().unwrap();
+
+        rg.close().unwrap();
+    }
+    writer.close().unwrap();
+    path
+}
+
+#[test]
+fn test_parquet_row_groups() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_file("test_parquet_row_groups");
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    let source = ParquetSource::new(None, true);
+    let mut destination = ArrowDestination::new();
+    let dispatcher =
+        Dispatcher::<_, _, ParquetArrowTransport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let result = destination.arrow().unwrap();
+    assert_eq!(2, result.len());
+    verify_arrow_results(result);
+}
+
+#[test]
+fn test_parquet_projection() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_file("test_parquet_projection");
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    let source = ParquetSource::new(Some(&["test_str", "test_int"]), false);
+    let mut destination = ArrowDestination::new();
+    let dispatcher =
+        Dispatcher::<_, _, ParquetArrowTransport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let result = destination.arrow().unwrap();
+    assert_eq!(1, result.len());
+    let rb = &result[0];
+    assert_eq!(2, rb.num_columns());
+    assert_eq!("test_str", rb.schema().field(0).name());
+    assert!(rb
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None
+        ])));
+    assert!(rb
+        .column(1)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .eq(&Int64Array::from(vec![1, 2, 3, 4])));
+}
+
+/// Write a file with a top-level column and a struct holding a column of the same name.
+fn setup_nested_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("connectorx_{}.parquet", name));
+    let schema = Arc::new(
+        parse_message_type(
+            "message schema {
+                REQUIRED INT64 x;
+                OPTIONAL group s {
+                    OPTIONAL INT64 x;
+                }
+            }",
+        )
+        .unwrap(),
+    );
+    let props = Arc::new(WriterProperties::builder().build());
+    let mut writer =
+        SerializedFileWriter::new(File::create(&path).unwrap(), schema, props).unwrap();
+    let mut rg = writer.next_row_group().unwrap();
+
+    let mut col = rg.next_column().unwrap().unwrap();
+    col.typed::<Int64Type>()
+        .write_batch(&[1, 2], None, None)
+        .unwrap();
+    col.close().unwrap();
+
+    let mut col = rg.next_column().unwrap().unwrap();
+    col.typed::<Int64Type>()
+        .write_batch(&[10], Some(&[2, 0]), None)
+        .unwrap();
+    col.close().unwrap();
+
+    rg.close().unwrap();
+    writer.close().unwrap();
+    path
+}
+
+#[test]
+fn test_parquet_nested() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = setup_nested_file("test_parquet_nested");
+    let files = [CXQuery::naked(path.to_str().unwrap())];
+
+    for projection in [None, Some(&["s"][..])] {
+        let source = ParquetSource::new(projection, false);
+        let mut destination = ArrowDestination::new();
+        let dispatcher =
+            Dispatcher::<_, _, ParquetArrowTransport>::new(source, &mut destination, &files, None);
+        let err = dispatcher.run().unwrap_err();
+        assert!(
+            err.to_string().contains("nested column \"s.x\""),
+            "unexpected error {}",
+            err
+        );
+    }
+
+    // the top-level column is not confused with the struct field of the same name
+    let source = ParquetSource::new(Some(&["x"]), false);
+    let mut destination = ArrowDestination::new();
+    let dispatcher =
+        Dispatcher::<_, _, ParquetArrowTransport>::new(source, &mut destination, &files, None);
+    dispatcher.run().expect("run dispatcher");
+
+    let result = destination.arrow().unwrap();
+    assert!(result[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .eq(&Int64Array::from(vec![1, 2])));
+}
+
+pub fn verify_arrow_results(result: Vec<RecordBatch>) {
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    for r in result {
+        match r
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .value(0)
+        {
+            1 => {
+                assert!(r
+                    .column(1)
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .unwrap()
+                    .eq(&Float64Array::from(vec![1.1, 2.2])));
+                assert!(r
+                    .column(2)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap()
+                    .eq(&StringArray::from(vec!["a", "b"])));
+                assert_eq!(
+                    Some(epoch),
+                    r.column(3)
+                        .as_any()
+                        .downcast_ref::<Date32Array>()
+                        .unwrap()
+                        .value_as_date(0)
+                );
+            }
+            3 => {
+                assert!(r
+                    .column(1)
+                    .as_any()
+                    .downcast_ref::<Float64Array>()
+                    .unwrap()
+                    .eq(&Float64Array::from(vec![None, Some(4.4)])));
+                assert!(r
+                    .column(2)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap()
+                    .eq(&StringArray::from(vec![Some("c"), None])));
+                assert_eq!(
+                    NaiveDate::from_ymd_opt(1970, 1, 4),
+                    r.column(3)
+                        .as_any()
+                        .downcast_ref::<Date32Array>()
+                        .unwrap()
+                        .value_as_date(1)
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use arrow::{
+    array::{Float64Array, Int64Array, StringArray},
+    compute::concat_batches,
+    record_batch::RecordBatch,
+};
+use connectorx::{
+    destinations::parquet::{ParquetDestination, ParquetOptions},
+    prelude::*,
+    sources::{
+        dummy::{DummySource, DummyTypeSystem},
+        postgres::{rewrite_tls_args, BinaryProtocol, PostgresSource},
+    },
+    sql::CXQuery,
+    transports::{DummyArrowTransport, ParquetTransport, PostgresArrowTransport},
+};
+use parquet::{
+    arrow::arrow_reader::ParquetRecordBatchReaderBuilder,
+    basic::{Compression, ZstdLevel},
+};
+use postgres::NoTls;
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use url::Url;
+
+fn temp_path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("connectorx_dst_{}", name));
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+/// Write the dummy partitions of 4 and 7 rows to `path`.
+fn write_dummy(path: &Path, options: ParquetOptions) -> Vec<PathBuf> {
+    let schema = [
+        DummyTypeSystem::I64(false),
+        DummyTypeSystem::F64(true),
+        DummyTypeSystem::String(true),
+    ];
+    let queries = [CXQuery::naked("4,3"), CXQuery::naked("7,3")];
+    let mut destination = ParquetDestination::new_with_options(path, options);
+    let dispatcher = Dispatcher::<_, _, ParquetTransport<DummyArrowTransport>>::new(
+        DummySource::new(&["a", "b", "c"], &schema),
+        &mut destination,
+        &queries,
+        None,
+    );
+    dispatcher.run().expect("run dispatcher");
+    destination.finish().unwrap()
+}
+
+/// Read a file back, with the number of rows of each of its row groups.
+fn read_file(path: &Path) -> (RecordBatch, Vec<i64>) {
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap();
+    let row_groups = builder
+        .metadata()
+        .row_groups()
+        .iter()
+        .map(|rg| rg.num_rows())
+        .collect();
+    let schema = builder.schema().clone();
+    let batches = builder
+        .build()
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    (concat_batches(&schema, &batches).unwrap(), row_groups)
+}
+
+#[test]
+fn test_parquet_single_file() {
+    let path = temp_path("single.parquet");
+    let options = ParquetOptions {
+        compression: Compression::ZSTD(ZstdLevel::default()),
+        row_group_size: 3,
+        ..Default::default()
+    };
+    let files = write_dummy(&path, options);
+    assert_eq!(vec![path.clone()], files);
+
+    let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+    for column in builder.metadata().row_group(0).columns() {
+        assert_eq!(
+            Compression::ZSTD(ZstdLevel::default()),
+            column.compression()
+        );
+    }
+
+    let (rb, row_groups) = read_file(&path);
+    assert_eq!(vec![3, 3, 3, 2], row_groups);
+    assert_eq!(11, rb.num_rows());
+    let mut ids = rb
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .values()
+        .to_vec();
+    ids.sort_unstable();
+    assert_eq!(vec![0, 0, 1, 1, 2, 2, 3, 3, 4, 5, 6], ids);
+}
+
+#[test]
+fn test_parquet_file_per_partition() {
+    let path = temp_path("partitions");
+    let options = ParquetOptions {
+        row_group_size: 3,
+        file_per_partition: true,
+        ..Default::default()
+    };
+    let files = write_dummy(&path, options);
+    assert_eq!(
+        vec![path.join("part-0.parquet"), path.join("part-1.parquet")],
+        files
+    );
+
+    let (rb, row_groups) = read_file(&files[0]);
+    assert_eq!(vec![3, 1], row_groups);
+    assert!(rb
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .eq(&Int64Array::from(vec![0, 1, 2, 3])));
+    assert!(rb
+        .column(1)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap()
+        .eq(&Float64Array::from(vec![0.0, 1.0, 2.0, 3.0])));
+    assert!(rb
+        .column(2)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec!["0", "1", "2", "3"])));
+
+    let (rb, row_groups) = read_file(&files[1]);
+    assert_eq!(vec![3, 3, 1], row_groups);
+    assert_eq!(7, rb.num_rows());
+}
+
+#[test]
+fn test_postgres_parquet() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [
+        CXQuery::naked("select * from test_table where test_int < 2"),
+        CXQuery::naked("select * from test_table where test_int >= 2"),
+    ];
+    let url = Url::parse(dburl.as_str()).unwrap();
+    let (config, _tls) = rewrite_tls_args(&url).unwrap();
+    let builder = PostgresSource::<BinaryProtocol, NoTls>::new(config, NoTls, 2).unwrap();
+
+    let path = temp_path("postgres.parquet");
+    let mut destination = ParquetDestination::new(&path);
+    let dispatcher = Dispatcher::<
+        _,
+        _,
+        ParquetTransport<PostgresArrowTransport<BinaryProtocol, NoTls>>,
+    >::new(builder, &mut destination, &queries, None);
+    dispatcher.run().expect("run dispatcher");
+    destination.finish().unwrap();
+
+    let (rb, _) = read_file(&path);
+    let names: Vec<_> = rb
+        .schema()
+        .fields()
+        .iter()
+        .map(|f| f.name().clone())
+        .collect();
+    assert_eq!(
+        vec![
+            "test_int",
+            "test_nullint",
+            "test_str",
+            "test_float",
+            "test_bool"
+        ],
+        names
+    );
+    assert_eq!(6, rb.num_rows());
+    assert_eq!(1, rb.column(2).null_count());
+}
@@ -1,4 +1,5 @@
 use connectorx::{
+    arrow2_batch_iter::Arrow2BatchIter,
     constants::RECORD_BATCH_SIZE,
     destinations::arrow2::Arrow2Destination,
     prelude::*,
@@ -11,6 +12,7 @@ use connectorx::{
 };
 use polars::{df, prelude::*};
 use postgres::NoTls;
+use std::convert::TryFrom;
 use std::env;
 use url::Url;
 
@@ -92,6 +94,85 @@ fn test_polars_large() {
     assert_eq!(5, df.width());
 }
 
+#[test]
+fn test_polars_stream() {
+    let schema = [DummyTypeSystem::I64(true), DummyTypeSystem::String(true)];
+    let nrows = [4, 7];
+    let ncols = schema.len();
+    let queries: Vec<CXQuery> = nrows
+        .iter()
+        .map(|v| CXQuery::naked(format!("{},{}", v, ncols)))
+        .collect();
+    let destination = Arrow2Destination::new_streaming(3, ArrowOptions::default());
+
+    let mut iter = Arrow2BatchIter::<_, DummyArrow2Transport>::new(
+        DummySource::new(&["a", "b"], &schema),
+        destination,
+        None,
+        &queries,
+    )
+    .unwrap();
+    let (arrow_schema, names) = iter.get_schema();
+    assert_eq!(2, arrow_schema.fields.len());
+    assert_eq!(&["a", "b"], names);
+
+    iter.prepare();
+    let mut heights = vec![];
+    let mut a = vec![];
+    while let Some(df) = iter.next_polars().unwrap() {
+        assert_eq!(2, df.width());
+        heights.push(df.height());
+        a.extend(df.column("a").unwrap().i64().unwrap().into_no_null_iter());
+    }
+    heights.sort();
+    a.sort();
+    // each partition is split in chunks of at most 3 rows
+    assert_eq!(vec![1, 1, 3, 3, 3], heights);
+    assert_eq!(vec![0, 0, 1, 1, 2, 2, 3, 3, 4, 5, 6], a);
+}
+
+#[test]
+fn test_postgres_arrow2_stream() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let queries = [
+        CXQuery::naked("select * from test_table where test_int < 2"),
+        CXQuery::naked("select * from test_table where test_int >= 2"),
+    ];
+    let mut iter = new_arrow2_batch_iter(&conn, None, &queries, 2).unwrap();
+
+    let mut test_int = vec![];
+    let mut nchunks = 0;
+    while let Some(df) = iter.next_polars().unwrap() {
+        assert!(df.height() <= 2);
+        nchunks += 1;
+        test_int.extend(
+            df.column("test_int")
+                .unwrap()
+                .i32()
+                .unwrap()
+                .into_no_null_iter(),
+        );
+    }
+    test_int.sort();
+    assert_eq!(vec![0, 1, 2, 3, 4, 1314], test_int);
+    assert!(nchunks >= 3);
+
+    let queries = [CXQuery::naked("select * from no_such_table")];
+    assert!(new_arrow2_batch_iter(&conn, None, &queries, 2).is_err());
+
+    let conn = SourceConn::try_from(format!("{}?cxprotocol=nothing", dburl).as_str()).unwrap();
+    match new_arrow2_batch_iter(&conn, None, &queries, 2) {
+        Err(err) => assert_eq!(
+            "Source Postgres with protocol nothing not supported.",
+            err.to_string()
+        ),
+        Ok(_) => panic!("nothing is not a Postgres protocol"),
+    }
+}
+
 #[test]
 fn test_postgres_arrow() {
     let _ = env_logger::builder().is_test(true).try_init();
@@ -297,3 +378,46 @@ fn test_pg_pl_name() {
     println!("{:?}", df);
     assert_eq!(df, test_df);
 }
+
+#[test]
+fn test_pg_pl_enum() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+
+    // each partition has its own dictionary
+    let queries = [
+        CXQuery::naked("select test_int16, test_enum from test_types where test_int16 < 2"),
+        CXQuery::naked("select test_int16, test_enum from test_types where test_int16 >= 2"),
+    ];
+    let url = Url::parse(dburl.as_str()).unwrap();
+    let (config, _tls) = rewrite_tls_args(&url).unwrap();
+    let builder = PostgresSource::<BinaryProtocol, NoTls>::new(config, NoTls, 2).unwrap();
+    let mut destination = Arrow2Destination::new();
+    let dispatcher = Dispatcher::<_, _, PostgresArrow2Transport<BinaryProtocol, NoTls>>::new(
+        builder,
+        &mut destination,
+        &queries,
+        Some("select test_int16, test_enum from test_types".to_string()),
+    );
+
+    dispatcher.run().expect("run dispatcher");
+
+    let df: DataFrame = destination.polars().unwrap();
+    let enums = df.column("test_enum").unwrap();
+    assert!(matches!(enums.dtype(), DataType::Categorical(_)));
+
+    let enums = enums.cast(&DataType::Utf8).unwrap();
+    let ids = df.column("test_int16").unwrap().i32().unwrap();
+    let mut rows: Vec<_> = ids.into_iter().zip(enums.utf8().unwrap()).collect();
+    rows.sort();
+    assert_eq!(
+        vec![
+            (Some(0), Some("happy")),
+            (Some(1), Some("very happy")),
+            (Some(2), Some("ecstatic")),
+            (Some(3), None)
+        ],
+        rows
+    );
+}
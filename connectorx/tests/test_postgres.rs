@@ -1,18 +1,35 @@
 use arrow::{
-    array::{BooleanArray, Float64Array, Int64Array, StringArray},
+    array::{
+        Array, BooleanArray, Decimal128Array, DictionaryArray, FixedSizeBinaryArray, Float32Array,
+        Float64Array, Int16Array, Int32Array, Int64Array, IntervalMonthDayNanoArray,
+        LargeBinaryArray, LargeListArray, MapArray, StringArray, StructArray,
+        TimestampMillisecondArray, TimestampSecondArray,
+    },
+    datatypes::{DataType, Field, Int32Type, IntervalMonthDayNanoType, TimeUnit},
     record_batch::RecordBatch,
 };
+use arrow2::{
+    array::{
+        Array as _, DictionaryArray as Arrow2DictionaryArray,
+        FixedSizeBinaryArray as Arrow2FixedSizeBinaryArray, ListArray, PrimitiveArray, Utf8Array,
+    },
+    datatypes::{DataType as Arrow2DataType, IntegerType},
+    types::months_days_ns,
+};
 use connectorx::{
-    destinations::arrow::ArrowDestination,
+    destinations::{arrow::ArrowDestination, arrow2::Arrow2Destination},
     prelude::*,
     sources::postgres::{rewrite_tls_args, BinaryProtocol, CSVProtocol, PostgresSource},
     sources::PartitionParser,
     sql::CXQuery,
-    transports::PostgresArrowTransport,
+    transports::{PostgresArrow2Transport, PostgresArrowTransport},
 };
 use postgres::NoTls;
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::env;
 use url::Url;
+use uuid::Uuid;
 
 #[test]
 fn load_and_parse() {
@@ -219,6 +236,256 @@ fn test_postgres_agg() {
         ])));
 }
 
+#[test]
+fn test_postgres_extended_types() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select * from test_extended_types order by test_int",
+    )];
+
+    for protocol in ["binary", "csv", "cursor", "simple"] {
+        let conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+        let destination = get_arrow(&conn, None, &queries).unwrap();
+        let mut result = destination.arrow().unwrap();
+        assert_eq!(1, result.len());
+        let rb = result.pop().unwrap();
+        assert_eq!(12, rb.num_columns());
+
+        assert!(
+            rb.column(1)
+                .as_any()
+                .downcast_ref::<IntervalMonthDayNanoArray>()
+                .unwrap()
+                .eq(&IntervalMonthDayNanoArray::from(vec![
+                    Some(IntervalMonthDayNanoType::make_value(
+                        14,
+                        3,
+                        14_706_500_000_000
+                    )),
+                    Some(IntervalMonthDayNanoType::make_value(
+                        0,
+                        -1,
+                        7_380_000_000_000
+                    )),
+                    None,
+                ])),
+            "{}",
+            protocol
+        );
+        assert!(
+            rb.column(5)
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .unwrap()
+                .eq(&Float64Array::from(vec![Some(1234.56), Some(-0.5), None])),
+            "{}",
+            protocol
+        );
+
+        let expected = [
+            (2, ["192.168.1.5", "2001:db8::1/64"]),
+            (3, ["10.0.0.0/8", "2001:db8::/32"]),
+            (4, ["08:00:2b:01:02:03", "00:00:00:00:00:ff"]),
+            (6, ["1010", "0001"]),
+            (7, ["101", "11"]),
+            (8, ["<a>b</a>", "<x/>"]),
+            (9, ["[1,11)", "empty"]),
+            (
+                10,
+                [
+                    "[\"2020-01-01 00:00:00\",\"2020-01-02 12:30:00\")",
+                    "(,\"2020-01-01 00:00:00\"]",
+                ],
+            ),
+            (11, ["(1.5,-2,\"a b\")", "(,0,\"\")"]),
+        ];
+        for (col, values) in expected {
+            assert_eq!(
+                &StringArray::from(vec![Some(values[0]), Some(values[1]), None]),
+                rb.column(col)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap(),
+                "column {} with {} protocol",
+                col,
+                protocol
+            );
+        }
+    }
+}
+
+#[test]
+fn test_postgres_geometric_types() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select * from (values \
+            ('(1,2.5)'::point, '{1,-1,0}'::line, '[(0,0),(1,1)]'::lseg, '(0,0),(2,1)'::box, '[(0,0),(1,1)]'::path, '((0,0),(1,0),(1,1))'::polygon, '<(1,2),3>'::circle), \
+            ('(-0.5,0)'::point, '{0,1,-2}'::line, '[(1,2),(3,4)]'::lseg, '(1,1),(1,1)'::box, '((0,0),(1,0),(1,1))'::path, '((0,0),(0,1))'::polygon, '<(0,0),0.5>'::circle), \
+            (null, null, null, null, null, null, null)) t(p, l, s, b, pa, po, c)",
+    )];
+    let expected = [
+        ["(1,2.5)", "(-0.5,0)"],
+        ["{1,-1,0}", "{0,1,-2}"],
+        ["[(0,0),(1,1)]", "[(1,2),(3,4)]"],
+        ["(2,1),(0,0)", "(1,1),(1,1)"],
+        ["[(0,0),(1,1)]", "((0,0),(1,0),(1,1))"],
+        ["((0,0),(1,0),(1,1))", "((0,0),(0,1))"],
+        ["<(1,2),3>", "<(0,0),0.5>"],
+    ];
+
+    for protocol in ["binary", "csv", "cursor", "simple"] {
+        let conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+        let destination = get_arrow(&conn, None, &queries).unwrap();
+        let rb = destination.arrow().unwrap().pop().unwrap();
+        for (col, values) in expected.iter().enumerate() {
+            assert_eq!(
+                &StringArray::from(vec![Some(values[0]), Some(values[1]), None]),
+                rb.column(col)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap(),
+                "column {} with {} protocol",
+                col,
+                protocol
+            );
+        }
+    }
+}
+
+#[test]
+fn test_postgres_interval_arrow2() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_interval, test_money from test_extended_types order by test_int",
+    )];
+    let url = Url::parse(dburl.as_str()).unwrap();
+    let (config, _tls) = rewrite_tls_args(&url).unwrap();
+    let builder = PostgresSource::<BinaryProtocol, NoTls>::new(config, NoTls, 1).unwrap();
+    let mut destination = Arrow2Destination::new();
+    let dispatcher = Dispatcher::<_, _, PostgresArrow2Transport<BinaryProtocol, NoTls>>::new(
+        builder,
+        &mut destination,
+        &queries,
+        None,
+    );
+    dispatcher.run().expect("run dispatcher");
+
+    let (chunks, _) = destination.arrow().unwrap();
+    assert_eq!(1, chunks.len());
+    let intervals = chunks[0].arrays()[0]
+        .as_any()
+        .downcast_ref::<PrimitiveArray<months_days_ns>>()
+        .unwrap();
+    assert_eq!(
+        vec![
+            Some(months_days_ns::new(14, 3, 14_706_500_000_000)),
+            Some(months_days_ns::new(0, -1, 7_380_000_000_000)),
+            None
+        ],
+        intervals.iter().map(|v| v.copied()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_postgres_domain_types() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select * from test_domain_types order by test_int",
+    )];
+
+    for protocol in ["binary", "csv", "cursor"] {
+        let conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+        let destination = get_arrow2(&conn, None, &queries).unwrap();
+        let (chunks, _) = destination.arrow().unwrap();
+        assert_eq!(1, chunks.len());
+        let arrays = chunks[0].arrays();
+
+        let posints = arrays[1]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1), Some(2), None],
+            posints.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+        let emails = arrays[2].as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        assert_eq!(
+            vec![Some("a@example.com"), Some("c@example.com"), None],
+            emails.iter().collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+        let moods = arrays[3]
+            .as_any()
+            .downcast_ref::<Arrow2DictionaryArray<i32>>()
+            .unwrap();
+        let mood_values = moods
+            .values()
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .unwrap();
+        assert_eq!(
+            vec![Some("happy"), Some("very happy"), None],
+            moods
+                .keys()
+                .iter()
+                .map(|k| k.map(|k| mood_values.value(*k as usize)))
+                .collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+
+        let enum_arrays = arrays[4].as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        let enums = enum_arrays.value(0);
+        let enums = enums.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        assert_eq!(
+            vec![Some("happy"), Some("ecstatic")],
+            enums.iter().collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+        assert_eq!(0, enum_arrays.value(1).len());
+
+        let posint_arrays = arrays[5].as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        let posints = posint_arrays.value(0);
+        let posints = posints
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1), Some(2)],
+            posints.iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+        assert_eq!(0, posint_arrays.value(1).len());
+        assert!(posint_arrays.is_null(2));
+
+        let email_arrays = arrays[6].as_any().downcast_ref::<ListArray<i64>>().unwrap();
+        let emails = email_arrays.value(0);
+        let emails = emails.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+        assert_eq!(
+            vec![Some("a@example.com"), Some("b@example.com")],
+            emails.iter().collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+    }
+}
+
 pub fn verify_arrow_results(result: Vec<RecordBatch>) {
     assert!(result.len() == 2);
 
@@ -302,3 +569,1011 @@ pub fn verify_arrow_results(result: Vec<RecordBatch>) {
         }
     }
 }
+
+#[test]
+fn test_postgres_arrow_lists() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_i4array, test_f8array, test_textarray, test_boolarray from test_types order by test_int16",
+    )];
+
+    for protocol in ["binary", "csv", "cursor"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+
+        let rbs = get_arrow(&source_conn, None, &queries)
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let mut iter = new_record_batch_iter(&source_conn, None, &queries, 1024);
+        iter.prepare();
+        let mut stream_rbs = vec![];
+        while let Some(rb) = iter.next_batch() {
+            stream_rbs.push(rb);
+        }
+
+        for rb in rbs.iter().chain(stream_rbs.iter()) {
+            check_list_columns(rb, protocol);
+        }
+    }
+}
+
+fn check_list_columns(rb: &RecordBatch, protocol: &str) {
+    let lists = |col: usize| {
+        rb.column(col)
+            .as_any()
+            .downcast_ref::<LargeListArray>()
+            .unwrap()
+            .clone()
+    };
+
+    let i4arrays = lists(0);
+    let i4s: Vec<Option<Vec<i64>>> = (0..i4arrays.len())
+        .map(|i| {
+            i4arrays.is_valid(i).then(|| {
+                let values = i4arrays.value(i);
+                let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+                values.values().to_vec()
+            })
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            Some(vec![-1, 0, 1123]),
+            Some(vec![]),
+            Some(vec![-2147483648, 2147483647]),
+            None
+        ],
+        i4s,
+        "{}",
+        protocol
+    );
+
+    let f8arrays = lists(1);
+    assert!(f8arrays.is_null(1), "{}", protocol);
+    let f8s = f8arrays.value(3);
+    let f8s = f8s.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert_eq!(&[0.000234, -12.987654321], f8s.values().as_ref());
+
+    let textarrays = lists(2);
+    let texts = textarrays.value(0);
+    let texts = texts.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(
+        vec![Some("text1"), Some("text2")],
+        texts.iter().collect::<Vec<_>>(),
+        "{}",
+        protocol
+    );
+    assert_eq!(0, textarrays.value(3).len());
+
+    let boolarrays = lists(3);
+    let bools = boolarrays.value(0);
+    let bools = bools.as_any().downcast_ref::<BooleanArray>().unwrap();
+    assert_eq!(
+        vec![Some(true), Some(false)],
+        bools.iter().collect::<Vec<_>>()
+    );
+    assert!(boolarrays.is_null(3));
+}
+
+#[test]
+fn test_postgres_hstore_map() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_hstore from test_hstore order by test_int",
+    )];
+    // hstore is only supported by the cursor protocol
+    let source_conn =
+        SourceConn::try_from(format!("{}?cxprotocol=cursor", dburl).as_str()).unwrap();
+
+    let options = ArrowOptions {
+        hstore_map: true,
+        ..Default::default()
+    };
+    let rbs = get_arrow_with_options(&source_conn, None, &queries, options)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    let maps = rbs[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<MapArray>()
+        .unwrap();
+    let mut entries: Vec<Vec<(String, Option<String>)>> = (0..maps.len())
+        .map(|i| {
+            let entries = maps.value(i);
+            let keys = entries
+                .column(0)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            let values = entries
+                .column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            keys.iter()
+                .zip(values.iter())
+                .map(|(k, v)| (k.unwrap().to_string(), v.map(str::to_string)))
+                .collect()
+        })
+        .collect();
+    entries[0].sort();
+    assert_eq!(
+        vec![
+            vec![
+                ("a".to_string(), Some("1".to_string())),
+                ("b".to_string(), None)
+            ],
+            vec![],
+            vec![],
+        ],
+        entries
+    );
+    assert!(maps.is_null(2));
+
+    // without the option hstore is stored as JSON text
+    let rbs = get_arrow(&source_conn, None, &queries)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    let texts = rbs[0]
+        .column(0)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap();
+    assert_eq!(None, texts.iter().nth(2).unwrap());
+    let value: serde_json::Value = serde_json::from_str(texts.value(0)).unwrap();
+    assert_eq!(serde_json::json!({"a": "1", "b": null}), value);
+}
+
+#[test]
+fn test_postgres_json_nested() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_json, test_jsonb from test_types order by test_int16",
+    )];
+    let mut json_types = HashMap::new();
+    json_types.insert(
+        "test_jsonb".to_string(),
+        DataType::Struct(vec![Field::new("qty", DataType::Int32, true)].into()),
+    );
+    let options = ArrowOptions {
+        json_nested: true,
+        json_types,
+        ..Default::default()
+    };
+
+    for protocol in ["binary", "cursor"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+
+        // the type of test_json is inferred from its values
+        let orders = rbs[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let customers = orders
+            .column_by_name("customer")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            vec![
+                Some("John Doe"),
+                Some("Lily Bush"),
+                Some("Josh William"),
+                None
+            ],
+            customers.iter().collect::<Vec<_>>(),
+            "{}",
+            protocol
+        );
+        let items = orders
+            .column_by_name("items")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let qty = items
+            .column_by_name("qty")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(6), Some(24), Some(1), None],
+            qty.iter().collect::<Vec<_>>()
+        );
+        assert!(orders.is_null(3));
+
+        // the type of test_jsonb is given, the other fields are dropped
+        assert_eq!(
+            &DataType::Struct(vec![Field::new("qty", DataType::Int32, true)].into()),
+            rbs[0].schema().field(1).data_type()
+        );
+        let items = rbs[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let qty = items
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(6), Some(24), Some(1), None],
+            qty.iter().collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_postgres_timestamp_options() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_int16, ts, tstz from test_timestamp_precision order by test_int16",
+    )];
+    let options = ArrowOptions {
+        timestamp_unit: true,
+        timestamp_tz: true,
+        naive_timezone: Some("Europe/Paris".to_string()),
+        ..Default::default()
+    };
+
+    for protocol in ["binary", "csv", "cursor", "simple"] {
+        // the session time zone, which timestamptz columns are tagged with
+        let source_conn = SourceConn::try_from(
+            format!(
+                "{}?cxprotocol={}&options=-cTimeZone%3DAsia%2FTokyo",
+                dburl, protocol
+            )
+            .as_str(),
+        )
+        .unwrap();
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+
+        let schema = rbs[0].schema();
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Second, Some("Europe/Paris".into())),
+            schema.field(1).data_type(),
+            "{}",
+            protocol
+        );
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("Asia/Tokyo".into())),
+            schema.field(2).data_type(),
+            "{}",
+            protocol
+        );
+
+        let ts: Vec<_> = rbs
+            .iter()
+            .flat_map(|rb| {
+                rb.column(1)
+                    .as_any()
+                    .downcast_ref::<TimestampSecondArray>()
+                    .unwrap()
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        // naive timestamps are read as local times in Paris, one hour ahead of UTC in winter
+        assert_eq!(
+            vec![Some(-3599), Some(951735610), Some(2147468399), None],
+            ts,
+            "{}",
+            protocol
+        );
+
+        let tstz: Vec<_> = rbs
+            .iter()
+            .flat_map(|rb| {
+                rb.column(2)
+                    .as_any()
+                    .downcast_ref::<TimestampMillisecondArray>()
+                    .unwrap()
+                    .iter()
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(
+            vec![Some(1000), Some(951753610000), Some(2147443199000), None],
+            tstz,
+            "{}",
+            protocol
+        );
+
+        // the session time zone is not attached to timestamps without time zone
+        let options = ArrowOptions {
+            naive_timezone: None,
+            ..options.clone()
+        };
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options)
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let schema = rbs[0].schema();
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Second, None),
+            schema.field(1).data_type(),
+            "{}",
+            protocol
+        );
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Millisecond, Some("Asia/Tokyo".into())),
+            schema.field(2).data_type(),
+            "{}",
+            protocol
+        );
+    }
+}
+
+#[test]
+fn test_postgres_column_metadata() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_int16, test_numeric, test_varchar, test_timestamptz from test_types",
+    )];
+    let options = ArrowOptions {
+        column_metadata: true,
+        ..Default::default()
+    };
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let expected: Vec<HashMap<String, String>> = vec![
+        vec![
+            ("connectorx.type_name", "int2"),
+            ("connectorx.nullable", "true"),
+            ("connectorx.table", "test_types"),
+        ],
+        vec![
+            ("connectorx.type_name", "numeric"),
+            ("connectorx.precision", "5"),
+            ("connectorx.scale", "2"),
+            ("connectorx.nullable", "true"),
+            ("connectorx.table", "test_types"),
+        ],
+        vec![
+            ("connectorx.type_name", "varchar"),
+            ("connectorx.precision", "10"),
+            ("connectorx.nullable", "true"),
+            ("connectorx.table", "test_types"),
+        ],
+        vec![
+            ("connectorx.type_name", "timestamptz"),
+            ("connectorx.precision", "6"),
+            ("connectorx.nullable", "true"),
+            ("connectorx.table", "test_types"),
+        ],
+    ]
+    .into_iter()
+    .map(|metadata| {
+        metadata
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    })
+    .collect();
+
+    let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+        .unwrap()
+        .arrow()
+        .unwrap();
+    let schema = rbs[0].schema();
+    assert_eq!(
+        expected,
+        schema
+            .fields()
+            .iter()
+            .map(|f| f.metadata().clone())
+            .collect::<Vec<_>>()
+    );
+
+    let (_, schema) = get_arrow2_with_options(&source_conn, None, &queries, options.clone())
+        .unwrap()
+        .arrow()
+        .unwrap();
+    assert_eq!(
+        expected,
+        schema
+            .fields
+            .iter()
+            .map(|f| f.metadata.clone().into_iter().collect())
+            .collect::<Vec<HashMap<_, _>>>()
+    );
+
+    // computed columns are not read from a table
+    let queries = [CXQuery::naked(
+        "select test_int, test_int + 1 as test_computed from test_table",
+    )];
+    let rbs = get_arrow_with_options(&source_conn, None, &queries, options)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    let schema = rbs[0].schema();
+    assert_eq!(
+        &HashMap::from([
+            ("connectorx.type_name".to_string(), "int4".to_string()),
+            ("connectorx.nullable".to_string(), "false".to_string()),
+            ("connectorx.table".to_string(), "test_table".to_string()),
+        ]),
+        schema.field(0).metadata()
+    );
+    assert_eq!(
+        &HashMap::from([("connectorx.type_name".to_string(), "int4".to_string())]),
+        schema.field(1).metadata()
+    );
+
+    // the metadata is opt-in
+    let rbs = get_arrow(&source_conn, None, &queries)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    assert!(rbs[0]
+        .schema()
+        .fields()
+        .iter()
+        .all(|f| f.metadata().is_empty()));
+}
+
+#[test]
+fn test_postgres_uuid_binary() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_int16, test_uuid from test_types order by test_int16",
+    )];
+    let options = ArrowOptions {
+        uuid_binary: true,
+        ..Default::default()
+    };
+    let expected: Vec<Option<Vec<u8>>> = vec![
+        Some(
+            Uuid::parse_str("86b494cc-96b2-11eb-9298-3e22fbb9fe9d")
+                .unwrap()
+                .as_bytes()
+                .to_vec(),
+        ),
+        Some(
+            Uuid::parse_str("86b49b84-96b2-11eb-9298-3e22fbb9fe9d")
+                .unwrap()
+                .as_bytes()
+                .to_vec(),
+        ),
+        Some(
+            Uuid::parse_str("86b49c42-96b2-11eb-9298-3e22fbb9fe9d")
+                .unwrap()
+                .as_bytes()
+                .to_vec(),
+        ),
+        None,
+    ];
+
+    for protocol in ["binary", "csv", "cursor", "simple"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let schema = rbs[0].schema();
+        let field = schema.field(1);
+        assert_eq!(&DataType::FixedSizeBinary(16), field.data_type());
+        assert_eq!(
+            Some(&"arrow.uuid".to_string()),
+            field.metadata().get("ARROW:extension:name")
+        );
+        let values: Vec<_> = rbs
+            .iter()
+            .flat_map(|rb| {
+                rb.column(1)
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|v| v.map(<[u8]>::to_vec))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(expected, values, "{}", protocol);
+
+        let (chunks, schema) =
+            get_arrow2_with_options(&source_conn, None, &queries, options.clone())
+                .unwrap()
+                .arrow()
+                .unwrap();
+        assert_eq!(
+            Some(&"arrow.uuid".to_string()),
+            schema.fields[1].metadata.get("ARROW:extension:name")
+        );
+        let values: Vec<_> = chunks
+            .iter()
+            .flat_map(|chunk| {
+                chunk.arrays()[1]
+                    .as_any()
+                    .downcast_ref::<Arrow2FixedSizeBinaryArray>()
+                    .unwrap()
+                    .iter()
+                    .map(|v| v.map(<[u8]>::to_vec))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(expected, values, "{}", protocol);
+    }
+}
+
+#[test]
+fn test_postgres_decimal128() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_numeric, test_numeric * 1.5 as test_product from test_types order by test_int16",
+    )];
+    let options = ArrowOptions {
+        decimal128: true,
+        ..Default::default()
+    };
+
+    for protocol in ["binary", "cursor"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        // NUMERIC(5,2) keeps its precision and scale, unconstrained NUMERIC uses the defaults
+        let expected = [
+            Decimal128Array::from(vec![None, Some(52134), Some(0), Some(0)])
+                .with_precision_and_scale(5, 2)
+                .unwrap(),
+            Decimal128Array::from(vec![None, Some(7_820_100_000_000), Some(0), Some(0)])
+                .with_precision_and_scale(38, 10)
+                .unwrap(),
+        ];
+        for (col, expected) in expected.iter().enumerate() {
+            let actual = rbs[0]
+                .column(col)
+                .as_any()
+                .downcast_ref::<Decimal128Array>()
+                .unwrap();
+            assert_eq!(
+                expected, actual,
+                "column {} with {} protocol",
+                col, protocol
+            );
+        }
+
+        let (chunks, schema) =
+            get_arrow2_with_options(&source_conn, None, &queries, options.clone())
+                .unwrap()
+                .arrow()
+                .unwrap();
+        assert_eq!(Arrow2DataType::Decimal(5, 2), schema.fields[0].data_type);
+        assert_eq!(Arrow2DataType::Decimal(38, 10), schema.fields[1].data_type);
+        let numeric = chunks[0].arrays()[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i128>>()
+            .unwrap();
+        assert_eq!(
+            vec![None, Some(52134), Some(0), Some(0)],
+            numeric.iter().map(|v| v.copied()).collect::<Vec<_>>()
+        );
+    }
+
+    // without the option decimals are still stored as floats
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let rbs = get_arrow(&source_conn, None, &queries)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    assert_eq!(&DataType::Float64, rbs[0].schema().field(0).data_type());
+
+    // the precision and scale are read from the catalog, so a read-only session keeps them
+    let source_conn = SourceConn::try_from(
+        format!("{}?options=-cdefault_transaction_read_only%3Don", dburl).as_str(),
+    )
+    .unwrap();
+    let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+        .unwrap()
+        .arrow()
+        .unwrap();
+    assert_eq!(
+        &DataType::Decimal128(5, 2),
+        rbs[0].schema().field(0).data_type()
+    );
+
+    // a value with more fraction digits than the scale is not rounded
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let queries = [CXQuery::naked(
+        "select 0.12345678901::numeric as test_numeric",
+    )];
+    let result = get_arrow_with_options(&source_conn, None, &queries, options)
+        .and_then(|destination| Ok(destination.arrow()?));
+    match result {
+        Err(err) => assert!(err
+            .to_string()
+            .contains("does not fit in Decimal128(38, 10)")),
+        Ok(_) => panic!("0.12345678901 is rounded to Decimal128(38, 10)"),
+    }
+}
+
+#[test]
+fn test_postgres_arrow2_unsupported_options() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked("select * from test_table")];
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+
+    for (option, options) in [
+        (
+            "keep_width",
+            ArrowOptions {
+                keep_width: true,
+                ..Default::default()
+            },
+        ),
+        (
+            "hstore_map",
+            ArrowOptions {
+                hstore_map: true,
+                ..Default::default()
+            },
+        ),
+        (
+            "timestamp_tz",
+            ArrowOptions {
+                timestamp_tz: true,
+                ..Default::default()
+            },
+        ),
+    ] {
+        match get_arrow2_with_options(&source_conn, None, &queries, options) {
+            Err(err) => assert!(err.to_string().contains(option), "{}", err),
+            Ok(_) => panic!("{} should not be supported by arrow2", option),
+        }
+    }
+}
+
+#[test]
+fn test_postgres_keep_width() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_int16, test_int16::int4 as test_int32, test_int64, test_float32 from test_types order by test_int16",
+    )];
+    let options = ArrowOptions {
+        keep_width: true,
+        ..Default::default()
+    };
+
+    for protocol in ["binary", "csv", "cursor", "simple"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let schema = rbs[0].schema();
+        let types: Vec<_> = schema.fields().iter().map(|f| f.data_type()).collect();
+        assert_eq!(
+            vec![
+                &DataType::Int16,
+                &DataType::Int32,
+                &DataType::Int64,
+                &DataType::Float32
+            ],
+            types,
+            "{} protocol",
+            protocol
+        );
+
+        let col = rbs[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int16Array>()
+            .unwrap();
+        assert_eq!(&Int16Array::from(vec![0, 1, 2, 3]), col);
+        let col = rbs[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(&Int32Array::from(vec![0, 1, 2, 3]), col);
+        let col = rbs[0]
+            .column(3)
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .unwrap();
+        assert_eq!(
+            &Float32Array::from(vec![
+                None,
+                Some(std::f32::consts::PI),
+                Some(2.71),
+                Some(0.0)
+            ]),
+            col
+        );
+    }
+
+    // without the option the columns are widened
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let rbs = get_arrow(&source_conn, None, &queries)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    assert_eq!(&DataType::Int64, rbs[0].schema().field(0).data_type());
+    assert_eq!(&DataType::Float64, rbs[0].schema().field(3).data_type());
+}
+
+#[test]
+fn test_postgres_dictionary() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_int16, test_enum, test_varchar from test_types order by test_int16",
+    )];
+    let options = ArrowOptions {
+        dictionary_columns: std::iter::once("test_varchar".to_string()).collect(),
+        ..Default::default()
+    };
+    let owned = |values: &[Option<&str>]| -> Vec<Option<String>> {
+        values.iter().map(|v| v.map(str::to_string)).collect()
+    };
+    let enums = owned(&[Some("happy"), Some("very happy"), Some("ecstatic"), None]);
+    let varchars = owned(&[None, Some("bb"), Some("c"), Some("defghijklm")]);
+    let dictionary = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    let arrow2_dictionary =
+        Arrow2DataType::Dictionary(IntegerType::Int32, Box::new(Arrow2DataType::Utf8), false);
+
+    for protocol in ["binary", "csv", "cursor"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let mut iter =
+            new_record_batch_iter_with_options(&source_conn, None, &queries, 1024, options.clone());
+        iter.prepare();
+        let mut stream_rbs = vec![];
+        while let Some(rb) = iter.next_batch() {
+            stream_rbs.push(rb);
+        }
+
+        for rbs in [&rbs, &stream_rbs] {
+            let schema = rbs[0].schema();
+            assert_eq!(&dictionary, schema.field(1).data_type(), "{}", protocol);
+            assert_eq!(&dictionary, schema.field(2).data_type(), "{}", protocol);
+            assert_eq!(enums, dictionary_values(rbs, 1), "{} protocol", protocol);
+            assert_eq!(varchars, dictionary_values(rbs, 2), "{} protocol", protocol);
+        }
+
+        let (chunks, schema) =
+            get_arrow2_with_options(&source_conn, None, &queries, options.clone())
+                .unwrap()
+                .arrow()
+                .unwrap();
+        assert_eq!(arrow2_dictionary, schema.fields[1].data_type);
+        assert_eq!(arrow2_dictionary, schema.fields[2].data_type);
+        for (col, expected) in [(1, &enums), (2, &varchars)] {
+            let values: Vec<_> = chunks
+                .iter()
+                .flat_map(|chunk| {
+                    let dict = chunk.arrays()[col]
+                        .as_any()
+                        .downcast_ref::<Arrow2DictionaryArray<i32>>()
+                        .unwrap();
+                    let values = dict
+                        .values()
+                        .as_any()
+                        .downcast_ref::<Utf8Array<i32>>()
+                        .unwrap();
+                    dict.keys()
+                        .iter()
+                        .map(|k| k.map(|k| values.value(*k as usize).to_string()))
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+            assert_eq!(expected, &values, "{} protocol", protocol);
+        }
+    }
+
+    // without the option only enum columns are dictionary-encoded
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let rbs = get_arrow(&source_conn, None, &queries)
+        .unwrap()
+        .arrow()
+        .unwrap();
+    assert_eq!(&dictionary, rbs[0].schema().field(1).data_type());
+    assert_eq!(&DataType::Utf8, rbs[0].schema().field(2).data_type());
+}
+
+fn dictionary_values(rbs: &[RecordBatch], col: usize) -> Vec<Option<String>> {
+    rbs.iter()
+        .flat_map(|rb| {
+            let dict = rb
+                .column(col)
+                .as_any()
+                .downcast_ref::<DictionaryArray<Int32Type>>()
+                .unwrap();
+            let values = dict.downcast_dict::<StringArray>().unwrap();
+            values
+                .into_iter()
+                .map(|v| v.map(str::to_string))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[test]
+fn test_postgres_type_overrides() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    let queries = [CXQuery::naked(
+        "select test_int16, test_numeric, test_varchar::text as test_text, test_varchar from test_types order by test_int16",
+    )];
+    let options = ArrowOptions {
+        type_overrides: [
+            ("test_numeric", "LargeUtf8"),
+            ("test_text", "LargeBinary"),
+            ("test_varchar", "LargeBinary"),
+        ]
+        .iter()
+        .map(|&(col, ty)| (col.to_string(), ty.to_string()))
+        .collect(),
+        ..Default::default()
+    };
+    let numerics = vec![None, Some("521.34"), Some("0.00"), Some("0.00")];
+
+    for protocol in ["binary", "csv", "cursor"] {
+        let source_conn =
+            SourceConn::try_from(format!("{}?cxprotocol={}", dburl, protocol).as_str()).unwrap();
+
+        let rbs = get_arrow_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let schema = rbs[0].schema();
+        // the override keeps the nullability of the column
+        assert_eq!(
+            &Field::new("test_numeric", DataType::Utf8, true),
+            schema.field(1),
+            "{} protocol",
+            protocol
+        );
+        assert_eq!(&DataType::LargeBinary, schema.field(2).data_type());
+        assert_eq!(&DataType::LargeBinary, schema.field(3).data_type());
+        let col = rbs[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(numerics, col.iter().collect::<Vec<_>>(), "{}", protocol);
+        let col = rbs[0]
+            .column(2)
+            .as_any()
+            .downcast_ref::<LargeBinaryArray>()
+            .unwrap();
+        assert_eq!(
+            vec![
+                None,
+                Some(&b"bb"[..]),
+                Some(&b"c"[..]),
+                Some(&b"defghijklm"[..])
+            ],
+            col.iter().collect::<Vec<_>>(),
+            "{} protocol",
+            protocol
+        );
+
+        let mut iter =
+            new_record_batch_iter_with_options(&source_conn, None, &queries, 1024, options.clone());
+        iter.prepare();
+        let rb = iter.next_batch().unwrap();
+        let col = rb.column(1).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(numerics, col.iter().collect::<Vec<_>>(), "{}", protocol);
+        assert_eq!(&DataType::LargeBinary, rb.schema().field(3).data_type());
+
+        let (chunks, _) = get_arrow2_with_options(&source_conn, None, &queries, options.clone())
+            .unwrap()
+            .arrow()
+            .unwrap();
+        let col = chunks[0].arrays()[1]
+            .as_any()
+            .downcast_ref::<Utf8Array<i64>>()
+            .unwrap();
+        assert_eq!(numerics, col.iter().collect::<Vec<_>>(), "{}", protocol);
+        assert_eq!(
+            &Arrow2DataType::LargeBinary,
+            chunks[0].arrays()[3].data_type()
+        );
+    }
+
+    // a conversion without a rule is an error
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let options = ArrowOptions {
+        type_overrides: std::iter::once(("test_int16".to_string(), "Date32".to_string())).collect(),
+        ..Default::default()
+    };
+    let err = get_arrow_with_options(&source_conn, None, &queries, options)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("Cannot override the type of column test_int16"),
+        "{}",
+        err
+    );
+
+    // so are a column that is not in the result and a type the destination does not have
+    let options = ArrowOptions {
+        type_overrides: std::iter::once(("test_nothing".to_string(), "Int64".to_string()))
+            .collect(),
+        ..Default::default()
+    };
+    let err = get_arrow_with_options(&source_conn, None, &queries, options)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("Cannot override the type of column test_nothing"),
+        "{}",
+        err
+    );
+    let options = ArrowOptions {
+        type_overrides: std::iter::once(("test_int16".to_string(), "Int16".to_string())).collect(),
+        ..Default::default()
+    };
+    match get_arrow2_with_options(&source_conn, None, &queries, options) {
+        Err(err) => assert!(
+            err.to_string()
+                .contains("the arrow2 destination has no type Int16"),
+            "{}",
+            err
+        ),
+        Ok(_) => panic!("arrow2 has no Int16 type"),
+    }
+    let options = ArrowOptions {
+        type_overrides: std::iter::once(("test_int16".to_string(), "Int128".to_string())).collect(),
+        ..Default::default()
+    };
+    let err = get_arrow_with_options(&source_conn, None, &queries, options)
+        .err()
+        .unwrap();
+    assert!(
+        err.to_string()
+            .contains("the arrow destination has no type Int128"),
+        "{}",
+        err
+    );
+}
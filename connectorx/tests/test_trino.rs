@@ -1,9 +1,12 @@
 use arrow::{
-    array::{Float64Array, Int64Array},
+    array::{Decimal128Array, Float64Array, Int64Array},
     record_batch::RecordBatch,
 };
 use connectorx::{
-    destinations::arrow::ArrowDestination, prelude::*, sources::trino::TrinoSource, sql::CXQuery,
+    destinations::{arrow::ArrowDestination, ArrowOptions},
+    prelude::*,
+    sources::trino::TrinoSource,
+    sql::CXQuery,
     transports::TrinoArrowTransport,
 };
 use std::{env, sync::Arc};
@@ -60,6 +63,40 @@ fn test_trino_text() {
     verify_arrow_results(result);
 }
 
+#[test]
+#[ignore]
+fn test_trino_decimal128() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("TRINO_URL").unwrap();
+
+    let queries = [CXQuery::naked(
+        "select test_decimal from test.test_types order by test_int",
+    )];
+
+    let rt = Arc::new(tokio::runtime::Runtime::new().expect("Failed to create runtime"));
+    let builder = TrinoSource::new(rt, &dburl).unwrap();
+    let mut destination = ArrowDestination::new_with_options(ArrowOptions {
+        decimal128: true,
+        ..Default::default()
+    });
+    let dispatcher =
+        Dispatcher::<_, _, TrinoArrowTransport>::new(builder, &mut destination, &queries, None);
+    dispatcher.run().unwrap();
+
+    let result = destination.arrow().unwrap();
+    assert_eq!(
+        &Decimal128Array::from(vec![Some(123456789012), Some(123456789012), None])
+            .with_precision_and_scale(15, 2)
+            .unwrap(),
+        result[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Decimal128Array>()
+            .unwrap()
+    );
+}
+
 pub fn verify_arrow_results(result: Vec<RecordBatch>) {
     assert!(result.len() == 2);
 
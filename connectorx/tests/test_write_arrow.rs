@@ -0,0 +1,593 @@
+use arrow::{
+    array::{
+        ArrayRef, BooleanArray, Date32Array, Decimal128Array, Float64Array, Int32Array, Int64Array,
+        StringArray, Time64MicrosecondArray, TimestampMicrosecondArray,
+    },
+    datatypes::{DataType, Field, Schema, TimeUnit},
+    record_batch::RecordBatch,
+};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use connectorx::prelude::*;
+use postgres::{Client, NoTls};
+use r2d2_mysql::mysql::prelude::Queryable;
+use rust_decimal::Decimal;
+use std::convert::TryFrom;
+use std::env;
+use std::str::FromStr;
+use std::sync::Arc;
+
+fn batch(schema: &Arc<Schema>, columns: Vec<ArrayRef>) -> RecordBatch {
+    RecordBatch::try_new(Arc::clone(schema), columns).unwrap()
+}
+
+fn id_batches(ids: &[&[i64]]) -> Vec<RecordBatch> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int64, false),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    ids.iter()
+        .map(|ids| {
+            batch(
+                &schema,
+                vec![
+                    Arc::new(Int64Array::from(ids.to_vec())),
+                    Arc::new(StringArray::from(
+                        ids.iter().map(|i| Some(i.to_string())).collect::<Vec<_>>(),
+                    )),
+                ],
+            )
+        })
+        .collect()
+}
+
+fn postgres() -> (SourceConn, Client) {
+    let dburl = env::var("POSTGRES_URL").unwrap();
+    (
+        SourceConn::try_from(dburl.as_str()).unwrap(),
+        Client::connect(&dburl, NoTls).unwrap(),
+    )
+}
+
+#[test]
+fn test_postgres_write_create() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (source_conn, mut client) = postgres();
+    client
+        .batch_execute("DROP TABLE IF EXISTS test_write_create")
+        .unwrap();
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("test_int", DataType::Int32, false),
+        Field::new("test_float", DataType::Float64, true),
+        Field::new("Test Str", DataType::Utf8, true),
+        Field::new("test_bool", DataType::Boolean, true),
+        Field::new("test_date", DataType::Date32, true),
+        Field::new(
+            "test_timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            true,
+        ),
+        Field::new("test_decimal", DataType::Decimal128(10, 2), true),
+    ]));
+    let batches = vec![
+        batch(
+            &schema,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(Float64Array::from(vec![Some(1.5), None])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+                Arc::new(BooleanArray::from(vec![Some(true), None])),
+                Arc::new(Date32Array::from(vec![Some(18000), None])),
+                Arc::new(TimestampMicrosecondArray::from(vec![
+                    Some(1_600_000_000_000_000),
+                    None,
+                ])),
+                Arc::new(
+                    Decimal128Array::from(vec![Some(12345), None])
+                        .with_precision_and_scale(10, 2)
+                        .unwrap(),
+                ),
+            ],
+        ),
+        batch(
+            &schema,
+            vec![
+                Arc::new(Int32Array::from(vec![3])),
+                Arc::new(Float64Array::from(vec![-2.0])),
+                Arc::new(StringArray::from(vec!["b"])),
+                Arc::new(BooleanArray::from(vec![false])),
+                Arc::new(Date32Array::from(vec![0])),
+                Arc::new(TimestampMicrosecondArray::from(vec![0])),
+                Arc::new(
+                    Decimal128Array::from(vec![-1])
+                        .with_precision_and_scale(10, 2)
+                        .unwrap(),
+                ),
+            ],
+        ),
+    ];
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    let n = write_arrow(&source_conn, "test_write_create", &batches, options).unwrap();
+    assert_eq!(3, n);
+
+    let rows = client
+        .query(
+            "SELECT test_int, test_float, \"Test Str\", test_bool, test_date, test_timestamp, \
+             test_decimal FROM test_write_create ORDER BY test_int",
+            &[],
+        )
+        .unwrap();
+    assert_eq!(3, rows.len());
+    assert_eq!(1, rows[0].get::<_, i32>(0));
+    assert_eq!(Some(1.5), rows[0].get::<_, Option<f64>>(1));
+    assert_eq!(Some("a"), rows[0].get::<_, Option<&str>>(2));
+    assert_eq!(Some(true), rows[0].get::<_, Option<bool>>(3));
+    assert_eq!(
+        NaiveDate::from_ymd_opt(2019, 4, 14),
+        rows[0].get::<_, Option<NaiveDate>>(4)
+    );
+    assert_eq!(
+        DateTime::from_timestamp(1_600_000_000, 0).map(|dt| dt.naive_utc()),
+        rows[0].get::<_, Option<NaiveDateTime>>(5)
+    );
+    assert_eq!(
+        Some(Decimal::from_str("123.45").unwrap()),
+        rows[0].get::<_, Option<Decimal>>(6)
+    );
+    assert_eq!(None, rows[1].get::<_, Option<f64>>(1));
+    assert_eq!(None, rows[1].get::<_, Option<&str>>(2));
+    assert_eq!(None, rows[1].get::<_, Option<Decimal>>(6));
+    assert_eq!(Some("b"), rows[2].get::<_, Option<&str>>(2));
+    assert_eq!(
+        Some(Decimal::from_str("-0.01").unwrap()),
+        rows[2].get::<_, Option<Decimal>>(6)
+    );
+
+    let batches = id_batches(&[&[1]]);
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    assert!(write_arrow(&source_conn, "test_write_create", &batches, options).is_err());
+}
+
+#[test]
+fn test_postgres_write_append_truncate() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (source_conn, mut client) = postgres();
+    client
+        .batch_execute(
+            "DROP TABLE IF EXISTS test_write_append; \
+             CREATE TABLE test_write_append (name TEXT, id INTEGER NOT NULL, note TEXT)",
+        )
+        .unwrap();
+    let count = |client: &mut Client| -> i64 {
+        client
+            .query_one("SELECT count(*) FROM test_write_append", &[])
+            .unwrap()
+            .get(0)
+    };
+
+    let batches = id_batches(&[&[0, 1], &[2], &[3, 4, 5]]);
+    let options = WriteOptions::default();
+    let n = write_arrow(&source_conn, "test_write_append", &batches, options.clone()).unwrap();
+    assert_eq!(6, n);
+    let n = write_arrow(&source_conn, "test_write_append", &batches, options).unwrap();
+    assert_eq!(6, n);
+    assert_eq!(12, count(&mut client));
+
+    let options = WriteOptions {
+        mode: WriteMode::Truncate,
+        ..Default::default()
+    };
+    let n = write_arrow(&source_conn, "test_write_append", &batches[..1], options).unwrap();
+    assert_eq!(2, n);
+    let rows = client
+        .query(
+            "SELECT id, name, note FROM test_write_append ORDER BY id",
+            &[],
+        )
+        .unwrap();
+    let rows: Vec<(i32, Option<String>, Option<String>)> = rows
+        .iter()
+        .map(|r| (r.get(0), r.get(1), r.get(2)))
+        .collect();
+    assert_eq!(
+        vec![
+            (0, Some("0".to_string()), None),
+            (1, Some("1".to_string()), None)
+        ],
+        rows
+    );
+}
+
+#[test]
+fn test_postgres_write_rollback() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (source_conn, mut client) = postgres();
+    client
+        .batch_execute(
+            "DROP TABLE IF EXISTS test_write_rollback; \
+             CREATE TABLE test_write_rollback (id INTEGER NOT NULL, name TEXT); \
+             INSERT INTO test_write_rollback VALUES (0, 'kept')",
+        )
+        .unwrap();
+
+    // the second batch does not fit in INTEGER, so the truncation is rolled back too
+    let batches = id_batches(&[&[1, 2], &[i64::MAX]]);
+    let options = WriteOptions {
+        mode: WriteMode::Truncate,
+        ..Default::default()
+    };
+    assert!(write_arrow(&source_conn, "test_write_rollback", &batches, options).is_err());
+    let rows: Vec<(i32, String)> = client
+        .query("SELECT id, name FROM test_write_rollback", &[])
+        .unwrap()
+        .iter()
+        .map(|r| (r.get(0), r.get(1)))
+        .collect();
+    assert_eq!(vec![(0, "kept".to_string())], rows);
+
+    // and so is the creation of the table
+    client
+        .batch_execute("DROP TABLE test_write_rollback")
+        .unwrap();
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Utf8, false)]));
+    let batches = vec![
+        batch(&schema, vec![Arc::new(StringArray::from(vec!["1"]))]),
+        batch(&schema, vec![Arc::new(StringArray::from(vec!["a\0b"]))]),
+    ];
+    assert!(write_arrow(&source_conn, "test_write_rollback", &batches, options).is_err());
+    assert_eq!(
+        0i64,
+        client
+            .query_one(
+                "SELECT count(*) FROM pg_tables WHERE tablename = 'test_write_rollback'",
+                &[]
+            )
+            .unwrap()
+            .get::<_, i64>(0)
+    );
+}
+
+#[test]
+fn test_postgres_write_parallel() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (source_conn, mut client) = postgres();
+    client
+        .batch_execute("DROP TABLE IF EXISTS test_write_parallel")
+        .unwrap();
+    let ids = |client: &mut Client| -> Vec<i64> {
+        client
+            .query(
+                "SELECT id::bigint FROM test_write_parallel ORDER BY id",
+                &[],
+            )
+            .unwrap()
+            .iter()
+            .map(|r| r.get(0))
+            .collect()
+    };
+
+    let batches = id_batches(&[&[0, 1], &[2], &[3, 4, 5], &[6]]);
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        partition_num: 3,
+    };
+    let n = write_arrow(&source_conn, "test_write_parallel", &batches, options).unwrap();
+    assert_eq!(7, n);
+    assert_eq!((0..7).collect::<Vec<_>>(), ids(&mut client));
+
+    // the partitions are written in their own transactions, so the first one is kept
+    client
+        .batch_execute(
+            "DROP TABLE test_write_parallel; \
+             CREATE TABLE test_write_parallel (id INTEGER NOT NULL, name TEXT)",
+        )
+        .unwrap();
+    let batches = id_batches(&[&[1, 2], &[i64::MAX]]);
+    let options = WriteOptions {
+        mode: WriteMode::Append,
+        partition_num: 2,
+    };
+    assert!(write_arrow(&source_conn, "test_write_parallel", &batches, options).is_err());
+    assert_eq!(vec![1, 2], ids(&mut client));
+}
+
+#[test]
+fn test_postgres_write_conversions() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let (source_conn, mut client) = postgres();
+    client
+        .batch_execute(
+            "DROP TABLE IF EXISTS test_write_conversions; \
+             CREATE TABLE test_write_conversions \
+             (a SMALLINT, b NUMERIC, c REAL, d JSONB, e UUID, f TIMESTAMPTZ)",
+        )
+        .unwrap();
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("a", DataType::Int64, true),
+        Field::new("b", DataType::Int64, true),
+        Field::new("c", DataType::Float64, true),
+        Field::new("d", DataType::Utf8, true),
+        Field::new("e", DataType::Utf8, true),
+        Field::new(
+            "f",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            true,
+        ),
+    ]));
+    let columns = |a: i64| -> Vec<ArrayRef> {
+        vec![
+            Arc::new(Int64Array::from(vec![a])),
+            Arc::new(Int64Array::from(vec![i64::MAX])),
+            Arc::new(Float64Array::from(vec![0.5])),
+            Arc::new(StringArray::from(vec![r#"{"k": [1, 2]}"#])),
+            Arc::new(StringArray::from(vec![
+                "936da01f-9abd-4d9d-80c7-02af85c822a8",
+            ])),
+            Arc::new(TimestampMicrosecondArray::from(vec![0]).with_timezone("UTC")),
+        ]
+    };
+    let batches = vec![batch(&schema, columns(7))];
+    let n = write_arrow(
+        &source_conn,
+        "test_write_conversions",
+        &batches,
+        WriteOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(1, n);
+
+    let row = client
+        .query_one(
+            "SELECT a, b::text, c, d->>'k', e::text, f = 'epoch'::timestamptz \
+             FROM test_write_conversions",
+            &[],
+        )
+        .unwrap();
+    assert_eq!(7, row.get::<_, i16>(0));
+    assert_eq!(i64::MAX.to_string(), row.get::<_, String>(1));
+    assert_eq!(0.5, row.get::<_, f32>(2));
+    assert_eq!("[1, 2]", row.get::<_, String>(3));
+    assert_eq!(
+        "936da01f-9abd-4d9d-80c7-02af85c822a8",
+        row.get::<_, String>(4)
+    );
+    assert!(row.get::<_, bool>(5));
+
+    // out of the range of the column
+    let batches = vec![batch(&schema, columns(1 << 20))];
+    assert!(write_arrow(
+        &source_conn,
+        "test_write_conversions",
+        &batches,
+        WriteOptions::default(),
+    )
+    .is_err());
+}
+
+#[test]
+fn test_postgres_write_unsupported_type() {
+    let (source_conn, mut client) = postgres();
+    client
+        .batch_execute("DROP TABLE IF EXISTS test_write_unsupported")
+        .unwrap();
+
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "a",
+        DataType::Duration(TimeUnit::Second),
+        true,
+    )]));
+    let batches = vec![RecordBatch::new_empty(schema)];
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    let err = write_arrow(&source_conn, "test_write_unsupported", &batches, options).unwrap_err();
+    assert!(matches!(err, ConnectorXOutError::PostgresSourceError(_)));
+    assert_eq!(
+        "Cannot write column a of type Duration(Second).",
+        err.to_string()
+    );
+}
+
+fn round_trip_batches() -> Vec<RecordBatch> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("test_int", DataType::Int64, false),
+        Field::new("test_str", DataType::Utf8, true),
+        Field::new("test_float", DataType::Float64, true),
+        Field::new("test_date", DataType::Date32, true),
+    ]));
+    vec![
+        batch(
+            &schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+                Arc::new(Float64Array::from(vec![Some(0.5), None])),
+                Arc::new(Date32Array::from(vec![Some(18000), None])),
+            ],
+        ),
+        batch(
+            &schema,
+            vec![
+                Arc::new(Int64Array::from(vec![3])),
+                Arc::new(StringArray::from(vec!["b"])),
+                Arc::new(Float64Array::from(vec![-1.0])),
+                Arc::new(Date32Array::from(vec![0])),
+            ],
+        ),
+    ]
+}
+
+/// Read back the table written from `round_trip_batches`, checking the values and
+/// the types given by the Arrow transport of the source.
+fn verify_round_trip(source_conn: &SourceConn, table: &str) {
+    let queries = [CXQuery::naked(format!(
+        "SELECT test_int, test_str, test_float, test_date FROM {} ORDER BY test_int",
+        table
+    ))];
+    let rb = get_arrow(source_conn, None, &queries)
+        .unwrap()
+        .arrow()
+        .unwrap()
+        .remove(0);
+    assert_eq!(3, rb.num_rows());
+    assert!(rb
+        .column(0)
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap()
+        .eq(&Int64Array::from(vec![1, 2, 3])));
+    assert!(rb
+        .column(1)
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .unwrap()
+        .eq(&StringArray::from(vec![Some("a"), None, Some("b")])));
+    assert!(rb
+        .column(2)
+        .as_any()
+        .downcast_ref::<Float64Array>()
+        .unwrap()
+        .eq(&Float64Array::from(vec![Some(0.5), None, Some(-1.0)])));
+    assert!(rb
+        .column(3)
+        .as_any()
+        .downcast_ref::<Date32Array>()
+        .unwrap()
+        .eq(&Date32Array::from(vec![Some(18000), None, Some(0)])));
+}
+
+#[test]
+fn test_sqlite_write() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let path = env::temp_dir().join("connectorx_test_sqlite_write.db");
+    let _ = std::fs::remove_file(&path);
+    let source_conn =
+        SourceConn::try_from(format!("sqlite://{}", path.to_str().unwrap()).as_str()).unwrap();
+
+    let batches = round_trip_batches();
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    let n = write_arrow(&source_conn, "test_write", &batches, options).unwrap();
+    assert_eq!(3, n);
+    verify_round_trip(&source_conn, "test_write");
+
+    let n = write_arrow(
+        &source_conn,
+        "test_write",
+        &batches,
+        WriteOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(3, n);
+    let options = WriteOptions {
+        mode: WriteMode::Truncate,
+        ..Default::default()
+    };
+    let n = write_arrow(&source_conn, "test_write", &batches, options).unwrap();
+    assert_eq!(3, n);
+    verify_round_trip(&source_conn, "test_write");
+}
+
+#[test]
+fn test_mysql_write() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("MYSQL_URL").unwrap();
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let mut conn = r2d2_mysql::mysql::Conn::new(dburl.as_str()).unwrap();
+    conn.query_drop("DROP TABLE IF EXISTS test_write").unwrap();
+
+    let batches = round_trip_batches();
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    let n = write_arrow(&source_conn, "test_write", &batches, options).unwrap();
+    assert_eq!(3, n);
+    verify_round_trip(&source_conn, "test_write");
+
+    let options = WriteOptions {
+        mode: WriteMode::Truncate,
+        ..Default::default()
+    };
+    let n = write_arrow(&source_conn, "test_write", &batches, options).unwrap();
+    assert_eq!(3, n);
+    verify_round_trip(&source_conn, "test_write");
+}
+
+#[test]
+fn test_mysql_write_rollback() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let dburl = env::var("MYSQL_URL").unwrap();
+    let source_conn = SourceConn::try_from(dburl.as_str()).unwrap();
+    let mut conn = r2d2_mysql::mysql::Conn::new(dburl.as_str()).unwrap();
+    conn.query_drop("DROP TABLE IF EXISTS test_write_rollback")
+        .unwrap();
+    conn.query_drop("CREATE TABLE test_write_rollback (id INTEGER NOT NULL, name TEXT)")
+        .unwrap();
+    conn.query_drop("INSERT INTO test_write_rollback VALUES (0, 'kept')")
+        .unwrap();
+
+    // the second batch does not fit in INTEGER, so the truncation is rolled back too
+    let batches = id_batches(&[&[1, 2], &[i64::MAX]]);
+    let options = WriteOptions {
+        mode: WriteMode::Truncate,
+        ..Default::default()
+    };
+    assert!(write_arrow(&source_conn, "test_write_rollback", &batches, options).is_err());
+    let rows: Vec<(i32, String)> = conn
+        .query("SELECT id, name FROM test_write_rollback")
+        .unwrap();
+    assert_eq!(vec![(0, "kept".to_string())], rows);
+
+    // the table created is dropped, since MySQL commits its creation on its own
+    conn.query_drop("DROP TABLE test_write_rollback").unwrap();
+    let options = WriteOptions {
+        mode: WriteMode::Create,
+        ..Default::default()
+    };
+    let schema = Arc::new(Schema::new(vec![Field::new(
+        "t",
+        DataType::Time64(TimeUnit::Microsecond),
+        false,
+    )]));
+    let batches = vec![
+        batch(
+            &schema,
+            vec![Arc::new(Time64MicrosecondArray::from(vec![0]))],
+        ),
+        // 100 hours
+        batch(
+            &schema,
+            vec![Arc::new(Time64MicrosecondArray::from(vec![
+                360_000_000_000,
+            ]))],
+        ),
+    ];
+    assert!(write_arrow(&source_conn, "test_write_rollback", &batches, options).is_err());
+    let tables: Vec<String> = conn
+        .query("SHOW TABLES LIKE 'test_write_rollback'")
+        .unwrap();
+    assert!(tables.is_empty());
+}
@@ -1,10 +1,19 @@
 DROP TABLE IF EXISTS test_table;
 DROP TABLE IF EXISTS test_str;
+DROP VIEW IF EXISTS test_timestamp_precision;
 DROP TABLE IF EXISTS test_types;
 DROP TABLE IF EXISTS test_infinite_values;
+DROP TABLE IF EXISTS test_extended_types;
+DROP TABLE IF EXISTS test_domain_types;
+DROP TABLE IF EXISTS test_hstore;
+DROP DOMAIN IF EXISTS posint;
+DROP DOMAIN IF EXISTS email;
+DROP DOMAIN IF EXISTS mood;
 DROP TYPE IF EXISTS happiness;
+DROP TYPE IF EXISTS complex;
 DROP EXTENSION IF EXISTS citext;
 DROP EXTENSION IF EXISTS ltree;
+DROP EXTENSION IF EXISTS hstore;
 
 CREATE TABLE IF NOT EXISTS test_table(
     test_int INTEGER NOT NULL,
@@ -92,6 +101,55 @@ INSERT INTO test_types VALUES ('2000-02-28', '2000-02-28 12:00:10', '2000-02-28
 INSERT INTO test_types VALUES ('2038-01-18', '2038-01-18 23:59:59', '2038-01-18 23:59:59+08', 2, 9223372036854775807, 2.71, '1e-130', 'ccc', NULL, 'c', '86b49c42-96b2-11eb-9298-3e22fbb9fe9d', '23:00:10', '3 months 2 days ago', '{"customer": "Josh William", "items": {"product": "Toy Car","qty": 1}}', '{"product": "Toy Car","qty": 1}', '', 'ecstatic', '{123.123}', '{-1e-307, 1e308}', '{521.34}', '{true}', '{-32768, 32767}', '{-2147483648, 2147483647}', '{0}', 's', 'A', '*', 'A@',ARRAY['','  '],ARRAY['','  '],'someName');
 INSERT INTO test_types VALUES (NULL, NULL, NULL, 3, NULL, 0.00, -1e-37, NULL, 'd', 'defghijklm', NULL, '18:30:00', '3 year', NULL, NULL, '😜', NULL, '{-1e-37, 1e37}', '{0.000234, -12.987654321}', '{0.12, 333.33, 22.22}', NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL,'{}','{}','101203203-1212323-22131235');
 
+CREATE VIEW test_timestamp_precision AS SELECT test_int16, test_timestamp::timestamp(0) AS ts, test_timestamptz::timestamptz(3) AS tstz FROM test_types;
+
+CREATE TYPE complex AS (r DOUBLE PRECISION, i INTEGER, label TEXT);
+CREATE TABLE IF NOT EXISTS test_extended_types(
+    test_int INTEGER NOT NULL,
+    test_interval INTERVAL,
+    test_inet INET,
+    test_cidr CIDR,
+    test_macaddr MACADDR,
+    test_money MONEY,
+    test_bit BIT(4),
+    test_varbit VARBIT,
+    test_xml XML,
+    test_int4range INT4RANGE,
+    test_tsrange TSRANGE,
+    test_complex complex
+);
+
+INSERT INTO test_extended_types VALUES (1, '1 year 2 mons 3 days 04:05:06.5', '192.168.1.5', '10.0.0.0/8', '08:00:2b:01:02:03', 1234.56, B'1010', B'101', '<a>b</a>', '[1,10]', '[2020-01-01 00:00:00,2020-01-02 12:30:00)', ROW(1.5, -2, 'a b'));
+INSERT INTO test_extended_types VALUES (2, '-1 days +02:03:00', '2001:db8::1/64', '2001:db8::/32', '00:00:00:00:00:ff', -0.5, B'0001', B'11', '<x/>', 'empty', '(,2020-01-01 00:00:00]', ROW(NULL, 0, ''));
+INSERT INTO test_extended_types VALUES (3, NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL, NULL);
+
+CREATE DOMAIN posint AS INTEGER CHECK (VALUE > 0);
+CREATE DOMAIN email AS TEXT;
+CREATE DOMAIN mood AS happiness;
+CREATE TABLE IF NOT EXISTS test_domain_types(
+    test_int INTEGER NOT NULL,
+    test_posint posint,
+    test_email email,
+    test_mood mood,
+    test_enumarray happiness[],
+    test_posintarray posint[],
+    test_emailarray email[]
+);
+
+INSERT INTO test_domain_types VALUES (1, 1, 'a@example.com', 'happy', '{happy,ecstatic}', '{1,2}', '{a@example.com,b@example.com}');
+INSERT INTO test_domain_types VALUES (2, 2, 'c@example.com', 'very happy', '{}', '{}', '{}');
+INSERT INTO test_domain_types VALUES (3, NULL, NULL, NULL, NULL, NULL, NULL);
+
+CREATE EXTENSION hstore;
+CREATE TABLE IF NOT EXISTS test_hstore(
+    test_int INTEGER NOT NULL,
+    test_hstore HSTORE
+);
+
+INSERT INTO test_hstore VALUES (1, 'a=>1, b=>NULL');
+INSERT INTO test_hstore VALUES (2, '');
+INSERT INTO test_hstore VALUES (3, NULL);
+
 CREATE OR REPLACE FUNCTION increment(i integer) RETURNS integer AS $$
     BEGIN
         RETURN i + 1;